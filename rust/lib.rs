@@ -19,6 +19,11 @@ pub mod ubidi;
 pub use shape::*;
 pub use ubidi::*;
 
+/// The (major, minor, update) Unicode version of the character property tables
+// BEGIN GENERATED UNICODE_VERSION (cargo xtask gen-tables)
+pub const UNICODE_VERSION: (u8, u8, u8) = (16, 0, 0);
+// END GENERATED UNICODE_VERSION
+
 #[cfg(any(target_arch = "wasm32", feature = "wasm"))]
 use alloc::vec::Vec;
 #[cfg(any(target_arch = "wasm32", feature = "wasm"))]
@@ -35,8 +40,12 @@ static ALLOCATOR: AssumeSingleThreaded<FreeListAllocator> =
 /// Define a panic handler for WASM applications
 #[cfg(any(target_arch = "wasm32", feature = "wasm"))]
 mod wasm_specific {
+    /// Trap instead of looping forever, so JavaScript gets a `RuntimeError`
     #[panic_handler]
     fn panic(_info: &core::panic::PanicInfo) -> ! {
+        #[cfg(target_arch = "wasm32")]
+        core::arch::wasm32::unreachable();
+        #[cfg(not(target_arch = "wasm32"))]
         loop {}
     }
 }
@@ -61,6 +70,48 @@ pub unsafe extern "C" fn processText(input_ptr: *const u16, len: usize, options:
     setUnicodeArray(result_vec.as_ptr(), result_vec.len());
 }
 
+/// Same as `processText`, but rejects invalid options and malformed input (see
+/// `try_shape_unicode`). Returns false without calling setUnicodeArray if it does.
+///
+/// # Safety
+///
+/// This function is not safe, but it's only used in wasm
+#[cfg(any(target_arch = "wasm32", feature = "wasm"))]
+#[no_mangle]
+pub unsafe extern "C" fn tryProcessText(input_ptr: *const u16, len: usize, options: u32) -> bool {
+    let input_slice = core::slice::from_raw_parts(input_ptr, len);
+    match try_shape_unicode(input_slice, &options).ok() {
+        Some(result_vec) => {
+            setUnicodeArray(result_vec.as_ptr(), result_vec.len());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Passes the joining form of every code unit of logical text (see `joining_forms`) to
+/// setUnicodeArray: 0 for none, then 1 isolated, 2 final, 3 initial and 4 medial.
+///
+/// # Safety
+///
+/// This function is not safe, but it's only used in wasm
+#[cfg(any(target_arch = "wasm32", feature = "wasm"))]
+#[no_mangle]
+pub unsafe extern "C" fn joiningForms(input_ptr: *const u16, len: usize) {
+    let input_slice = core::slice::from_raw_parts(input_ptr, len);
+    let result_vec: Vec<u16> = joining_forms(input_slice)
+        .iter()
+        .map(|form| match form {
+            None => 0,
+            Some(GlyphForm::Isolated) => 1,
+            Some(GlyphForm::Final) => 2,
+            Some(GlyphForm::Initial) => 3,
+            Some(GlyphForm::Medial) => 4,
+        })
+        .collect();
+    setUnicodeArray(result_vec.as_ptr(), result_vec.len());
+}
+
 /// # Safety
 ///
 /// This function is not safe, but it's only used in wasm
@@ -93,17 +144,17 @@ pub unsafe extern "C" fn free(ptr: *mut u16, size: usize) {
     alloc::alloc::dealloc(ptr as *mut u8, alloc::alloc::Layout::array::<u16>(size).unwrap());
 }
 
-/// Check if a character is RTL
+/// Check if a character (code point) is RTL
 #[cfg(any(target_arch = "wasm32", feature = "wasm"))]
 #[no_mangle]
-pub extern "C" fn isRTL(input: u16) -> bool {
-    is_rtl(&input)
+pub extern "C" fn isRTL(input: u32) -> bool {
+    is_rtl_code_point(&input)
 }
 
-/// Check if a character is CJK
+/// Check if a character (code point) is CJK
 #[cfg(any(target_arch = "wasm32", feature = "wasm"))]
 #[no_mangle]
-pub extern "C" fn isCJK(input: u16) -> bool {
+pub extern "C" fn isCJK(input: u32) -> bool {
     /// Check if a character is CJK
-    is_cjk(&input)
+    is_cjk_code_point(&input)
 }
@@ -2,12 +2,7 @@
 // https://r12a.github.io/scripts/arab/arb.html
 use crate::shape::*;
 use alloc::vec::Vec;
-use core::cmp::max;
-
-// enum ShapeError {
-//     UOutOfRange,
-//     UNoSpaceAvailable,
-// }
+use core::cmp::{max, Ordering};
 
 // Converts the Alef characters into an equivalent
 // LamAlef location in the 0x06xx Range, this is an
@@ -29,9 +24,10 @@ fn is_tashkeel_char(ch: &u16) -> bool {
     *ch >= 0x064B && *ch <= 0x0652
 }
 
-// Returns 1 for Tashkeel characters in FE range else return 0
-fn is_tashkeel_char_fe(ch: &u16) -> bool {
-    *ch >= 0xFE70 && *ch <= 0xFE7F
+// Returns true for the Tashkeel characters that are removed when shaping
+// with a U_SHAPE_TASHKEEL mode (all but Shadda) else return false
+fn is_removed_tashkeel_char(ch: &u16) -> bool {
+    is_tashkeel_char(ch) && *ch != SHADDA06_CHAR
 }
 
 // Returns 1 for Alef characters else return 0
@@ -44,33 +40,38 @@ fn is_lam_alef_char(ch: &u16) -> bool {
     *ch >= 0xFEF5 && *ch <= 0xFEFC
 }
 
-// returns 1 if the character is a seen family character in the Unicode
-// 06 range otherwise returns 0
-// fn is_seen_family_char(ch: &u16) -> bool {
-//     *ch >= 0x633 && *ch <= 0x636
-// }
-
-// returns 1 if the character is a seen family isolated character
-// in the FE range otherwise returns 0
-// fn is_seen_tail_family_char(ch: &u16) -> u16 {
-//     if *ch >= 0xfeb1 && *ch < 0xfebf {
-//         let diff: usize = (*ch - 0xFEB1).into();
-//         return TAIL_FAMILY_ISOLATED_FINAL[diff].into();
-//     }
-//     0
-// }
-
-// returns 1 if the character is a yehHamza isolated or yehhamza
-// final is found otherwise returns 0
-// fn is_yeh_hamza_char(ch: &u32) -> bool {
-//     *ch == 0xFE89 || *ch == 0xFE8A
-// }
+// returns true if the character is a seen family isolated or final
+// character in the FE range otherwise returns false
+fn is_seen_tail_family_char(ch: &u16) -> bool {
+    if *ch >= 0xfeb1 && *ch < 0xfebf {
+        let diff: usize = (*ch - 0xFEB1).into();
+        return TAIL_FAMILY_ISOLATED_FINAL[diff] == 1;
+    }
+    false
+}
+
+// returns true if the character is a yehHamza isolated or yehhamza
+// final is found otherwise returns false
+fn is_yeh_hamza_char(ch: &u16) -> bool {
+    *ch == 0xFE89 || *ch == 0xFE8A
+}
+
+// Returns true for Alef Maksoura characters (the Yeh of a YehHamza
+// expanded in two cells) else return false
+fn is_alef_maksoura_char(ch: &u16) -> bool {
+    *ch == 0xFEEF || *ch == 0xFEF0 || *ch == 0x0649
+}
+
+// Returns true for the old and the new tail characters else return false
+fn is_tail_char(ch: &u16) -> bool {
+    *ch == OLD_TAIL_CHAR || *ch == NEW_TAIL_CHAR
+}
 
 // Resolves the link between the characters as
 // Arabic characters have four forms :
 // Isolated, Initial, Middle and Final Form
 fn get_link(ch: &u16) -> u16 {
-    if *ch >= 0x0622 && *ch <= 0x06D3 {
+    if *ch >= 0x0622 && usize::from(*ch - 0x0622) < ARA_LINK.len() {
         let ind: usize = (*ch - 0x0622).into();
         return ARA_LINK[ind];
     } else if *ch == 0x200D {
@@ -87,6 +88,24 @@ fn get_link(ch: &u16) -> u16 {
     0
 }
 
+// Returns the link of a code point from its Joining_Type and its joining script, or get_link
+// for the presentation forms, which are non joining in ArabicShaping.txt but link like the form
+// they represent
+fn get_joining_link(cp: u32) -> (u16, u8) {
+    match JOINING_LINK.binary_search_by(|(first, last, _, _)| {
+        if *last < cp {
+            Ordering::Less
+        } else if *first > cp {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(idx) => (JOINING_LINK[idx].2, JOINING_LINK[idx].3),
+        Err(_) => u16::try_from(cp).map_or((0, 0), |ch| (get_link(&ch), ARABIC_SCRIPT)),
+    }
+}
+
 // Checks if the Tashkeel Character is on Tatweel or not,if the
 // Tashkeel on tatweel (FE range), it returns 1 else if the
 // Tashkeel with shadda on tatweel (FC range)return 2 otherwise
@@ -148,7 +167,7 @@ fn count_spaces(dest: &[u16], spaces_countl: &mut usize, spaces_countr: &mut usi
     let mut i: usize = 0;
     let mut countl: usize = 0;
     let mut countr: usize = 0;
-    while dest[i] == SPACE_CHAR && countl < s {
+    while countl < s && dest[i] == SPACE_CHAR {
         countl += 1;
         i += 1;
     }
@@ -166,8 +185,10 @@ fn count_spaces(dest: &[u16], spaces_countl: &mut usize, spaces_countr: &mut usi
 // This function inverts the buffer, it's used
 // in case the user specifies the buffer to be
 // U_SHAPE_TEXT_DIRECTION_LOGICAL
-fn invert_buffer(buffer: &mut [u16], lowlimit: usize, highlimit: usize) {
-    // let mut tmp: u16 = 0;
+fn invert_buffer<T>(buffer: &mut [T], lowlimit: usize, highlimit: usize) {
+    if lowlimit + highlimit >= buffer.len() {
+        return;
+    }
     let mut i: usize = lowlimit;
     let mut j: usize = buffer.len() - highlimit - 1;
     while i < j {
@@ -200,10 +221,11 @@ fn calculate_size(source: &[u16], options: &u32) -> usize {
         if (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_VISUAL_LTR {
             i = 0;
             while i < source.len() {
-                if ((is_alef_char(&source[i])
+                if ((lam_alef_option
+                    && is_alef_char(&source[i])
                     && i < (source.len() - 1)
                     && source[i + 1] == LAM_CHAR)
-                    || is_tashkeel_char_fe(&source[i]))
+                    || (tashkeel_option && is_removed_tashkeel_char(&source[i])))
                     && dest_size > 0
                 {
                     dest_size -= 1;
@@ -213,10 +235,11 @@ fn calculate_size(source: &[u16], options: &u32) -> usize {
         } else if (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_LOGICAL {
             i = 0;
             while i < source.len() {
-                if (((source[i] == LAM_CHAR)
+                if ((lam_alef_option
+                    && (source[i] == LAM_CHAR)
                     && (i < (source.len() - 1))
                     && (is_alef_char(&source[i + 1])))
-                    || (is_tashkeel_char_fe(&source[i])))
+                    || (tashkeel_option && is_removed_tashkeel_char(&source[i])))
                     && (dest_size > 0)
                 {
                     dest_size -= 1;
@@ -243,355 +266,239 @@ fn calculate_size(source: &[u16], options: &u32) -> usize {
 
 // The shape_arabic function converts Lam + Alef into LamAlef + space,
 // and Tashkeel to space.
-// handleGeneratedSpaces function puts these generated spaces
-// according to the options the user specifies. LamAlef and Tashkeel
-// spaces can be replaced at begin, at end, at near or decrease the
-// buffer size.
+// handle_generated_spaces puts these generated spaces according to the
+// options the user specifies. LamAlef and Tashkeel spaces can be put at
+// begin, at end, or removed to decrease the buffer size (the RESIZE
+// options, where the final copy drops them). LamAlef spaces can also
+// stay near the LamAlef.
 //
-// There is also Auto option for LamAlef and tashkeel, which will put
-// the spaces at end of the buffer (or end of text if the user used
-// the option U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END).
+// There is also the Auto option for LamAlef, which puts the spaces at end of the
+// buffer (or end of text if the user used the option
+// U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END).
 //
 // If the text type was visual_LTR and the option
 // U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END was selected the END
 // option will place the space at the beginning of the buffer and
 // BEGIN will place the space at the end of the buffer.
-// fn handle_generated_spaces(
-//     dest: &mut [u16],
-//     source_length: usize,
-//     options: u32,
-//     shape_vars: UShapeVariables
-// ) {
-//     let mut i: usize;
-//     let mut j: usize;
-//     let mut count: isize;
-
-//     let mut lam_alef_option: bool = false;
-//     let mut tashkeel_option: bool = false;
-
-//     if (options & U_SHAPE_LAMALEF_MASK) == U_SHAPE_LAMALEF_RESIZE {
-//         lam_alef_option = true;
-//     }
-//     if (options & U_SHAPE_TASHKEEL_MASK) == U_SHAPE_TASHKEEL_RESIZE {
-//         tashkeel_option = true;
-//     }
-
-//     let mut tempbuffer = Vec::<u16>::with_capacity(source_length);
-
-//     if lam_alef_option || tashkeel_option {
-//         for _ in 0..dest.len() { tempbuffer.push(0); }
-
-//         i = 0;
-//         j = 0;
-//         count = 0;
-//         while i < source_length {
-//             if (lam_alef_option && dest[i] == LAMALEF_SPACE_SUB) ||
-//                 (tashkeel_option && dest[i] == TASHKEEL_SPACE_SUB)
-//             {
-//                 j -= 1;
-//                 count += 1;
-//             } else {
-//                 tempbuffer[j] = dest[i];
-//             }
-//             i += 1;
-//             j += 1;
-//         }
-
-//         while count >= 0 {
-//             tempbuffer[i] = 0x0000;
-//             i -= 1;
-//             count -= 1;
-//         }
-
-//         dest.extend_from_slice(&tempbuffer);
-//     }
-
-//     lam_alef_option = false;
-
-//     if (options & U_SHAPE_LAMALEF_MASK) == U_SHAPE_LAMALEF_NEAR {
-//         lam_alef_option = true;
-//     }
-
-//     if lam_alef_option {
-//         // Lam+Alef is already shaped into LamAlef + FFFF
-//         i = 0;
-//         while i < source_length {
-//             if lam_alef_option && dest[i] == LAMALEF_SPACE_SUB {
-//                 dest[i] = SPACE_CHAR;
-//             }
-//             i += 1;
-//         }
-//     }
-//     lam_alef_option = false;
-//     tashkeel_option = false;
-
-//     if (
-//         (options & U_SHAPE_LAMALEF_MASK) == shape_vars.u_shape_lamalef_begin) ||
-//         (
-//             ((options & U_SHAPE_LAMALEF_MASK) == U_SHAPE_LAMALEF_AUTO) &&
-//             (shape_vars.spaces_relative_to_text_begin_end == 1)
-//         )
-//     {
-//         lam_alef_option = true;
-//     }
-//     if (options & U_SHAPE_TASHKEEL_MASK) == shape_vars.u_shape_tashkeel_begin {
-//         tashkeel_option = true;
-//     }
-
-//     if lam_alef_option || tashkeel_option {
-//         for _ in 0..dest.len() { tempbuffer.push(0); }
-
-//         i = source_length;
-//         j = source_length;
-//         count = 0;
-
-//         loop {
-//             if (lam_alef_option && dest[i] == LAMALEF_SPACE_SUB) ||
-//                 (tashkeel_option && dest[i] == TASHKEEL_SPACE_SUB)
-//             {
-//                 j += 1;
-//                 count += 1;
-//             } else {
-//                 tempbuffer[j] = dest[i];
-//             }
-//             if i == 0 { break }
-//             i -= 1;
-//             j -= 1;
-//         }
-
-//         i = 0;
-//         while i < count.try_into().unwrap() {
-//             tempbuffer[i] = SPACE_CHAR;
-//             i += 1
-//         }
-
-//         dest.extend_from_slice(&tempbuffer);
-//     }
-
-//     lam_alef_option = false;
-//     tashkeel_option = false;
-
-//     if (
-//         (options & U_SHAPE_LAMALEF_MASK) == shape_vars.u_shape_lamalef_end) ||
-//         (
-//             (options & U_SHAPE_LAMALEF_MASK) == U_SHAPE_LAMALEF_AUTO &&
-//             (shape_vars.spaces_relative_to_text_begin_end == 0)
-//         )
-//     {
-//         lam_alef_option = true;
-//     }
-//     if (options & U_SHAPE_TASHKEEL_MASK) == shape_vars.u_shape_tashkeel_end {
-//         tashkeel_option = true;
-//     }
-
-//     if lam_alef_option || tashkeel_option {
-//         for _ in 0..dest.len() { tempbuffer.push(0); }
-
-//         i = 0;
-//         j = 0;
-//         count = 0;
-//         while i < source_length {
-//             if
-//                 lam_alef_option && dest[i] == LAMALEF_SPACE_SUB ||
-//                 tashkeel_option && dest[i] == TASHKEEL_SPACE_SUB
-//             {
-//                 j -= 1;
-//                 count += 1;
-//             } else {
-//                 tempbuffer[j] = dest[i];
-//             }
-//             i += 1;
-//             j += 1;
-//         }
-
-//         while count >= 0 {
-//             tempbuffer[i] = SPACE_CHAR;
-//             i -= 1;
-//             count -= 1;
-//         }
-
-//         dest.extend_from_slice(&tempbuffer);
-//     }
-// }
-
-// Expands the LamAlef character to Lam and Alef consuming the required
-// space from beginning of the buffer. If the text type was visual_LTR
-// and the option U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END was selected
-// the spaces will be located at end of buffer.
-// If there are no spaces to expand the LamAlef, an error
-// will be set to U_NO_SPACE_AVAILABLE as defined in utypes.h
-// fn expand_composit_char_at_begin(
-//     dest: &mut [u16],
-//     source_length: usize,
-// ) -> Result<(), ShapeError> {
-//     let mut i: usize = 0;
-//     let mut j: usize;
-//     let mut countl: usize = 0;
-
-//     // let tempbuffer = try allocator.alloc(u16, dest.len);
-//     let mut tempbuffer = Vec::<u16>::with_capacity(dest.len());
-//     for _ in 0..dest.len() { tempbuffer.push(0); }
-
-//     while dest[i] == SPACE_CHAR {
-//         countl += 1;
-//         i += 1;
-//     }
-
-//     i = source_length - 1;
-//     j = source_length - 1;
-
-//     loop {
-//         if countl > 0 && is_lam_alef_char(&dest[i]) {
-//             tempbuffer[j] = LAM_CHAR;
-//             // to ensure the array index is within the range
-//             let dest_pos: usize = (dest[i] - 0xFEF5).into();
-//             if dest[i] >= 0xFEF5 && dest_pos < CONVERT_LAM_ALEF.len() {
-//                 return Err(ShapeError::UOutOfRange);
-//             }
-//             tempbuffer[j - 1] = CONVERT_LAM_ALEF[dest_pos];
-//             j -= 1;
-//             countl -= 1;
-//         } else {
-//             if countl == 0 && is_lam_alef_char(&dest[i]) {
-//                 return Err(ShapeError::UNoSpaceAvailable);
-//             }
-//             tempbuffer[j] = dest[i];
-//         }
-//         if i == 0 || j == 0 { break }
-//         i -= 1;
-//         j -= 1;
-//     }
-
-//     dest.extend_from_slice(&tempbuffer);
-//     Ok(())
-// }
-
-// Expands the LamAlef character to Lam and Alef consuming the
-// required space from end of the buffer. If the text type was
-// Visual LTR and the option U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END
-// was used, the spaces will be consumed from begin of buffer. If
-// there are no spaces to expand the LamAlef, an error
-// will be set to U_NO_SPACE_AVAILABLE as defined in utypes.h
-// fn expandCompositCharAtEnd(
-//     dest: []u16,
-//     sourceLength: usize,
-//     allocator: std.mem.Allocator,
-// ) !void {
-//     var i: usize = 0;
-//     var j: usize = 0;
-//     var countr: usize = 0;
-//     var inpsize: usize = sourceLength;
-
-//     var tempbuffer = try allocator.alloc(u16, dest.len);
-//     defer allocator.free(tempbuffer);
-
-//     while (dest[inpsize - 1] == SPACE_CHAR) {
-//         countr += 1;
-//         inpsize -= 1;
-//     }
-
-//     i = sourceLength - countr - 1;
-//     j = sourceLength - 1;
-
-//     while (i >= 0 and j >= 0) {
-//         if (countr > 0 and isLamAlefChar(dest[i])) {
-//             tempbuffer[j] = LAM_CHAR;
-//             tempbuffer[j - 1] = convertLamAlef[dest[i] - 0xFEF5];
-//             j -= 1;
-//             countr -= 1;
-//         } else {
-//             if ((countr == 0) and isLamAlefChar(dest[i])) return ShapeError.U_NO_SPACE_AVAILABLE;
-//             tempbuffer[j] = dest[i];
-//         }
-//         i -= 1;
-//         j -= 1;
-//     }
-
-//     if (countr > 0) {
-//         // u_memmove(void * destination, const void * source, size_t num);
-//         std.mem.copy(u16, tempbuffer, tempbuffer[countr .. countr + sourceLength]);
-//         if (tempbuffer.len < sourceLength) {
-//             i = sourceLength - 1;
-//             while (i >= sourceLength - countr) : (i -= 1) tempbuffer[i] = SPACE_CHAR;
-//         }
-//     }
-
-//     std.mem.copy(u16, dest, tempbuffer);
-// }
-
-// Expands the LamAlef character into Lam + Alef, YehHamza character
-// into Yeh + Hamza, SeenFamily character into SeenFamily character
-// + Tail, while consuming the space next to the character.
-// If there are no spaces next to the character, an error
-// will be set to U_NO_SPACE_AVAILABLE as defined in utypes.h
-// fn expandCompositCharAtNear(
-//     dest: []u16,
-//     sourceLength: usize,
-//     yehHamzaOption: bool,
-//     seenTailOption: bool,
-//     lamAlefOption: bool,
-//     shapeVars: UShapeVariables,
-// ) !void {
-//     var i: usize = 0;
-//     var lamalefChar: u16 = 0;
-//     var yehhamzaChar: u16 = 0;
-
-//     while (i <= sourceLength - 1) : (i += 1) {
-//         if (seenTailOption and isSeenTailFamilyChar(dest[i]) != 0) {
-//             if ((i > 0) and (dest[i - 1] == SPACE_CHAR)) {
-//                 dest[i - 1] = shapeVars.tailChar;
-//             } else {
-//                 return ShapeError.U_NO_SPACE_AVAILABLE;
-//             }
-//         } else if (yehHamzaOption and (isYehHamzaChar(dest[i]))) {
-//             if ((i > 0) and (dest[i - 1] == SPACE_CHAR)) {
-//                 yehhamzaChar = dest[i];
-//                 dest[i] = yehHamzaToYeh[yehhamzaChar - YEH_HAMZAFE_CHAR];
-//                 dest[i - 1] = HAMZAFE_CHAR;
-//             } else {
-//                 return ShapeError.U_NO_SPACE_AVAILABLE;
-//             }
-//         } else if (lamAlefOption and isLamAlefChar(dest[i + 1])) {
-//             if (dest[i] == SPACE_CHAR) {
-//                 lamalefChar = dest[i + 1];
-//                 dest[i + 1] = LAM_CHAR;
-//                 dest[i] = convertLamAlef[lamalefChar - 0xFEF5];
-//             } else {
-//                 return ShapeError.U_NO_SPACE_AVAILABLE;
-//             }
-//         }
-//     }
-// }
-
-// LamAlef, need special handling, since it expands from one
-// character into two characters while shaping or deshaping.
-// In order to expand it, near or far spaces according to the
-// options user specifies. Also buffer size can be increased.
 //
-// For SeenFamily characters and YehHamza only the near option is
-// supported, while for LamAlef we can take spaces from begin, end,
-// near or even increase the buffer size.
-// There is also the Auto option for LamAlef only, which will first
-// search for a space at end, begin then near, respectively.
-// If there are no spaces to expand these characters, an error will be set to
-// U_NO_SPACE_AVAILABLE as defined in utypes.h
-// fn expandCompositChar(
-//     dest: []u16,
-//     sourceLength: usize,
-//     options: u32,
-//     shapeVars: UShapeVariables,
-// ) !void {
-//     var yehHamzaOption: bool = false;
-//     var seenTailOption: bool = false;
-//     const lamAlefOption: bool = false;
-
-//     if ((options & U_SHAPE_YEHHAMZA_MASK) == U_SHAPE_YEHHAMZA_TWOCELL_NEAR) yehHamzaOption = true;
-//     if ((options & U_SHAPE_SEEN_MASK) == U_SHAPE_SEEN_TWOCELL_NEAR) seenTailOption = true;
-
-//     if (yehHamzaOption or seenTailOption or lamAlefOption) {
-//         try expandCompositCharAtNear(dest, sourceLength, yehHamzaOption, seenTailOption, lamAlefOption, shapeVars);
-//     }
-// }
+// Unlike ICU this runs on the buffer in its final order, so a space
+// generated near a LamAlef at the edge of the text stays next to it.
+fn handle_generated_spaces(
+    dest: &mut [u16],
+    map: &mut [usize],
+    options: &u32,
+    shape_vars: &UShapeVariables,
+) {
+    let lamalef_mode = options & U_SHAPE_LAMALEF_MASK;
+    let auto = lamalef_mode == U_SHAPE_LAMALEF_AUTO;
+    if lamalef_mode == U_SHAPE_LAMALEF_NEAR {
+        // Lam+Alef is already shaped into LamAlef + LAMALEF_SPACE_SUB
+        for ch in dest.iter_mut().filter(|ch| **ch == LAMALEF_SPACE_SUB) {
+            *ch = SPACE_CHAR;
+        }
+    } else if lamalef_mode == shape_vars.u_shape_lamalef_begin
+        || (auto && shape_vars.spaces_relative_to_text_begin_end == 1)
+    {
+        move_generated_spaces(dest, map, LAMALEF_SPACE_SUB, true);
+    } else if lamalef_mode == shape_vars.u_shape_lamalef_end
+        || (auto && shape_vars.spaces_relative_to_text_begin_end == 0)
+    {
+        move_generated_spaces(dest, map, LAMALEF_SPACE_SUB, false);
+    }
+
+    let tashkeel_mode = options & U_SHAPE_TASHKEEL_MASK;
+    if tashkeel_mode == shape_vars.u_shape_tashkeel_begin {
+        move_generated_spaces(dest, map, TASHKEEL_SPACE_SUB, true);
+    } else if tashkeel_mode == shape_vars.u_shape_tashkeel_end {
+        move_generated_spaces(dest, map, TASHKEEL_SPACE_SUB, false);
+    }
+}
+
+// Replaces the generated spaces (space_sub) by spaces at the beginning or
+// at the end of the buffer, keeping the order of all other characters.
+// The spaces keep the position of the character they replace in map.
+fn move_generated_spaces(dest: &mut [u16], map: &mut [usize], space_sub: u16, to_begin: bool) {
+    let mut spaces = Vec::<(u16, usize)>::new();
+    let mut text = Vec::<(u16, usize)>::with_capacity(dest.len());
+    for (ch, idx) in dest.iter().zip(map.iter()) {
+        if *ch == space_sub {
+            spaces.push((SPACE_CHAR, *idx));
+        } else {
+            text.push((*ch, *idx));
+        }
+    }
+    let (first, second) = if to_begin { (spaces, text) } else { (text, spaces) };
+    for ((ch, idx), (dest_ch, dest_idx)) in
+        first.into_iter().chain(second).zip(dest.iter_mut().zip(map.iter_mut()))
+    {
+        *dest_ch = ch;
+        *dest_idx = idx;
+    }
+}
+
+// Expands the LamAlef characters to Lam and Alef (in the order of the
+// buffer) and consumes one space per LamAlef so the buffer keeps its
+// length: from the beginning, the end or next to the Alef of the buffer
+// as the user specifies. Auto tries the end, the beginning and then the
+// near spaces. If the text type was visual_LTR and the option
+// U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END was selected, begin and end
+// are swapped. Both characters take the position of the LamAlef in map.
+// Returns false if there were not enough spaces, the buffer then grows
+// like with U_SHAPE_LAMALEF_RESIZE (ICU's U_NO_SPACE_AVAILABLE).
+fn expand_lam_alef(
+    dest: &mut Vec<u16>,
+    map: &mut Vec<usize>,
+    options: &u32,
+    shape_vars: &UShapeVariables,
+    is_logical: bool,
+) -> bool {
+    let mut alefs = Vec::<usize>::new();
+    let mut expanded = Vec::<u16>::with_capacity(dest.len() + 1);
+    let mut expanded_map = Vec::<usize>::with_capacity(dest.len() + 1);
+    for (ch, idx) in dest.iter().zip(map.iter()) {
+        if is_lam_alef_char(ch) {
+            let alef = CONVERT_LAM_ALEF[usize::from(*ch - 0xFEF5)];
+            if is_logical {
+                expanded.extend_from_slice(&[LAM_CHAR, alef]);
+                alefs.push(expanded.len() - 1);
+            } else {
+                alefs.push(expanded.len());
+                expanded.extend_from_slice(&[alef, LAM_CHAR]);
+            }
+            expanded_map.extend_from_slice(&[*idx, *idx]);
+        } else {
+            expanded.push(*ch);
+            expanded_map.push(*idx);
+        }
+    }
+    if alefs.is_empty() {
+        return true;
+    }
+    *dest = expanded;
+    *map = expanded_map;
+
+    let lamalef_mode = options & U_SHAPE_LAMALEF_MASK;
+    if lamalef_mode == U_SHAPE_LAMALEF_RESIZE {
+        return true;
+    }
+    let needed = alefs.len();
+    let countl = dest.iter().take_while(|ch| **ch == SPACE_CHAR).count().min(needed);
+    let countr = dest.iter().rev().take_while(|ch| **ch == SPACE_CHAR).count().min(needed);
+    let begin: Vec<usize> = (0..countl).collect();
+    let end: Vec<usize> = (dest.len() - countr..dest.len()).collect();
+    let near: Vec<usize> = alefs
+        .iter()
+        .filter_map(|alef| if is_logical { alef.checked_add(1) } else { alef.checked_sub(1) })
+        .filter(|pos| dest.get(*pos) == Some(&SPACE_CHAR))
+        .collect();
+
+    let spaces = if lamalef_mode == U_SHAPE_LAMALEF_AUTO {
+        let (first, second) = if shape_vars.spaces_relative_to_text_begin_end == 1 {
+            (begin, end)
+        } else {
+            (end, begin)
+        };
+        [first, second].into_iter().find(|spaces| spaces.len() == needed).unwrap_or(near)
+    } else if lamalef_mode == U_SHAPE_LAMALEF_NEAR {
+        near
+    } else if lamalef_mode == shape_vars.u_shape_lamalef_begin {
+        begin
+    } else {
+        end
+    };
+
+    (*dest, *map) = dest
+        .iter()
+        .zip(map.iter())
+        .enumerate()
+        .filter(|(pos, _)| spaces.binary_search(pos).is_err())
+        .map(|(_, (ch, idx))| (*ch, *idx))
+        .unzip();
+
+    spaces.len() == needed
+}
+
+// Expands the YehHamza character into Yeh + Hamza and adds the Tail to
+// the SeenFamily characters (in their isolated and final forms), while
+// consuming the space after the character in the text, i.e. before it in
+// a visual buffer. Characters without a space next to them are left as they
+// are and false is returned (ICU's U_NO_SPACE_AVAILABLE).
+fn expand_composit_char(
+    dest: &mut [u16],
+    options: &u32,
+    shape_vars: &UShapeVariables,
+    is_logical: bool,
+) -> bool {
+    // U_SHAPE_DIRECTION_OUTPUT_BIDI is the lowest bit of U_SHAPE_SEEN_MASK
+    let seen_tail_option: bool =
+        (options & U_SHAPE_SEEN_MASK & !U_SHAPE_DIRECTION_OUTPUT_BIDI) == U_SHAPE_SEEN_TWOCELL_NEAR;
+    let yeh_hamza_option: bool = (options & U_SHAPE_YEHHAMZA_MASK) == U_SHAPE_YEHHAMZA_TWOCELL_NEAR;
+    let mut space_available: bool = true;
+
+    for i in 0..dest.len() {
+        let seen_tail: bool = seen_tail_option && is_seen_tail_family_char(&dest[i]);
+        let yeh_hamza: bool = yeh_hamza_option && is_yeh_hamza_char(&dest[i]);
+        if !seen_tail && !yeh_hamza {
+            continue;
+        }
+        let near = if is_logical { i.checked_add(1) } else { i.checked_sub(1) };
+        match near.filter(|near| dest.get(*near) == Some(&SPACE_CHAR)) {
+            Some(near) if seen_tail => dest[near] = shape_vars.tail_char,
+            Some(near) => {
+                dest[i] = YEH_HAMZA_TO_YEH[usize::from(dest[i] - YEH_HAMZAFE_CHAR)];
+                dest[near] = HAMZAFE_CHAR;
+            }
+            None => space_available = false,
+        }
+    }
+
+    space_available
+}
+
+// Converts a shaped Arabic Unicode buffer in FBxx and FExx Range back into
+// the 06xx Range. LamAlef ligatures are expanded later by expand_lam_alef.
+// With the two cell options Yeh + Hamza is composed into YehHamza and the
+// Tail of a SeenFamily character is removed, leaving a space.
+fn deshape_unicode(dest: &mut [u16], options: &u32, is_logical: bool) {
+    // U_SHAPE_DIRECTION_OUTPUT_BIDI is the lowest bit of U_SHAPE_SEEN_MASK
+    let seen_compose_enabled: bool =
+        (options & U_SHAPE_SEEN_MASK & !U_SHAPE_DIRECTION_OUTPUT_BIDI) == U_SHAPE_SEEN_TWOCELL_NEAR;
+    let yeh_hamza_compose_enabled: bool =
+        (options & U_SHAPE_YEHHAMZA_MASK) == U_SHAPE_YEHHAMZA_TWOCELL_NEAR;
+
+    if seen_compose_enabled || yeh_hamza_compose_enabled {
+        for i in 0..dest.len() {
+            // the character before dest[i] in the text
+            let prev = if is_logical { i.checked_sub(1) } else { i.checked_add(1) };
+            let Some(prev) = prev.filter(|prev| *prev < dest.len()) else {
+                continue;
+            };
+            if yeh_hamza_compose_enabled
+                && (dest[i] == HAMZA06_CHAR || dest[i] == HAMZAFE_CHAR)
+                && is_alef_maksoura_char(&dest[prev])
+            {
+                dest[i] = SPACE_CHAR;
+                dest[prev] = YEH_HAMZA_CHAR;
+            } else if seen_compose_enabled
+                && is_tail_char(&dest[i])
+                && is_seen_tail_family_char(&dest[prev])
+            {
+                dest[i] = SPACE_CHAR;
+            }
+        }
+    }
+
+    for ch in dest.iter_mut() {
+        if (0xFB50..=0xFBFF).contains(ch) {
+            let base: u16 = CONVERT_FBTO06[usize::from(*ch - 0xFB50)];
+            if base != 0 {
+                *ch = base;
+            }
+        } else if (0xFE70..=0xFEF4).contains(ch) && *ch != NEW_TAIL_CHAR {
+            *ch = CONVERT_FETO06[usize::from(*ch - 0xFE70)];
+        }
+    }
+}
 
 // Converts an Arabic Unicode buffer in 06xx Range into a shaped
 // arabic Unicode buffer in FExx Range
@@ -614,8 +521,6 @@ fn _shape_arabic(
     let mut next_link: u16 = 0;
     let mut w_lamalef: u16;
     // let mut lamalef_found: bool = true;
-    // let mut seenfam_found: bool = true;
-    // let mut yehhamza_found: bool = true;
     // let mut tashkeel_found: bool = true;
 
     // NOTE: We do not need this since we want the presentation mask
@@ -639,6 +544,9 @@ fn _shape_arabic(
     //     }
     // }
 
+    if dest.is_empty() {
+        return;
+    }
     // sets the index to the end of the buffer
     i = dest.len() - 1;
     last_pos = i;
@@ -679,20 +587,6 @@ fn _shape_arabic(
                 curr_link = get_link(&w_lamalef); // LAMALEF_SPACE_SUB is added here and is replaced by spaces
             } // in removeLamAlefSpaces()
 
-            // if i > 0 && dest[i - 1] == SPACE_CHAR {
-            //     if is_seen_family_char(&dest[i]) {
-            //         seenfam_found = true;
-            //     } else if dest[i] == YEH_HAMZA_CHAR {
-            //         yehhamza_found = true;
-            //     }
-            // } else if i == 0 {
-            //     if is_seen_family_char(&dest[i]) {
-            //         seenfam_found = true;
-            //     } else if dest[i] == YEH_HAMZA_CHAR {
-            //         yehhamza_found = true;
-            //     }
-            // }
-
             // get the proper shape according to link ability of neighbors
             // and of character; depends on the order of the shapes
             // (isolated, initial, middle, final) in the compatibility area
@@ -722,15 +616,13 @@ fn _shape_arabic(
             }
             if (dest[i] ^ 0x0600) < 0x100 {
                 if is_tashkeel_char(&dest[i]) {
-                    if tashkeel_flag == 2 && dest[i] != SHADDA06_CHAR {
+                    if tashkeel_flag == 2 && is_removed_tashkeel_char(&dest[i]) {
                         dest[i] = TASHKEEL_SPACE_SUB;
                         // tashkeel_found = true;
                     } else {
+                        // tashkeel are U+064B..=U+0652, one entry of IRRELEVANT_POS each
                         let ind: usize = (dest[i] - 0x064B).into();
-                        // ensure the array index is within the range
-                        if dest[i] < 0x064B || ind >= IRRELEVANT_POS.len() {
-                            unreachable!();
-                        }
+                        debug_assert!(ind < IRRELEVANT_POS.len());
                         dest[i] = 0xFE70 + (IRRELEVANT_POS[ind] as u16) + shape;
                     }
                 } else if (curr_link & APRESENT) > 0 {
@@ -764,91 +656,304 @@ fn _shape_arabic(
         }
     }
 
-    // NOTE: Since we are only interested in shaping the Arabic characters,
-    // we do not need to handle the following cases.
-    // Also we can handle spaces more efficiently later.
-    // if (lamalef_found or tashkeel_found) {
-    //     try handleGeneratedSpaces(dest, dest.len, options, shapeVars, allocator);
-    // }
+    // NOTE: The generated spaces, SeenFamily and YehHamza characters are handled by
+    // handle_generated_spaces and expand_composit_char once the buffer is back in
+    // its final order.
+}
 
-    // if (seenfam_found or yehhamza_found) {
-    //     try expandCompositChar(dest, dest.len, options, shapeVars); // allocator);
-    // }
+// Replaces European digits by Arabic-Indic digits (digit_base is 0x0660 or 0x06F0) if the most
+// recent strong character is an Arabic letter. The text is read from its logical start, i.e.
+// backwards for visual LTR text.
+fn shape_to_arabic_digits_with_context(
+    dest: &mut [u16],
+    digit_base: u16,
+    is_logical: bool,
+    mut last_strong_was_al: bool,
+) {
+    let mut update = |c: &mut u16| match get_bidi_class(c) {
+        BidiClass::L | BidiClass::R => last_strong_was_al = false,
+        BidiClass::AL => last_strong_was_al = true,
+        BidiClass::EN if last_strong_was_al && c.wrapping_sub(0x30) < 10 => {
+            *c += digit_base - 0x30;
+        }
+        _ => {}
+    };
+    if is_logical {
+        dest.iter_mut().for_each(&mut update);
+    } else {
+        dest.iter_mut().rev().for_each(&mut update);
+    }
+}
+
+// Digit shaping (U_SHAPE_DIGITS_*), converting between European and Arabic-Indic digits
+fn shape_digits(dest: &mut [u16], options: &u32) {
+    let digit_base: u16 = match options & U_SHAPE_DIGIT_TYPE_MASK {
+        U_SHAPE_DIGIT_TYPE_AN_EXTENDED => 0x06F0, // Eastern Arabic-Indic digits (Persian and Urdu)
+        _ => 0x0660,                              // Arabic-Indic digits
+    };
+    let is_logical = (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_LOGICAL;
+    match options & U_SHAPE_DIGITS_MASK {
+        U_SHAPE_DIGITS_EN2AN => {
+            for c in dest.iter_mut().filter(|c| c.wrapping_sub(0x30) < 10) {
+                *c += digit_base - 0x30;
+            }
+        }
+        U_SHAPE_DIGITS_AN2EN => {
+            for c in dest.iter_mut().filter(|c| c.wrapping_sub(digit_base) < 10) {
+                *c -= digit_base - 0x30;
+            }
+        }
+        U_SHAPE_DIGITS_ALEN2AN_INIT_LR => {
+            shape_to_arabic_digits_with_context(dest, digit_base, is_logical, false);
+        }
+        U_SHAPE_DIGITS_ALEN2AN_INIT_AL => {
+            shape_to_arabic_digits_with_context(dest, digit_base, is_logical, true);
+        }
+        _ => {}
+    }
+}
+
+/// The contextual form of a joining character, i.e. which of its glyphs a font selects
+/// (the OpenType `isol`, `fina`, `init` and `medi` features)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GlyphForm {
+    /// Not joined to its neighbours
+    Isolated,
+    /// Joined to the previous character only
+    Final,
+    /// Joined to the next character only
+    Initial,
+    /// Joined to both neighbours
+    Medial,
+}
+
+// Resolves the form of every character from its own link and the links of its neighbours
+// like _shape_arabic, skipping transparent characters. Non joining and transparent
+// characters get no form. The links are read from their logical start, i.e. backwards for
+// visual LTR text.
+fn resolve_glyph_forms(links: &[u16], is_logical: bool) -> Vec<Option<GlyphForm>> {
+    let order: Vec<usize> =
+        if is_logical { (0..links.len()).collect() } else { (0..links.len()).rev().collect() };
+    let joining: Vec<usize> = order.into_iter().filter(|i| (links[*i] & IRRELEVANT) == 0).collect();
+    let link_of = |k: Option<&usize>| k.map_or(0, |i| usize::from(links[*i] & (LINKR + LINKL)));
+
+    let mut forms = Vec::<Option<GlyphForm>>::with_capacity(links.len());
+    forms.resize(links.len(), None);
+    for (k, i) in joining.iter().enumerate() {
+        let curr_link = link_of(Some(i));
+        if curr_link == 0 {
+            continue;
+        }
+        let last_link = link_of(k.checked_sub(1).and_then(|k| joining.get(k)));
+        let next_link = link_of(joining.get(k + 1));
+        let mut shape: u8 = SHAPE_TABLE[next_link][last_link][curr_link];
+        if curr_link == usize::from(LINKR) {
+            // right joining characters only have an isolated and a final form
+            shape &= 1;
+        }
+        forms[*i] = Some(match shape {
+            0 => GlyphForm::Isolated,
+            1 => GlyphForm::Final,
+            2 => GlyphForm::Initial,
+            _ => GlyphForm::Medial,
+        });
+    }
+
+    forms
 }
 
 /// Shape Arabic text
 pub fn shape_arabic(input: &[u16], options: &u32) -> Vec<u16> {
+    let mut source_map = Vec::<usize>::new();
+    shape_arabic_with_map(input, options, &mut source_map)
+}
+
+/// Shape Arabic text, storing the input position of every output code unit in `source_map`.
+/// Merged characters (e.g. lam-alef ligatures) point to their first input code unit,
+/// removed characters (e.g. tashkeel) have no entry and the spaces kept by the fixed length
+/// lam-alef and tashkeel modes point to the alef or tashkeel they replace.
+pub fn shape_arabic_with_map(
+    input: &[u16],
+    options: &u32,
+    source_map: &mut Vec<usize>,
+) -> Vec<u16> {
+    shape_arabic_with_space_check(input, options, source_map, &mut Vec::new(), &mut true)
+}
+
+/// Same as `shape_arabic_with_map`, but also stores the output position of every input code
+/// unit in `logical_to_visual`. Merged characters (the alef of a lam-alef ligature and the
+/// second mark of a shadda ligature) point to their ligature, removed characters are `None`.
+pub fn shape_arabic_with_maps(
+    input: &[u16],
+    options: &u32,
+    source_map: &mut Vec<usize>,
+    logical_to_visual: &mut Vec<Option<usize>>,
+) -> Vec<u16> {
+    shape_arabic_with_space_check(input, options, source_map, logical_to_visual, &mut true)
+}
+
+/// Same as `shape_arabic_with_map`, but fails with `ShapeError::NoSpaceAvailable` if a fixed
+/// length mode has no space to expand a character into instead of growing the output.
+pub fn try_shape_arabic_with_map(
+    input: &[u16],
+    options: &u32,
+    source_map: &mut Vec<usize>,
+) -> Result<Vec<u16>, ShapeError> {
+    let mut space_available: bool = true;
+    let output = shape_arabic_with_space_check(
+        input,
+        options,
+        source_map,
+        &mut Vec::new(),
+        &mut space_available,
+    );
+    if space_available {
+        Ok(output)
+    } else {
+        Err(ShapeError::NoSpaceAvailable)
+    }
+}
+
+/// Same as `shape_arabic`, but also stores the contextual form of every output code unit in
+/// `forms`. Letters without presentation forms (e.g. most of Arabic Supplement and Arabic
+/// Extended-A/B) keep their code point, so their form is what a font needs to pick the glyph.
+/// Non joining and transparent characters have no form.
+pub fn shape_arabic_with_forms(
+    input: &[u16],
+    options: &u32,
+    forms: &mut Vec<Option<GlyphForm>>,
+) -> Vec<u16> {
+    let output = shape_arabic(input, options);
+    let is_logical = (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_LOGICAL;
+    let links: Vec<u16> = output.iter().map(get_link).collect();
+    *forms = resolve_glyph_forms(&links, is_logical);
+    output
+}
+
+/// Get the joining form of every code unit of logical UTF-16 text without shaping it, for
+/// renderers whose font selects the glyphs itself. Covers every joining script of
+/// ArabicShaping.txt (e.g. Arabic, Syriac, N'Ko, Mongolian and Adlam), where letters of
+/// different scripts don't join each other. Both code units of a surrogate pair take the form
+/// of the code point they encode, non joining and transparent characters have no form.
+pub fn joining_forms(input: &[u16]) -> Vec<Option<GlyphForm>> {
+    let mut links = Vec::<(u16, u8)>::with_capacity(input.len());
+    let mut lens = Vec::<usize>::with_capacity(input.len());
+    for c in char::decode_utf16(input.iter().copied()) {
+        match c {
+            Ok(c) => {
+                links.push(get_joining_link(c.into()));
+                lens.push(c.len_utf16());
+            }
+            Err(_) => {
+                links.push((0, 0));
+                lens.push(1);
+            }
+        }
+    }
+
+    let mut forms = Vec::<Option<GlyphForm>>::with_capacity(input.len());
+    for (form, len) in resolve_script_runs(&links).into_iter().zip(lens) {
+        forms.resize(forms.len() + len, form);
+    }
+    forms
+}
+
+/// Same as `joining_forms`, but for logical text as code points
+pub fn joining_forms_chars(input: &[char]) -> Vec<Option<GlyphForm>> {
+    let links: Vec<(u16, u8)> = input.iter().map(|c| get_joining_link((*c).into())).collect();
+    resolve_script_runs(&links)
+}
+
+// Resolves the forms of logical text per run of a joining script, so that letters of different
+// scripts (e.g. Arabic and Syriac) don't join. Characters joining any script (script 0) stay in
+// the current run.
+fn resolve_script_runs(links: &[(u16, u8)]) -> Vec<Option<GlyphForm>> {
+    let mut forms = Vec::<Option<GlyphForm>>::with_capacity(links.len());
+    let mut run_links = Vec::<u16>::with_capacity(links.len());
+    let mut run_script: u8 = 0;
+    for (link, script) in links {
+        if *script != 0 && run_script != 0 && *script != run_script {
+            forms.extend(resolve_glyph_forms(&run_links, true));
+            run_links.clear();
+        }
+        if *script != 0 {
+            run_script = *script;
+        }
+        run_links.push(*link);
+    }
+    forms.extend(resolve_glyph_forms(&run_links, true));
+    forms
+}
+
+fn shape_arabic_with_space_check(
+    input: &[u16],
+    options: &u32,
+    source_map: &mut Vec<usize>,
+    logical_to_visual: &mut Vec<Option<usize>>,
+    space_available: &mut bool,
+) -> Vec<u16> {
     let mut source_ptr = input;
     let mut tempsource = Vec::<u16>::new();
+    let mut tempmap = Vec::<usize>::new();
+    let mut map_ptr: &[usize] = &[];
+    // [merged, kept] input positions of the marks aggregated into a shadda ligature
+    let mut aggregated = Vec::<(usize, usize)>::new();
 
     if (options & U_SHAPE_AGGREGATE_TASHKEEL_MASK) != 0 {
-        tempsource.resize(input.len() * 2, 0);
         let logical_order: bool =
             (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_LOGICAL;
         let aggregate_tashkeel: bool = (options
             & (U_SHAPE_AGGREGATE_TASHKEEL_MASK + U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED))
             == (U_SHAPE_AGGREGATE_TASHKEEL + U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED);
-        let mut j: usize = 2 * input.len();
-        if logical_order {
-            j = 0;
-        }
-        let mut i: usize = input.len();
-        if logical_order {
-            i = 0;
-        }
-        let mut end: usize = input.len() - 1;
-        if logical_order {
-            end = input.len();
-        }
+        // visual text is aggregated from its end, like the shaping itself
+        let order: Vec<usize> = if logical_order {
+            (0..input.len()).collect()
+        } else {
+            (0..input.len()).rev().collect()
+        };
         let mut aggregation_possible: bool = true;
         let mut prev: u16 = 0;
         let mut prev_link: u16;
         let mut curr_link: u16 = 0;
-        let mut new_source_length: usize = 0;
 
-        while i != end {
+        for i in order {
             prev_link = curr_link;
             curr_link = get_link(&input[i]);
             if aggregate_tashkeel
                 && ((prev_link | curr_link) & COMBINE) == COMBINE
                 && aggregation_possible
             {
+                // replace the previous mark by the shadda ligature
                 aggregation_possible = false;
-                if prev < input[i] {
-                    tempsource[j] = prev - 0x064C + 0xFC5E;
-                } else {
-                    tempsource[j] = input[i] - 0x064C + 0xFC5E;
+                let ligature = prev.min(input[i]) - 0x064C + 0xFC5E;
+                if let (Some(last), Some(last_idx)) = (tempsource.last_mut(), tempmap.last_mut()) {
+                    *last = ligature;
+                    aggregated.push(((*last_idx).max(i), (*last_idx).min(i)));
+                    *last_idx = (*last_idx).min(i);
                 }
-                curr_link = get_link(&tempsource[j]);
+                curr_link = get_link(&ligature);
             } else {
-                new_source_length += 1;
                 aggregation_possible = true;
-                tempsource[j] = input[i];
-                if logical_order {
-                    j += 1;
-                } else {
-                    j -= 1;
-                }
+                tempsource.push(input[i]);
+                tempmap.push(i);
                 prev = input[i];
             }
-            // move one notch forward
-            if logical_order {
-                i += 1;
-            } else {
-                i -= 1;
-            }
         }
-        if logical_order {
-            source_ptr = &tempsource[0..new_source_length];
-        } else {
-            source_ptr = &tempsource[j..new_source_length];
+        if !logical_order {
+            tempsource.reverse();
+            tempmap.reverse();
         }
+        source_ptr = &tempsource;
+        map_ptr = &tempmap;
     }
 
     // prep output
     let output_size = calculate_size(source_ptr, options);
     let mut output = Vec::<u16>::with_capacity(max(output_size, source_ptr.len()));
     output.extend_from_slice(source_ptr);
+    // the input position of every code unit of output
+    let mut output_map: Vec<usize> =
+        if map_ptr.is_empty() { (0..output.len()).collect() } else { map_ptr.to_vec() };
 
     let mut spaces_countl: usize = 0;
     let mut spaces_countr: usize = 0;
@@ -856,19 +961,23 @@ pub fn shape_arabic(input: &[u16], options: &u32) -> Vec<u16> {
     if (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_LOGICAL {
         count_spaces(&output, &mut spaces_countl, &mut spaces_countr);
         invert_buffer(&mut output, spaces_countl, spaces_countr);
+        invert_buffer(&mut output_map, spaces_countl, spaces_countr);
     }
 
-    // NOTE: WE DON'T NEED THIS SINCE WE simplified the whole process. Just not ready to delete
-    // var shapeVars = UShapeVariables.Default();
-    // if ((options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_VISUAL_LTR) {
-    //     if ((options & U_SHAPE_SPACES_RELATIVE_TO_TEXT_MASK) == U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END) {
-    //         shapeVars.spacesRelativeToTextBeginEnd = 1;
-    //         shapeVars.uShapeLamalefBegin = U_SHAPE_LAMALEF_END;
-    //         shapeVars.uShapeLamalefEnd = U_SHAPE_LAMALEF_BEGIN;
-    //         shapeVars.uShapeTashkeelBegin = U_SHAPE_TASHKEEL_END;
-    //         shapeVars.uShapeTashkeelEnd = U_SHAPE_TASHKEEL_BEGIN;
-    //     }
-    // }
+    let mut shape_vars = UShapeVariables::base();
+    if (options & U_SHAPE_TAIL_TYPE_MASK) != U_SHAPE_TAIL_NEW_UNICODE {
+        shape_vars.tail_char = OLD_TAIL_CHAR;
+    }
+    if (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_VISUAL_LTR
+        && (options & U_SHAPE_SPACES_RELATIVE_TO_TEXT_MASK)
+            == U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END
+    {
+        shape_vars.spaces_relative_to_text_begin_end = 1;
+        shape_vars.u_shape_lamalef_begin = U_SHAPE_LAMALEF_END;
+        shape_vars.u_shape_lamalef_end = U_SHAPE_LAMALEF_BEGIN;
+        shape_vars.u_shape_tashkeel_begin = U_SHAPE_TASHKEEL_END;
+        shape_vars.u_shape_tashkeel_end = U_SHAPE_TASHKEEL_BEGIN;
+    }
 
     // Arabic shaping
     match options & U_SHAPE_LETTERS_MASK {
@@ -893,24 +1002,64 @@ pub fn shape_arabic(input: &[u16], options: &u32) -> Vec<u16> {
             _shape_arabic(&mut output, 0);
         }
         _ => {
-            // will never occur because of validity checks above
+            // U_SHAPE_LETTERS_NOOP, U_SHAPE_LETTERS_UNSHAPE works on the final order below
         }
     }
 
     if (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_LOGICAL {
         count_spaces(&output, &mut spaces_countl, &mut spaces_countr);
         invert_buffer(&mut output, spaces_countl, spaces_countr);
+        invert_buffer(&mut output_map, spaces_countl, spaces_countr);
+    }
+
+    // place the spaces generated or consumed by LamAlef, SeenFamily and YehHamza
+    // characters in the final order
+    let is_logical = (options & U_SHAPE_TEXT_DIRECTION_MASK) == U_SHAPE_TEXT_DIRECTION_LOGICAL;
+    match options & U_SHAPE_LETTERS_MASK {
+        U_SHAPE_LETTERS_SHAPE | U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED => {
+            handle_generated_spaces(&mut output, &mut output_map, options, &shape_vars);
+            *space_available &= expand_composit_char(&mut output, options, &shape_vars, is_logical);
+        }
+        U_SHAPE_LETTERS_UNSHAPE => {
+            deshape_unicode(&mut output, options, is_logical);
+            *space_available &=
+                expand_lam_alef(&mut output, &mut output_map, options, &shape_vars, is_logical);
+        }
+        _ => {}
     }
     // End of Arabic letter shaping part
 
     // copy a slice to a new slice "arabic_output" of output_size
     // and run through output, skip every LAMALEF_SPACE_SUB and TASHKEEL_SPACE_SUB
     let mut arabic_output = Vec::<u16>::with_capacity(output_size);
-    for ch in output {
-        if ch != LAMALEF_SPACE_SUB && ch != TASHKEEL_SPACE_SUB {
+    source_map.clear();
+    logical_to_visual.clear();
+    logical_to_visual.resize(input.len(), None);
+    // the alefs of visual text wait for their lam-alef ligature, which follows them
+    let mut merged_alefs = Vec::<usize>::new();
+    for (ch, idx) in output.into_iter().zip(output_map) {
+        if ch == LAMALEF_SPACE_SUB {
+            // only marks separate the alef from its ligature
+            if is_logical {
+                logical_to_visual[idx] = arabic_output.iter().rposition(is_lam_alef_char);
+            } else {
+                merged_alefs.push(idx);
+            }
+        } else if ch != TASHKEEL_SPACE_SUB {
+            if is_lam_alef_char(&ch) {
+                for alef in merged_alefs.drain(..) {
+                    logical_to_visual[alef] = Some(arabic_output.len());
+                }
+            }
+            logical_to_visual[idx].get_or_insert(arabic_output.len());
             arabic_output.push(ch);
+            source_map.push(idx);
         }
     }
+    for (merged, kept) in aggregated {
+        logical_to_visual[merged] = logical_to_visual[kept];
+    }
+    shape_digits(&mut arabic_output, options);
 
     arabic_output
 }
@@ -0,0 +1,99 @@
+use crate::shape::*;
+use core::fmt;
+
+/// The reasons `try_shape_unicode` rejects its input or options
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    /// The options have a reserved value or a bit that is not part of any option
+    InvalidOptions(InvalidShapeOptions),
+    /// The options are valid on their own but can not be combined
+    IncompatibleOptions(&'static str),
+    /// The input has a surrogate code unit at this index that is not part of a pair
+    UnpairedSurrogate(usize),
+    /// A fixed length mode (e.g. `U_SHAPE_LAMALEF_NEAR`) found no space to expand a character into
+    NoSpaceAvailable,
+}
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::InvalidOptions(err) => err.fmt(f),
+            ShapeError::IncompatibleOptions(reason) => write!(f, "incompatible options: {reason}"),
+            ShapeError::UnpairedSurrogate(idx) => write!(f, "unpaired surrogate at index {idx}"),
+            ShapeError::NoSpaceAvailable => write!(f, "no space available to expand a character"),
+        }
+    }
+}
+
+impl From<InvalidShapeOptions> for ShapeError {
+    fn from(err: InvalidShapeOptions) -> ShapeError {
+        ShapeError::InvalidOptions(err)
+    }
+}
+
+/// Check that the `U_SHAPE_*` bits are valid and can be combined, like ICU's `u_shapeArabic`
+pub fn validate_options(options: &u32) -> Result<ShapeOptions, ShapeError> {
+    let shape_options = ShapeOptions::from_bits(*options)?;
+    if shape_options.letters == LettersMode::Unshape && shape_options.tashkeel != TashkeelMode::Noop
+    {
+        return Err(ShapeError::IncompatibleOptions("tashkeel handling needs letter shaping"));
+    }
+    if shape_options.aggregate_tashkeel {
+        if shape_options.letters != LettersMode::ShapeTashkeelIsolated {
+            return Err(ShapeError::IncompatibleOptions(
+                "tashkeel aggregation needs isolated tashkeel shaping",
+            ));
+        }
+        if shape_options.lam_alef != LamAlefMode::Resize {
+            return Err(ShapeError::IncompatibleOptions(
+                "tashkeel aggregation needs the resize lam-alef mode",
+            ));
+        }
+    }
+
+    Ok(shape_options)
+}
+
+/// Check that every surrogate code unit of the input is part of a pair
+pub fn validate_utf16(input: &[u16]) -> Result<(), ShapeError> {
+    let mut idx: usize = 0;
+    for c in char::decode_utf16(input.iter().copied()) {
+        match c {
+            Ok(c) => idx += c.len_utf16(),
+            Err(_) => return Err(ShapeError::UnpairedSurrogate(idx)),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options() {
+        let shape = U_SHAPE_LETTERS_SHAPE | U_SHAPE_DIRECTION_OUTPUT_BIDI;
+        assert!(validate_options(&shape).is_ok());
+        assert_eq!(
+            validate_options(&U_SHAPE_DIGITS_RESERVED),
+            Err(ShapeError::InvalidOptions(InvalidShapeOptions(U_SHAPE_DIGITS_RESERVED)))
+        );
+        let unshape = U_SHAPE_LETTERS_UNSHAPE | U_SHAPE_TASHKEEL_RESIZE;
+        assert!(matches!(validate_options(&unshape), Err(ShapeError::IncompatibleOptions(_))));
+        let aggregate = U_SHAPE_AGGREGATE_TASHKEEL | U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED;
+        assert!(validate_options(&aggregate).is_ok());
+        assert!(validate_options(&(aggregate | U_SHAPE_LAMALEF_NEAR)).is_err());
+        assert!(validate_options(&(U_SHAPE_AGGREGATE_TASHKEEL | U_SHAPE_LETTERS_SHAPE)).is_err());
+    }
+
+    #[test]
+    fn utf16() {
+        assert_eq!(validate_utf16(&[]), Ok(()));
+        assert_eq!(validate_utf16(&[0x61, 0xD83A, 0xDD00]), Ok(()));
+        assert_eq!(
+            validate_utf16(&[0x61, 0xD83A, 0xDD00, 0xDD00]),
+            Err(ShapeError::UnpairedSurrogate(3))
+        );
+        assert_eq!(validate_utf16(&[0xD83A, 0x61]), Err(ShapeError::UnpairedSurrogate(0)));
+    }
+}
@@ -210,6 +210,20 @@ pub const U_SHAPE_TEXT_DIRECTION_VISUAL_LTR: u32 = 4;
 // ** Bit mask for direction output. Made uniquely for this library. - @CraigglesO
 pub const U_SHAPE_DIRECTION_OUTPUT_BIDI: u32 = 1 << 20;
 
+// ** Base direction of the bidi output: detect it from the first strong character.
+// Made uniquely for this library. Like every U_SHAPE_* bit it is private to the crate, Rust
+// callers set it with ShapeOptions::base_direction.
+pub const U_SHAPE_BASE_DIRECTION_AUTO: u32 = 0;
+
+// ** Base direction of the bidi output: force left-to-right. Made uniquely for this library.
+pub const U_SHAPE_BASE_DIRECTION_LTR: u32 = 0x10000000;
+
+// ** Base direction of the bidi output: force right-to-left. Made uniquely for this library.
+pub const U_SHAPE_BASE_DIRECTION_RTL: u32 = 0x20000000;
+
+// ** Bit mask for the base direction of the bidi output. Made uniquely for this library.
+pub const U_SHAPE_BASE_DIRECTION_MASK: u32 = 0x30000000;
+
 // ** Bit mask for direction indicators. @stable ICU 2.
 pub const U_SHAPE_TEXT_DIRECTION_MASK: u32 = 4;
 
@@ -478,186 +492,1277 @@ pub static TAIL_FAMILY_ISOLATED_FINAL: [u8; 14] = [
     1, //* FEBE */
 ];
 
-pub static ARA_LINK: [u16; 178] = [
-    1 + 32 + 256 * 0x11, // 0x0622*/
-    1 + 32 + 256 * 0x13, // 0x0623*/
-    1 + 256 * 0x15,      // 0x0624*/
-    1 + 32 + 256 * 0x17, // 0x0625*/
-    1 + 2 + 256 * 0x19,  // 0x0626*/
-    1 + 32 + 256 * 0x1D, // 0x0627*/
-    1 + 2 + 256 * 0x1F,  // 0x0628*/
-    1 + 256 * 0x23,      // 0x0629*/
-    1 + 2 + 256 * 0x25,  // 0x062A*/
-    1 + 2 + 256 * 0x29,  // 0x062B*/
-    1 + 2 + 256 * 0x2D,  // 0x062C*/
-    1 + 2 + 256 * 0x31,  // 0x062D*/
-    1 + 2 + 256 * 0x35,  // 0x062E*/
-    1 + 256 * 0x39,      // 0x062F*/
-    1 + 256 * 0x3B,      // 0x0630*/
-    1 + 256 * 0x3D,      // 0x0631*/
-    1 + 256 * 0x3F,      // 0x0632*/
-    1 + 2 + 256 * 0x41,  // 0x0633*/
-    1 + 2 + 256 * 0x45,  // 0x0634*/
-    1 + 2 + 256 * 0x49,  // 0x0635*/
-    1 + 2 + 256 * 0x4D,  // 0x0636*/
-    1 + 2 + 256 * 0x51,  // 0x0637*/
-    1 + 2 + 256 * 0x55,  // 0x0638*/
-    1 + 2 + 256 * 0x59,  // 0x0639*/
-    1 + 2 + 256 * 0x5D,  // 0x063A*/
-    0,
-    0,
-    0,
-    0,
-    0,                       // 0x063B-0x063F*/
-    1 + 2,                   // 0x0640*/
-    1 + 2 + 256 * 0x61,      // 0x0641*/
-    1 + 2 + 256 * 0x65,      // 0x0642*/
-    1 + 2 + 256 * 0x69,      // 0x0643*/
-    1 + 2 + 16 + 256 * 0x6D, // 0x0644*/
-    1 + 2 + 256 * 0x71,      // 0x0645*/
-    1 + 2 + 256 * 0x75,      // 0x0646*/
-    1 + 2 + 256 * 0x79,      // 0x0647*/
-    1 + 256 * 0x7D,          // 0x0648*/
-    1 + 256 * 0x7F,          // 0x0649*/
-    1 + 2 + 256 * 0x81,      // 0x064A*/
-    4 + 256,                 // 0x064B*/ (4 + 256 * 1)
-    4 + 128 + 256,           // 0x064C*/ ( 4 + 128 + 256 * 1)
-    4 + 128 + 256,           // 0x064D*/ ( 4 + 128 + 256 * 1)
-    4 + 128 + 256,           // 0x064E*/ ( 4 + 128 + 256 * 1)
-    4 + 128 + 256,           // 0x064F*/ ( 4 + 128 + 256 * 1)
-    4 + 128 + 256,           // 0x0650*/ ( 4 + 128 + 256 * 1)
-    4 + 64 + 256 * 3,        // 0x0651*/
-    4 + 256,                 // 0x0652*/ (4 + 256 * 1)
-    4 + 256 * 7,             // 0x0653*/
-    4 + 256 * 8,             // 0x0654*/
-    4 + 256 * 8,             // 0x0655*/
-    4 + 256,                 // 0x0656*/ (4 + 256 * 1)
-    0,
-    0,
-    0,
-    0,
-    0,              // 0x0657-0x065B*/
-    1 + 256 * 0x85, // 0x065C*/
-    1 + 256 * 0x87, // 0x065D*/
-    1 + 256 * 0x89, // 0x065E*/
-    1 + 256 * 0x8B, // 0x065F*/
-    0,
-    0,
-    0,
-    0,
-    0, // 0x0660-0x0664*/
-    0,
-    0,
-    0,
-    0,
-    0, // 0x0665-0x0669*/
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,           // 0x066A-0x066F*/
-    4 + 256 * 6, // 0x0670*/
-    1 + 8,       // 0x0671*/ (1 + 8 + 256 * 0x00)
-    1 + 32,      // 0x0672*/
-    1 + 32,      // 0x0673*/
-    0,           // 0x0674*/
-    1 + 32,      // 0x0675*/
-    1,
-    1,                      // 0x0676-0x0677*/
-    1 + 2,                  // 0x0678*/
-    1 + 2 + 8 + 256 * 0x16, // 0x0679*/
-    1 + 2 + 8 + 256 * 0x0E, // 0x067A*/
-    1 + 2 + 8 + 256 * 0x02, // 0x067B*/
-    1 + 2,
-    1 + 2, // 0x67C-0x067D*/
-    1 + 2 + 8 + 256 * 0x06,
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2, // 0x067E-0x0683*/
-    1 + 2,
-    1 + 2,
-    1 + 2 + 8 + 256 * 0x2A,
-    1 + 2,              // 0x0684-0x0687*/
-    1 + 8 + 256 * 0x38, // 0x0688*/
-    1,
-    1,
-    1,                  // 0x0689-0x068B*/
-    1 + 8 + 256 * 0x34, // 0x068C*/
-    1 + 8 + 256 * 0x32, // 0x068D*/
-    1 + 8 + 256 * 0x36, // 0x068E*/
-    1,
-    1,                  // 0x068F-0x0690*/
-    1 + 8 + 256 * 0x3C, // 0x0691*/
-    1,
-    1,
-    1,
-    1,
-    1,
-    1,
-    1 + 8 + 256 * 0x3A,
-    1, // 0x0692-0x0699*/
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2, // 0x069A-0x06A3*/
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2, // 0x069A-0x06A3*/
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2 + 8 + 256 * 0x3E, // 0x06A4-0x06AD*/
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2, // 0x06A4-0x06AD*/
-    1 + 2,
-    1 + 2 + 8 + 256 * 0x42,
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2, // 0x06AE-0x06B7*/
-    1 + 2,
-    1 + 2,
-    1 + 2,
-    1 + 2, // 0x06AE-0x06B7*/
-    1 + 2,
-    1 + 2,                  // 0x06B8-0x06B9*/
-    1 + 8 + 256 * 0x4E,     // 0x06BA*/
-    1 + 2 + 8 + 256 * 0x50, // 0x06BB*/
-    1 + 2,
-    1 + 2,                  // 0x06BC-0x06BD*/
-    1 + 2 + 8 + 256 * 0x5A, // 0x06BE*/
-    1 + 2,                  // 0x06BF*/
-    1 + 8 + 256 * 0x54,     // 0x06C0*/
-    1 + 2 + 8 + 256 * 0x56, // 0x06C1*/
-    1,
-    1,
-    1,                      // 0x06C2-0x06C4*/
-    1 + 8 + 256 * 0x90,     // 0x06C5*/
-    1 + 8 + 256 * 0x89,     // 0x06C6*/
-    1 + 8 + 256 * 0x87,     // 0x06C7*/
-    1 + 8 + 256 * 0x8B,     // 0x06C8*/
-    1 + 8 + 256 * 0x92,     // 0x06C9*/
-    1,                      // 0x06CA*/
-    1 + 8 + 256 * 0x8E,     // 0x06CB*/
-    1 + 2 + 8 + 256 * 0xAC, // 0x06CC*/
-    1,                      // 0x06CD*/
-    1 + 2,
-    1 + 2,                  // 0x06CE-0x06CF*/
-    1 + 2 + 8 + 256 * 0x94, // 0x06D0*/
-    1 + 2,                  // 0x06D1*/
-    1 + 8 + 256 * 0x5E,     // 0x06D2*/
-    1 + 8 + 256 * 0x60,     // 0x06D3*/
+// BEGIN GENERATED ARA_LINK (cargo xtask gen-tables)
+pub static ARA_LINK: [u16; 734] = [
+    1 + 32 + 256 * 0x11,     // 0x0622
+    1 + 32 + 256 * 0x13,     // 0x0623
+    1 + 256 * 0x15,          // 0x0624
+    1 + 32 + 256 * 0x17,     // 0x0625
+    1 + 2 + 256 * 0x19,      // 0x0626
+    1 + 32 + 256 * 0x1D,     // 0x0627
+    1 + 2 + 256 * 0x1F,      // 0x0628
+    1 + 256 * 0x23,          // 0x0629
+    1 + 2 + 256 * 0x25,      // 0x062A
+    1 + 2 + 256 * 0x29,      // 0x062B
+    1 + 2 + 256 * 0x2D,      // 0x062C
+    1 + 2 + 256 * 0x31,      // 0x062D
+    1 + 2 + 256 * 0x35,      // 0x062E
+    1 + 256 * 0x39,          // 0x062F
+    1 + 256 * 0x3B,          // 0x0630
+    1 + 256 * 0x3D,          // 0x0631
+    1 + 256 * 0x3F,          // 0x0632
+    1 + 2 + 256 * 0x41,      // 0x0633
+    1 + 2 + 256 * 0x45,      // 0x0634
+    1 + 2 + 256 * 0x49,      // 0x0635
+    1 + 2 + 256 * 0x4D,      // 0x0636
+    1 + 2 + 256 * 0x51,      // 0x0637
+    1 + 2 + 256 * 0x55,      // 0x0638
+    1 + 2 + 256 * 0x59,      // 0x0639
+    1 + 2 + 256 * 0x5D,      // 0x063A
+    1 + 2,                   // 0x063B
+    1 + 2,                   // 0x063C
+    1 + 2,                   // 0x063D
+    1 + 2,                   // 0x063E
+    1 + 2,                   // 0x063F
+    1 + 2,                   // 0x0640
+    1 + 2 + 256 * 0x61,      // 0x0641
+    1 + 2 + 256 * 0x65,      // 0x0642
+    1 + 2 + 256 * 0x69,      // 0x0643
+    1 + 2 + 16 + 256 * 0x6D, // 0x0644
+    1 + 2 + 256 * 0x71,      // 0x0645
+    1 + 2 + 256 * 0x75,      // 0x0646
+    1 + 2 + 256 * 0x79,      // 0x0647
+    1 + 256 * 0x7D,          // 0x0648
+    1 + 256 * 0x7F,          // 0x0649
+    1 + 2 + 256 * 0x81,      // 0x064A
+    4 + 256,                 // 0x064B
+    4 + 128 + 256,           // 0x064C
+    4 + 128 + 256,           // 0x064D
+    4 + 128 + 256,           // 0x064E
+    4 + 128 + 256,           // 0x064F
+    4 + 128 + 256,           // 0x0650
+    4 + 64 + 256 * 3,        // 0x0651
+    4 + 256,                 // 0x0652
+    4 + 256 * 7,             // 0x0653
+    4 + 256 * 8,             // 0x0654
+    4 + 256 * 8,             // 0x0655
+    4 + 256,                 // 0x0656
+    4,                       // 0x0657
+    4,                       // 0x0658
+    4,                       // 0x0659
+    4,                       // 0x065A
+    4,                       // 0x065B
+    1 + 256 * 0x85,          // 0x065C
+    1 + 256 * 0x87,          // 0x065D
+    1 + 256 * 0x89,          // 0x065E
+    1 + 256 * 0x8B,          // 0x065F
+    0,                       // 0x0660
+    0,                       // 0x0661
+    0,                       // 0x0662
+    0,                       // 0x0663
+    0,                       // 0x0664
+    0,                       // 0x0665
+    0,                       // 0x0666
+    0,                       // 0x0667
+    0,                       // 0x0668
+    0,                       // 0x0669
+    0,                       // 0x066A
+    0,                       // 0x066B
+    0,                       // 0x066C
+    0,                       // 0x066D
+    1 + 2,                   // 0x066E
+    1 + 2,                   // 0x066F
+    4 + 256 * 6,             // 0x0670
+    1 + 8,                   // 0x0671
+    1 + 32,                  // 0x0672
+    1 + 32,                  // 0x0673
+    0,                       // 0x0674
+    1 + 32,                  // 0x0675
+    1,                       // 0x0676
+    1,                       // 0x0677
+    1 + 2,                   // 0x0678
+    1 + 2 + 8 + 256 * 0x16,  // 0x0679
+    1 + 2 + 8 + 256 * 0x0E,  // 0x067A
+    1 + 2 + 8 + 256 * 0x02,  // 0x067B
+    1 + 2,                   // 0x067C
+    1 + 2,                   // 0x067D
+    1 + 2 + 8 + 256 * 0x06,  // 0x067E
+    1 + 2 + 8 + 256 * 0x12,  // 0x067F
+    1 + 2 + 8 + 256 * 0x0A,  // 0x0680
+    1 + 2,                   // 0x0681
+    1 + 2,                   // 0x0682
+    1 + 2 + 8 + 256 * 0x26,  // 0x0683
+    1 + 2 + 8 + 256 * 0x22,  // 0x0684
+    1 + 2,                   // 0x0685
+    1 + 2 + 8 + 256 * 0x2A,  // 0x0686
+    1 + 2 + 8 + 256 * 0x2E,  // 0x0687
+    1 + 8 + 256 * 0x38,      // 0x0688
+    1,                       // 0x0689
+    1,                       // 0x068A
+    1,                       // 0x068B
+    1 + 8 + 256 * 0x34,      // 0x068C
+    1 + 8 + 256 * 0x32,      // 0x068D
+    1 + 8 + 256 * 0x36,      // 0x068E
+    1,                       // 0x068F
+    1,                       // 0x0690
+    1 + 8 + 256 * 0x3C,      // 0x0691
+    1,                       // 0x0692
+    1,                       // 0x0693
+    1,                       // 0x0694
+    1,                       // 0x0695
+    1,                       // 0x0696
+    1,                       // 0x0697
+    1 + 8 + 256 * 0x3A,      // 0x0698
+    1,                       // 0x0699
+    1 + 2,                   // 0x069A
+    1 + 2,                   // 0x069B
+    1 + 2,                   // 0x069C
+    1 + 2,                   // 0x069D
+    1 + 2,                   // 0x069E
+    1 + 2,                   // 0x069F
+    1 + 2,                   // 0x06A0
+    1 + 2,                   // 0x06A1
+    1 + 2,                   // 0x06A2
+    1 + 2,                   // 0x06A3
+    1 + 2 + 8 + 256 * 0x1A,  // 0x06A4
+    1 + 2,                   // 0x06A5
+    1 + 2 + 8 + 256 * 0x1E,  // 0x06A6
+    1 + 2,                   // 0x06A7
+    1 + 2,                   // 0x06A8
+    1 + 2 + 8 + 256 * 0x3E,  // 0x06A9
+    1 + 2,                   // 0x06AA
+    1 + 2,                   // 0x06AB
+    1 + 2,                   // 0x06AC
+    1 + 2 + 8 + 256 * 0x83,  // 0x06AD
+    1 + 2,                   // 0x06AE
+    1 + 2 + 8 + 256 * 0x42,  // 0x06AF
+    1 + 2,                   // 0x06B0
+    1 + 2 + 8 + 256 * 0x4A,  // 0x06B1
+    1 + 2,                   // 0x06B2
+    1 + 2 + 8 + 256 * 0x46,  // 0x06B3
+    1 + 2,                   // 0x06B4
+    1 + 2,                   // 0x06B5
+    1 + 2,                   // 0x06B6
+    1 + 2,                   // 0x06B7
+    1 + 2,                   // 0x06B8
+    1 + 2,                   // 0x06B9
+    1 + 8 + 256 * 0x4E,      // 0x06BA
+    1 + 2 + 8 + 256 * 0x50,  // 0x06BB
+    1 + 2,                   // 0x06BC
+    1 + 2,                   // 0x06BD
+    1 + 2 + 8 + 256 * 0x5A,  // 0x06BE
+    1 + 2,                   // 0x06BF
+    1 + 8 + 256 * 0x54,      // 0x06C0
+    1 + 2 + 8 + 256 * 0x56,  // 0x06C1
+    1 + 2,                   // 0x06C2
+    1,                       // 0x06C3
+    1,                       // 0x06C4
+    1 + 8 + 256 * 0x90,      // 0x06C5
+    1 + 8 + 256 * 0x89,      // 0x06C6
+    1 + 8 + 256 * 0x87,      // 0x06C7
+    1 + 8 + 256 * 0x8B,      // 0x06C8
+    1 + 8 + 256 * 0x92,      // 0x06C9
+    1,                       // 0x06CA
+    1 + 8 + 256 * 0x8E,      // 0x06CB
+    1 + 2 + 8 + 256 * 0xAC,  // 0x06CC
+    1,                       // 0x06CD
+    1 + 2,                   // 0x06CE
+    1,                       // 0x06CF
+    1 + 2 + 8 + 256 * 0x94,  // 0x06D0
+    1 + 2,                   // 0x06D1
+    1 + 8 + 256 * 0x5E,      // 0x06D2
+    1 + 8 + 256 * 0x60,      // 0x06D3
+    0,                       // 0x06D4
+    1,                       // 0x06D5
+    4,                       // 0x06D6
+    4,                       // 0x06D7
+    4,                       // 0x06D8
+    4,                       // 0x06D9
+    4,                       // 0x06DA
+    4,                       // 0x06DB
+    4,                       // 0x06DC
+    4,                       // 0x06DD
+    0,                       // 0x06DE
+    4,                       // 0x06DF
+    4,                       // 0x06E0
+    4,                       // 0x06E1
+    4,                       // 0x06E2
+    4,                       // 0x06E3
+    4,                       // 0x06E4
+    0,                       // 0x06E5
+    0,                       // 0x06E6
+    4,                       // 0x06E7
+    4,                       // 0x06E8
+    0,                       // 0x06E9
+    4,                       // 0x06EA
+    4,                       // 0x06EB
+    4,                       // 0x06EC
+    4,                       // 0x06ED
+    1,                       // 0x06EE
+    1,                       // 0x06EF
+    0,                       // 0x06F0
+    0,                       // 0x06F1
+    0,                       // 0x06F2
+    0,                       // 0x06F3
+    0,                       // 0x06F4
+    0,                       // 0x06F5
+    0,                       // 0x06F6
+    0,                       // 0x06F7
+    0,                       // 0x06F8
+    0,                       // 0x06F9
+    1 + 2,                   // 0x06FA
+    1 + 2,                   // 0x06FB
+    1 + 2,                   // 0x06FC
+    0,                       // 0x06FD
+    0,                       // 0x06FE
+    1 + 2,                   // 0x06FF
+    0,                       // 0x0700
+    0,                       // 0x0701
+    0,                       // 0x0702
+    0,                       // 0x0703
+    0,                       // 0x0704
+    0,                       // 0x0705
+    0,                       // 0x0706
+    0,                       // 0x0707
+    0,                       // 0x0708
+    0,                       // 0x0709
+    0,                       // 0x070A
+    0,                       // 0x070B
+    0,                       // 0x070C
+    0,                       // 0x070D
+    0,                       // 0x070E
+    0,                       // 0x070F
+    0,                       // 0x0710
+    0,                       // 0x0711
+    0,                       // 0x0712
+    0,                       // 0x0713
+    0,                       // 0x0714
+    0,                       // 0x0715
+    0,                       // 0x0716
+    0,                       // 0x0717
+    0,                       // 0x0718
+    0,                       // 0x0719
+    0,                       // 0x071A
+    0,                       // 0x071B
+    0,                       // 0x071C
+    0,                       // 0x071D
+    0,                       // 0x071E
+    0,                       // 0x071F
+    0,                       // 0x0720
+    0,                       // 0x0721
+    0,                       // 0x0722
+    0,                       // 0x0723
+    0,                       // 0x0724
+    0,                       // 0x0725
+    0,                       // 0x0726
+    0,                       // 0x0727
+    0,                       // 0x0728
+    0,                       // 0x0729
+    0,                       // 0x072A
+    0,                       // 0x072B
+    0,                       // 0x072C
+    0,                       // 0x072D
+    0,                       // 0x072E
+    0,                       // 0x072F
+    0,                       // 0x0730
+    0,                       // 0x0731
+    0,                       // 0x0732
+    0,                       // 0x0733
+    0,                       // 0x0734
+    0,                       // 0x0735
+    0,                       // 0x0736
+    0,                       // 0x0737
+    0,                       // 0x0738
+    0,                       // 0x0739
+    0,                       // 0x073A
+    0,                       // 0x073B
+    0,                       // 0x073C
+    0,                       // 0x073D
+    0,                       // 0x073E
+    0,                       // 0x073F
+    0,                       // 0x0740
+    0,                       // 0x0741
+    0,                       // 0x0742
+    0,                       // 0x0743
+    0,                       // 0x0744
+    0,                       // 0x0745
+    0,                       // 0x0746
+    0,                       // 0x0747
+    0,                       // 0x0748
+    0,                       // 0x0749
+    0,                       // 0x074A
+    0,                       // 0x074B
+    0,                       // 0x074C
+    0,                       // 0x074D
+    0,                       // 0x074E
+    0,                       // 0x074F
+    1 + 2,                   // 0x0750
+    1 + 2,                   // 0x0751
+    1 + 2,                   // 0x0752
+    1 + 2,                   // 0x0753
+    1 + 2,                   // 0x0754
+    1 + 2,                   // 0x0755
+    1 + 2,                   // 0x0756
+    1 + 2,                   // 0x0757
+    1 + 2,                   // 0x0758
+    1,                       // 0x0759
+    1,                       // 0x075A
+    1,                       // 0x075B
+    1 + 2,                   // 0x075C
+    1 + 2,                   // 0x075D
+    1 + 2,                   // 0x075E
+    1 + 2,                   // 0x075F
+    1 + 2,                   // 0x0760
+    1 + 2,                   // 0x0761
+    1 + 2,                   // 0x0762
+    1 + 2,                   // 0x0763
+    1 + 2,                   // 0x0764
+    1 + 2,                   // 0x0765
+    1 + 2,                   // 0x0766
+    1 + 2,                   // 0x0767
+    1 + 2,                   // 0x0768
+    1 + 2,                   // 0x0769
+    1 + 2,                   // 0x076A
+    1,                       // 0x076B
+    1,                       // 0x076C
+    1 + 2,                   // 0x076D
+    1 + 2,                   // 0x076E
+    1 + 2,                   // 0x076F
+    1 + 2,                   // 0x0770
+    1,                       // 0x0771
+    1 + 2,                   // 0x0772
+    1,                       // 0x0773
+    1,                       // 0x0774
+    1 + 2,                   // 0x0775
+    1 + 2,                   // 0x0776
+    1 + 2,                   // 0x0777
+    1,                       // 0x0778
+    1,                       // 0x0779
+    1 + 2,                   // 0x077A
+    1 + 2,                   // 0x077B
+    1 + 2,                   // 0x077C
+    1 + 2,                   // 0x077D
+    1 + 2,                   // 0x077E
+    1 + 2,                   // 0x077F
+    0,                       // 0x0780
+    0,                       // 0x0781
+    0,                       // 0x0782
+    0,                       // 0x0783
+    0,                       // 0x0784
+    0,                       // 0x0785
+    0,                       // 0x0786
+    0,                       // 0x0787
+    0,                       // 0x0788
+    0,                       // 0x0789
+    0,                       // 0x078A
+    0,                       // 0x078B
+    0,                       // 0x078C
+    0,                       // 0x078D
+    0,                       // 0x078E
+    0,                       // 0x078F
+    0,                       // 0x0790
+    0,                       // 0x0791
+    0,                       // 0x0792
+    0,                       // 0x0793
+    0,                       // 0x0794
+    0,                       // 0x0795
+    0,                       // 0x0796
+    0,                       // 0x0797
+    0,                       // 0x0798
+    0,                       // 0x0799
+    0,                       // 0x079A
+    0,                       // 0x079B
+    0,                       // 0x079C
+    0,                       // 0x079D
+    0,                       // 0x079E
+    0,                       // 0x079F
+    0,                       // 0x07A0
+    0,                       // 0x07A1
+    0,                       // 0x07A2
+    0,                       // 0x07A3
+    0,                       // 0x07A4
+    0,                       // 0x07A5
+    0,                       // 0x07A6
+    0,                       // 0x07A7
+    0,                       // 0x07A8
+    0,                       // 0x07A9
+    0,                       // 0x07AA
+    0,                       // 0x07AB
+    0,                       // 0x07AC
+    0,                       // 0x07AD
+    0,                       // 0x07AE
+    0,                       // 0x07AF
+    0,                       // 0x07B0
+    0,                       // 0x07B1
+    0,                       // 0x07B2
+    0,                       // 0x07B3
+    0,                       // 0x07B4
+    0,                       // 0x07B5
+    0,                       // 0x07B6
+    0,                       // 0x07B7
+    0,                       // 0x07B8
+    0,                       // 0x07B9
+    0,                       // 0x07BA
+    0,                       // 0x07BB
+    0,                       // 0x07BC
+    0,                       // 0x07BD
+    0,                       // 0x07BE
+    0,                       // 0x07BF
+    0,                       // 0x07C0
+    0,                       // 0x07C1
+    0,                       // 0x07C2
+    0,                       // 0x07C3
+    0,                       // 0x07C4
+    0,                       // 0x07C5
+    0,                       // 0x07C6
+    0,                       // 0x07C7
+    0,                       // 0x07C8
+    0,                       // 0x07C9
+    0,                       // 0x07CA
+    0,                       // 0x07CB
+    0,                       // 0x07CC
+    0,                       // 0x07CD
+    0,                       // 0x07CE
+    0,                       // 0x07CF
+    0,                       // 0x07D0
+    0,                       // 0x07D1
+    0,                       // 0x07D2
+    0,                       // 0x07D3
+    0,                       // 0x07D4
+    0,                       // 0x07D5
+    0,                       // 0x07D6
+    0,                       // 0x07D7
+    0,                       // 0x07D8
+    0,                       // 0x07D9
+    0,                       // 0x07DA
+    0,                       // 0x07DB
+    0,                       // 0x07DC
+    0,                       // 0x07DD
+    0,                       // 0x07DE
+    0,                       // 0x07DF
+    0,                       // 0x07E0
+    0,                       // 0x07E1
+    0,                       // 0x07E2
+    0,                       // 0x07E3
+    0,                       // 0x07E4
+    0,                       // 0x07E5
+    0,                       // 0x07E6
+    0,                       // 0x07E7
+    0,                       // 0x07E8
+    0,                       // 0x07E9
+    0,                       // 0x07EA
+    0,                       // 0x07EB
+    0,                       // 0x07EC
+    0,                       // 0x07ED
+    0,                       // 0x07EE
+    0,                       // 0x07EF
+    0,                       // 0x07F0
+    0,                       // 0x07F1
+    0,                       // 0x07F2
+    0,                       // 0x07F3
+    0,                       // 0x07F4
+    0,                       // 0x07F5
+    0,                       // 0x07F6
+    0,                       // 0x07F7
+    0,                       // 0x07F8
+    0,                       // 0x07F9
+    0,                       // 0x07FA
+    0,                       // 0x07FB
+    0,                       // 0x07FC
+    0,                       // 0x07FD
+    0,                       // 0x07FE
+    0,                       // 0x07FF
+    0,                       // 0x0800
+    0,                       // 0x0801
+    0,                       // 0x0802
+    0,                       // 0x0803
+    0,                       // 0x0804
+    0,                       // 0x0805
+    0,                       // 0x0806
+    0,                       // 0x0807
+    0,                       // 0x0808
+    0,                       // 0x0809
+    0,                       // 0x080A
+    0,                       // 0x080B
+    0,                       // 0x080C
+    0,                       // 0x080D
+    0,                       // 0x080E
+    0,                       // 0x080F
+    0,                       // 0x0810
+    0,                       // 0x0811
+    0,                       // 0x0812
+    0,                       // 0x0813
+    0,                       // 0x0814
+    0,                       // 0x0815
+    0,                       // 0x0816
+    0,                       // 0x0817
+    0,                       // 0x0818
+    0,                       // 0x0819
+    0,                       // 0x081A
+    0,                       // 0x081B
+    0,                       // 0x081C
+    0,                       // 0x081D
+    0,                       // 0x081E
+    0,                       // 0x081F
+    0,                       // 0x0820
+    0,                       // 0x0821
+    0,                       // 0x0822
+    0,                       // 0x0823
+    0,                       // 0x0824
+    0,                       // 0x0825
+    0,                       // 0x0826
+    0,                       // 0x0827
+    0,                       // 0x0828
+    0,                       // 0x0829
+    0,                       // 0x082A
+    0,                       // 0x082B
+    0,                       // 0x082C
+    0,                       // 0x082D
+    0,                       // 0x082E
+    0,                       // 0x082F
+    0,                       // 0x0830
+    0,                       // 0x0831
+    0,                       // 0x0832
+    0,                       // 0x0833
+    0,                       // 0x0834
+    0,                       // 0x0835
+    0,                       // 0x0836
+    0,                       // 0x0837
+    0,                       // 0x0838
+    0,                       // 0x0839
+    0,                       // 0x083A
+    0,                       // 0x083B
+    0,                       // 0x083C
+    0,                       // 0x083D
+    0,                       // 0x083E
+    0,                       // 0x083F
+    0,                       // 0x0840
+    0,                       // 0x0841
+    0,                       // 0x0842
+    0,                       // 0x0843
+    0,                       // 0x0844
+    0,                       // 0x0845
+    0,                       // 0x0846
+    0,                       // 0x0847
+    0,                       // 0x0848
+    0,                       // 0x0849
+    0,                       // 0x084A
+    0,                       // 0x084B
+    0,                       // 0x084C
+    0,                       // 0x084D
+    0,                       // 0x084E
+    0,                       // 0x084F
+    0,                       // 0x0850
+    0,                       // 0x0851
+    0,                       // 0x0852
+    0,                       // 0x0853
+    0,                       // 0x0854
+    0,                       // 0x0855
+    0,                       // 0x0856
+    0,                       // 0x0857
+    0,                       // 0x0858
+    0,                       // 0x0859
+    0,                       // 0x085A
+    0,                       // 0x085B
+    0,                       // 0x085C
+    0,                       // 0x085D
+    0,                       // 0x085E
+    0,                       // 0x085F
+    0,                       // 0x0860
+    0,                       // 0x0861
+    0,                       // 0x0862
+    0,                       // 0x0863
+    0,                       // 0x0864
+    0,                       // 0x0865
+    0,                       // 0x0866
+    0,                       // 0x0867
+    0,                       // 0x0868
+    0,                       // 0x0869
+    0,                       // 0x086A
+    0,                       // 0x086B
+    0,                       // 0x086C
+    0,                       // 0x086D
+    0,                       // 0x086E
+    0,                       // 0x086F
+    1,                       // 0x0870
+    1,                       // 0x0871
+    1,                       // 0x0872
+    1,                       // 0x0873
+    1,                       // 0x0874
+    1,                       // 0x0875
+    1,                       // 0x0876
+    1,                       // 0x0877
+    1,                       // 0x0878
+    1,                       // 0x0879
+    1,                       // 0x087A
+    1,                       // 0x087B
+    1,                       // 0x087C
+    1,                       // 0x087D
+    1,                       // 0x087E
+    1,                       // 0x087F
+    1,                       // 0x0880
+    1,                       // 0x0881
+    1,                       // 0x0882
+    1 + 2,                   // 0x0883
+    1 + 2,                   // 0x0884
+    1 + 2,                   // 0x0885
+    1 + 2,                   // 0x0886
+    0,                       // 0x0887
+    0,                       // 0x0888
+    1 + 2,                   // 0x0889
+    1 + 2,                   // 0x088A
+    1 + 2,                   // 0x088B
+    1 + 2,                   // 0x088C
+    1 + 2,                   // 0x088D
+    1,                       // 0x088E
+    0,                       // 0x088F
+    4,                       // 0x0890
+    4,                       // 0x0891
+    0,                       // 0x0892
+    0,                       // 0x0893
+    0,                       // 0x0894
+    0,                       // 0x0895
+    0,                       // 0x0896
+    4,                       // 0x0897
+    4,                       // 0x0898
+    4,                       // 0x0899
+    4,                       // 0x089A
+    4,                       // 0x089B
+    4,                       // 0x089C
+    4,                       // 0x089D
+    4,                       // 0x089E
+    4,                       // 0x089F
+    1 + 2,                   // 0x08A0
+    1 + 2,                   // 0x08A1
+    1 + 2,                   // 0x08A2
+    1 + 2,                   // 0x08A3
+    1 + 2,                   // 0x08A4
+    1 + 2,                   // 0x08A5
+    1 + 2,                   // 0x08A6
+    1 + 2,                   // 0x08A7
+    1 + 2,                   // 0x08A8
+    1 + 2,                   // 0x08A9
+    1,                       // 0x08AA
+    1,                       // 0x08AB
+    1,                       // 0x08AC
+    0,                       // 0x08AD
+    1,                       // 0x08AE
+    1 + 2,                   // 0x08AF
+    1 + 2,                   // 0x08B0
+    1,                       // 0x08B1
+    1,                       // 0x08B2
+    1 + 2,                   // 0x08B3
+    1 + 2,                   // 0x08B4
+    1 + 2,                   // 0x08B5
+    1 + 2,                   // 0x08B6
+    1 + 2,                   // 0x08B7
+    1 + 2,                   // 0x08B8
+    1,                       // 0x08B9
+    1 + 2,                   // 0x08BA
+    1 + 2,                   // 0x08BB
+    1 + 2,                   // 0x08BC
+    1 + 2,                   // 0x08BD
+    1 + 2,                   // 0x08BE
+    1 + 2,                   // 0x08BF
+    1 + 2,                   // 0x08C0
+    1 + 2,                   // 0x08C1
+    1 + 2,                   // 0x08C2
+    1 + 2,                   // 0x08C3
+    1 + 2,                   // 0x08C4
+    1 + 2,                   // 0x08C5
+    1 + 2,                   // 0x08C6
+    1 + 2,                   // 0x08C7
+    1 + 2,                   // 0x08C8
+    0,                       // 0x08C9
+    4,                       // 0x08CA
+    4,                       // 0x08CB
+    4,                       // 0x08CC
+    4,                       // 0x08CD
+    4,                       // 0x08CE
+    4,                       // 0x08CF
+    4,                       // 0x08D0
+    4,                       // 0x08D1
+    4,                       // 0x08D2
+    4,                       // 0x08D3
+    4,                       // 0x08D4
+    4,                       // 0x08D5
+    4,                       // 0x08D6
+    4,                       // 0x08D7
+    4,                       // 0x08D8
+    4,                       // 0x08D9
+    4,                       // 0x08DA
+    4,                       // 0x08DB
+    4,                       // 0x08DC
+    4,                       // 0x08DD
+    4,                       // 0x08DE
+    4,                       // 0x08DF
+    4,                       // 0x08E0
+    4,                       // 0x08E1
+    4,                       // 0x08E2
+    4,                       // 0x08E3
+    4,                       // 0x08E4
+    4,                       // 0x08E5
+    4,                       // 0x08E6
+    4,                       // 0x08E7
+    4,                       // 0x08E8
+    4,                       // 0x08E9
+    4,                       // 0x08EA
+    4,                       // 0x08EB
+    4,                       // 0x08EC
+    4,                       // 0x08ED
+    4,                       // 0x08EE
+    4,                       // 0x08EF
+    4,                       // 0x08F0
+    4,                       // 0x08F1
+    4,                       // 0x08F2
+    4,                       // 0x08F3
+    4,                       // 0x08F4
+    4,                       // 0x08F5
+    4,                       // 0x08F6
+    4,                       // 0x08F7
+    4,                       // 0x08F8
+    4,                       // 0x08F9
+    4,                       // 0x08FA
+    4,                       // 0x08FB
+    4,                       // 0x08FC
+    4,                       // 0x08FD
+    4,                       // 0x08FE
+    4,                       // 0x08FF
+];
+// 1468 bytes
+// END GENERATED ARA_LINK
+
+/// The script of the Arabic presentation forms in `joining_forms`, the first joining script of
+/// `JOINING_LINK`
+pub const ARABIC_SCRIPT: u8 = 1;
+
+/// The link flags of the Joining_Type of every joining and transparent character as ranges,
+/// for `joining_forms`. Unlike `ARA_LINK` it follows ArabicShaping.txt, e.g. alef maksura is
+/// dual joining even though it has no initial and medial presentation forms. The last field is
+/// the script of joining characters, 0 for characters joining any script (e.g. tatweel).
+// BEGIN GENERATED JOINING_LINK (cargo xtask gen-tables)
+pub static JOINING_LINK: [(u32, u32, u16, u8); 518] = [
+    (0x00AD, 0x00AD, 4, 0),
+    (0x0300, 0x036F, 4, 0),
+    (0x0483, 0x0489, 4, 0),
+    (0x0591, 0x05BD, 4, 0),
+    (0x05BF, 0x05BF, 4, 0),
+    (0x05C1, 0x05C2, 4, 0),
+    (0x05C4, 0x05C5, 4, 0),
+    (0x05C7, 0x05C7, 4, 0),
+    (0x0600, 0x0605, 4, 0),
+    (0x0610, 0x061A, 4, 0),
+    (0x061C, 0x061C, 4, 0),
+    (0x0620, 0x0620, 1 + 2, 1),
+    (0x0622, 0x0625, 1, 1),
+    (0x0626, 0x0626, 1 + 2, 1),
+    (0x0627, 0x0627, 1, 1),
+    (0x0628, 0x0628, 1 + 2, 1),
+    (0x0629, 0x0629, 1, 1),
+    (0x062A, 0x062E, 1 + 2, 1),
+    (0x062F, 0x0632, 1, 1),
+    (0x0633, 0x063F, 1 + 2, 1),
+    (0x0640, 0x0640, 1 + 2, 0),
+    (0x0641, 0x0647, 1 + 2, 1),
+    (0x0648, 0x0648, 1, 1),
+    (0x0649, 0x064A, 1 + 2, 1),
+    (0x064B, 0x065F, 4, 0),
+    (0x066E, 0x066F, 1 + 2, 1),
+    (0x0670, 0x0670, 4, 0),
+    (0x0671, 0x0673, 1, 1),
+    (0x0675, 0x0677, 1, 1),
+    (0x0678, 0x0687, 1 + 2, 1),
+    (0x0688, 0x0699, 1, 1),
+    (0x069A, 0x06BF, 1 + 2, 1),
+    (0x06C0, 0x06C0, 1, 1),
+    (0x06C1, 0x06C2, 1 + 2, 1),
+    (0x06C3, 0x06CB, 1, 1),
+    (0x06CC, 0x06CC, 1 + 2, 1),
+    (0x06CD, 0x06CD, 1, 1),
+    (0x06CE, 0x06CE, 1 + 2, 1),
+    (0x06CF, 0x06CF, 1, 1),
+    (0x06D0, 0x06D1, 1 + 2, 1),
+    (0x06D2, 0x06D3, 1, 1),
+    (0x06D5, 0x06D5, 1, 1),
+    (0x06D6, 0x06DD, 4, 0),
+    (0x06DF, 0x06E4, 4, 0),
+    (0x06E7, 0x06E8, 4, 0),
+    (0x06EA, 0x06ED, 4, 0),
+    (0x06EE, 0x06EF, 1, 1),
+    (0x06FA, 0x06FC, 1 + 2, 1),
+    (0x06FF, 0x06FF, 1 + 2, 1),
+    (0x070F, 0x070F, 4, 0),
+    (0x0710, 0x0710, 1, 2),
+    (0x0711, 0x0711, 4, 0),
+    (0x0712, 0x0714, 1 + 2, 2),
+    (0x0715, 0x0719, 1, 2),
+    (0x071A, 0x071D, 1 + 2, 2),
+    (0x071E, 0x071E, 1, 2),
+    (0x071F, 0x0727, 1 + 2, 2),
+    (0x0728, 0x0728, 1, 2),
+    (0x0729, 0x0729, 1 + 2, 2),
+    (0x072A, 0x072A, 1, 2),
+    (0x072B, 0x072B, 1 + 2, 2),
+    (0x072C, 0x072C, 1, 2),
+    (0x072D, 0x072E, 1 + 2, 2),
+    (0x072F, 0x072F, 1, 2),
+    (0x0730, 0x074A, 4, 0),
+    (0x074D, 0x074D, 1, 2),
+    (0x074E, 0x074F, 1 + 2, 2),
+    (0x0750, 0x0758, 1 + 2, 1),
+    (0x0759, 0x075B, 1, 1),
+    (0x075C, 0x076A, 1 + 2, 1),
+    (0x076B, 0x076C, 1, 1),
+    (0x076D, 0x0770, 1 + 2, 1),
+    (0x0771, 0x0771, 1, 1),
+    (0x0772, 0x0772, 1 + 2, 1),
+    (0x0773, 0x0774, 1, 1),
+    (0x0775, 0x0777, 1 + 2, 1),
+    (0x0778, 0x0779, 1, 1),
+    (0x077A, 0x077F, 1 + 2, 1),
+    (0x07A6, 0x07B0, 4, 0),
+    (0x07CA, 0x07EA, 1 + 2, 3),
+    (0x07EB, 0x07F3, 4, 0),
+    (0x07FA, 0x07FA, 1 + 2, 3),
+    (0x07FD, 0x07FD, 4, 0),
+    (0x0816, 0x0819, 4, 0),
+    (0x081B, 0x0823, 4, 0),
+    (0x0825, 0x0827, 4, 0),
+    (0x0829, 0x082D, 4, 0),
+    (0x0840, 0x0840, 1, 4),
+    (0x0841, 0x0845, 1 + 2, 4),
+    (0x0846, 0x0847, 1, 4),
+    (0x0848, 0x0848, 1 + 2, 4),
+    (0x0849, 0x0849, 1, 4),
+    (0x084A, 0x0853, 1 + 2, 4),
+    (0x0854, 0x0854, 1, 4),
+    (0x0855, 0x0855, 1 + 2, 4),
+    (0x0856, 0x0858, 1, 4),
+    (0x0859, 0x085B, 4, 0),
+    (0x0860, 0x0860, 1 + 2, 2),
+    (0x0862, 0x0865, 1 + 2, 2),
+    (0x0867, 0x0867, 1, 2),
+    (0x0868, 0x0868, 1 + 2, 2),
+    (0x0869, 0x086A, 1, 2),
+    (0x0870, 0x0882, 1, 1),
+    (0x0883, 0x0886, 1 + 2, 1),
+    (0x0889, 0x088D, 1 + 2, 1),
+    (0x088E, 0x088E, 1, 1),
+    (0x0890, 0x0891, 4, 0),
+    (0x0897, 0x089F, 4, 0),
+    (0x08A0, 0x08A9, 1 + 2, 1),
+    (0x08AA, 0x08AC, 1, 1),
+    (0x08AE, 0x08AE, 1, 1),
+    (0x08AF, 0x08B0, 1 + 2, 1),
+    (0x08B1, 0x08B2, 1, 1),
+    (0x08B3, 0x08B8, 1 + 2, 1),
+    (0x08B9, 0x08B9, 1, 1),
+    (0x08BA, 0x08C8, 1 + 2, 1),
+    (0x08CA, 0x0902, 4, 0),
+    (0x093A, 0x093A, 4, 0),
+    (0x093C, 0x093C, 4, 0),
+    (0x0941, 0x0948, 4, 0),
+    (0x094D, 0x094D, 4, 0),
+    (0x0951, 0x0957, 4, 0),
+    (0x0962, 0x0963, 4, 0),
+    (0x0981, 0x0981, 4, 0),
+    (0x09BC, 0x09BC, 4, 0),
+    (0x09C1, 0x09C4, 4, 0),
+    (0x09CD, 0x09CD, 4, 0),
+    (0x09E2, 0x09E3, 4, 0),
+    (0x09FE, 0x09FE, 4, 0),
+    (0x0A01, 0x0A02, 4, 0),
+    (0x0A3C, 0x0A3C, 4, 0),
+    (0x0A41, 0x0A42, 4, 0),
+    (0x0A47, 0x0A48, 4, 0),
+    (0x0A4B, 0x0A4D, 4, 0),
+    (0x0A51, 0x0A51, 4, 0),
+    (0x0A70, 0x0A71, 4, 0),
+    (0x0A75, 0x0A75, 4, 0),
+    (0x0A81, 0x0A82, 4, 0),
+    (0x0ABC, 0x0ABC, 4, 0),
+    (0x0AC1, 0x0AC5, 4, 0),
+    (0x0AC7, 0x0AC8, 4, 0),
+    (0x0ACD, 0x0ACD, 4, 0),
+    (0x0AE2, 0x0AE3, 4, 0),
+    (0x0AFA, 0x0AFF, 4, 0),
+    (0x0B01, 0x0B01, 4, 0),
+    (0x0B3C, 0x0B3C, 4, 0),
+    (0x0B3F, 0x0B3F, 4, 0),
+    (0x0B41, 0x0B44, 4, 0),
+    (0x0B4D, 0x0B4D, 4, 0),
+    (0x0B55, 0x0B56, 4, 0),
+    (0x0B62, 0x0B63, 4, 0),
+    (0x0B82, 0x0B82, 4, 0),
+    (0x0BC0, 0x0BC0, 4, 0),
+    (0x0BCD, 0x0BCD, 4, 0),
+    (0x0C00, 0x0C00, 4, 0),
+    (0x0C04, 0x0C04, 4, 0),
+    (0x0C3C, 0x0C3C, 4, 0),
+    (0x0C3E, 0x0C40, 4, 0),
+    (0x0C46, 0x0C48, 4, 0),
+    (0x0C4A, 0x0C4D, 4, 0),
+    (0x0C55, 0x0C56, 4, 0),
+    (0x0C62, 0x0C63, 4, 0),
+    (0x0C81, 0x0C81, 4, 0),
+    (0x0CBC, 0x0CBC, 4, 0),
+    (0x0CBF, 0x0CBF, 4, 0),
+    (0x0CC6, 0x0CC6, 4, 0),
+    (0x0CCC, 0x0CCD, 4, 0),
+    (0x0CE2, 0x0CE3, 4, 0),
+    (0x0D00, 0x0D01, 4, 0),
+    (0x0D3B, 0x0D3C, 4, 0),
+    (0x0D41, 0x0D44, 4, 0),
+    (0x0D4D, 0x0D4D, 4, 0),
+    (0x0D62, 0x0D63, 4, 0),
+    (0x0D81, 0x0D81, 4, 0),
+    (0x0DCA, 0x0DCA, 4, 0),
+    (0x0DD2, 0x0DD4, 4, 0),
+    (0x0DD6, 0x0DD6, 4, 0),
+    (0x0E31, 0x0E31, 4, 0),
+    (0x0E34, 0x0E3A, 4, 0),
+    (0x0E47, 0x0E4E, 4, 0),
+    (0x0EB1, 0x0EB1, 4, 0),
+    (0x0EB4, 0x0EBC, 4, 0),
+    (0x0EC8, 0x0ECE, 4, 0),
+    (0x0F18, 0x0F19, 4, 0),
+    (0x0F35, 0x0F35, 4, 0),
+    (0x0F37, 0x0F37, 4, 0),
+    (0x0F39, 0x0F39, 4, 0),
+    (0x0F71, 0x0F7E, 4, 0),
+    (0x0F80, 0x0F84, 4, 0),
+    (0x0F86, 0x0F87, 4, 0),
+    (0x0F8D, 0x0F97, 4, 0),
+    (0x0F99, 0x0FBC, 4, 0),
+    (0x0FC6, 0x0FC6, 4, 0),
+    (0x102D, 0x1030, 4, 0),
+    (0x1032, 0x1037, 4, 0),
+    (0x1039, 0x103A, 4, 0),
+    (0x103D, 0x103E, 4, 0),
+    (0x1058, 0x1059, 4, 0),
+    (0x105E, 0x1060, 4, 0),
+    (0x1071, 0x1074, 4, 0),
+    (0x1082, 0x1082, 4, 0),
+    (0x1085, 0x1086, 4, 0),
+    (0x108D, 0x108D, 4, 0),
+    (0x109D, 0x109D, 4, 0),
+    (0x135D, 0x135F, 4, 0),
+    (0x1712, 0x1714, 4, 0),
+    (0x1732, 0x1733, 4, 0),
+    (0x1752, 0x1753, 4, 0),
+    (0x1772, 0x1773, 4, 0),
+    (0x17B4, 0x17B5, 4, 0),
+    (0x17B7, 0x17BD, 4, 0),
+    (0x17C6, 0x17C6, 4, 0),
+    (0x17C9, 0x17D3, 4, 0),
+    (0x17DD, 0x17DD, 4, 0),
+    (0x1807, 0x1807, 1 + 2, 5),
+    (0x180A, 0x180A, 1 + 2, 5),
+    (0x180B, 0x180F, 4, 0),
+    (0x1820, 0x1878, 1 + 2, 5),
+    (0x1885, 0x1886, 4, 0),
+    (0x1887, 0x18A8, 1 + 2, 5),
+    (0x18A9, 0x18A9, 4, 0),
+    (0x18AA, 0x18AA, 1 + 2, 5),
+    (0x1920, 0x1922, 4, 0),
+    (0x1927, 0x1928, 4, 0),
+    (0x1932, 0x1932, 4, 0),
+    (0x1939, 0x193B, 4, 0),
+    (0x1A17, 0x1A18, 4, 0),
+    (0x1A1B, 0x1A1B, 4, 0),
+    (0x1A56, 0x1A56, 4, 0),
+    (0x1A58, 0x1A5E, 4, 0),
+    (0x1A60, 0x1A60, 4, 0),
+    (0x1A62, 0x1A62, 4, 0),
+    (0x1A65, 0x1A6C, 4, 0),
+    (0x1A73, 0x1A7C, 4, 0),
+    (0x1A7F, 0x1A7F, 4, 0),
+    (0x1AB0, 0x1ACE, 4, 0),
+    (0x1B00, 0x1B03, 4, 0),
+    (0x1B34, 0x1B34, 4, 0),
+    (0x1B36, 0x1B3A, 4, 0),
+    (0x1B3C, 0x1B3C, 4, 0),
+    (0x1B42, 0x1B42, 4, 0),
+    (0x1B6B, 0x1B73, 4, 0),
+    (0x1B80, 0x1B81, 4, 0),
+    (0x1BA2, 0x1BA5, 4, 0),
+    (0x1BA8, 0x1BA9, 4, 0),
+    (0x1BAB, 0x1BAD, 4, 0),
+    (0x1BE6, 0x1BE6, 4, 0),
+    (0x1BE8, 0x1BE9, 4, 0),
+    (0x1BED, 0x1BED, 4, 0),
+    (0x1BEF, 0x1BF1, 4, 0),
+    (0x1C2C, 0x1C33, 4, 0),
+    (0x1C36, 0x1C37, 4, 0),
+    (0x1CD0, 0x1CD2, 4, 0),
+    (0x1CD4, 0x1CE0, 4, 0),
+    (0x1CE2, 0x1CE8, 4, 0),
+    (0x1CED, 0x1CED, 4, 0),
+    (0x1CF4, 0x1CF4, 4, 0),
+    (0x1CF8, 0x1CF9, 4, 0),
+    (0x1DC0, 0x1DFF, 4, 0),
+    (0x200B, 0x200B, 4, 0),
+    (0x200D, 0x200D, 1 + 2, 0),
+    (0x200E, 0x200F, 4, 0),
+    (0x202A, 0x202E, 4, 0),
+    (0x2060, 0x2064, 4, 0),
+    (0x2066, 0x206F, 4, 0),
+    (0x20D0, 0x20F0, 4, 0),
+    (0x2CEF, 0x2CF1, 4, 0),
+    (0x2D7F, 0x2D7F, 4, 0),
+    (0x2DE0, 0x2DFF, 4, 0),
+    (0x302A, 0x302D, 4, 0),
+    (0x3099, 0x309A, 4, 0),
+    (0xA66F, 0xA672, 4, 0),
+    (0xA674, 0xA67D, 4, 0),
+    (0xA69E, 0xA69F, 4, 0),
+    (0xA6F0, 0xA6F1, 4, 0),
+    (0xA802, 0xA802, 4, 0),
+    (0xA806, 0xA806, 4, 0),
+    (0xA80B, 0xA80B, 4, 0),
+    (0xA825, 0xA826, 4, 0),
+    (0xA82C, 0xA82C, 4, 0),
+    (0xA840, 0xA871, 1 + 2, 6),
+    (0xA872, 0xA872, 2, 6),
+    (0xA8C4, 0xA8C5, 4, 0),
+    (0xA8E0, 0xA8F1, 4, 0),
+    (0xA8FF, 0xA8FF, 4, 0),
+    (0xA926, 0xA92D, 4, 0),
+    (0xA947, 0xA951, 4, 0),
+    (0xA980, 0xA982, 4, 0),
+    (0xA9B3, 0xA9B3, 4, 0),
+    (0xA9B6, 0xA9B9, 4, 0),
+    (0xA9BC, 0xA9BD, 4, 0),
+    (0xA9E5, 0xA9E5, 4, 0),
+    (0xAA29, 0xAA2E, 4, 0),
+    (0xAA31, 0xAA32, 4, 0),
+    (0xAA35, 0xAA36, 4, 0),
+    (0xAA43, 0xAA43, 4, 0),
+    (0xAA4C, 0xAA4C, 4, 0),
+    (0xAA7C, 0xAA7C, 4, 0),
+    (0xAAB0, 0xAAB0, 4, 0),
+    (0xAAB2, 0xAAB4, 4, 0),
+    (0xAAB7, 0xAAB8, 4, 0),
+    (0xAABE, 0xAABF, 4, 0),
+    (0xAAC1, 0xAAC1, 4, 0),
+    (0xAAEC, 0xAAED, 4, 0),
+    (0xAAF6, 0xAAF6, 4, 0),
+    (0xABE5, 0xABE5, 4, 0),
+    (0xABE8, 0xABE8, 4, 0),
+    (0xABED, 0xABED, 4, 0),
+    (0xFB1E, 0xFB1E, 4, 0),
+    (0xFE00, 0xFE0F, 4, 0),
+    (0xFE20, 0xFE2F, 4, 0),
+    (0xFEFF, 0xFEFF, 4, 0),
+    (0xFFF9, 0xFFFB, 4, 0),
+    (0x101FD, 0x101FD, 4, 0),
+    (0x102E0, 0x102E0, 4, 0),
+    (0x10376, 0x1037A, 4, 0),
+    (0x10A01, 0x10A03, 4, 0),
+    (0x10A05, 0x10A06, 4, 0),
+    (0x10A0C, 0x10A0F, 4, 0),
+    (0x10A38, 0x10A3A, 4, 0),
+    (0x10A3F, 0x10A3F, 4, 0),
+    (0x10AC0, 0x10AC4, 1 + 2, 7),
+    (0x10AC5, 0x10AC5, 1, 7),
+    (0x10AC7, 0x10AC7, 1, 7),
+    (0x10AC9, 0x10ACA, 1, 7),
+    (0x10ACD, 0x10ACD, 2, 7),
+    (0x10ACE, 0x10AD2, 1, 7),
+    (0x10AD3, 0x10AD6, 1 + 2, 7),
+    (0x10AD7, 0x10AD7, 2, 7),
+    (0x10AD8, 0x10ADC, 1 + 2, 7),
+    (0x10ADD, 0x10ADD, 1, 7),
+    (0x10ADE, 0x10AE0, 1 + 2, 7),
+    (0x10AE1, 0x10AE1, 1, 7),
+    (0x10AE4, 0x10AE4, 1, 7),
+    (0x10AE5, 0x10AE6, 4, 0),
+    (0x10AEB, 0x10AEE, 1 + 2, 7),
+    (0x10AEF, 0x10AEF, 1, 7),
+    (0x10B80, 0x10B80, 1 + 2, 8),
+    (0x10B81, 0x10B81, 1, 8),
+    (0x10B82, 0x10B82, 1 + 2, 8),
+    (0x10B83, 0x10B85, 1, 8),
+    (0x10B86, 0x10B88, 1 + 2, 8),
+    (0x10B89, 0x10B89, 1, 8),
+    (0x10B8A, 0x10B8B, 1 + 2, 8),
+    (0x10B8C, 0x10B8C, 1, 8),
+    (0x10B8D, 0x10B8D, 1 + 2, 8),
+    (0x10B8E, 0x10B8F, 1, 8),
+    (0x10B90, 0x10B90, 1 + 2, 8),
+    (0x10B91, 0x10B91, 1, 8),
+    (0x10BA9, 0x10BAC, 1, 8),
+    (0x10BAD, 0x10BAE, 1 + 2, 8),
+    (0x10D00, 0x10D00, 2, 9),
+    (0x10D01, 0x10D21, 1 + 2, 9),
+    (0x10D22, 0x10D22, 1, 9),
+    (0x10D23, 0x10D23, 1 + 2, 9),
+    (0x10D24, 0x10D27, 4, 0),
+    (0x10D69, 0x10D6D, 4, 0),
+    (0x10EAB, 0x10EAC, 4, 0),
+    (0x10EFC, 0x10EFF, 4, 0),
+    (0x10F30, 0x10F32, 1 + 2, 10),
+    (0x10F33, 0x10F33, 1, 10),
+    (0x10F34, 0x10F44, 1 + 2, 10),
+    (0x10F46, 0x10F50, 4, 0),
+    (0x10F51, 0x10F53, 1 + 2, 10),
+    (0x10F54, 0x10F54, 1, 10),
+    (0x10F70, 0x10F73, 1 + 2, 11),
+    (0x10F74, 0x10F75, 1, 11),
+    (0x10F76, 0x10F81, 1 + 2, 11),
+    (0x10F82, 0x10F85, 4, 0),
+    (0x10FB0, 0x10FB0, 1 + 2, 12),
+    (0x10FB2, 0x10FB3, 1 + 2, 12),
+    (0x10FB4, 0x10FB6, 1, 12),
+    (0x10FB8, 0x10FB8, 1 + 2, 12),
+    (0x10FB9, 0x10FBA, 1, 12),
+    (0x10FBB, 0x10FBC, 1 + 2, 12),
+    (0x10FBD, 0x10FBD, 1, 12),
+    (0x10FBE, 0x10FBF, 1 + 2, 12),
+    (0x10FC1, 0x10FC1, 1 + 2, 12),
+    (0x10FC2, 0x10FC3, 1, 12),
+    (0x10FC4, 0x10FC4, 1 + 2, 12),
+    (0x10FC9, 0x10FC9, 1, 12),
+    (0x10FCA, 0x10FCA, 1 + 2, 12),
+    (0x10FCB, 0x10FCB, 2, 12),
+    (0x11001, 0x11001, 4, 0),
+    (0x11038, 0x11046, 4, 0),
+    (0x11070, 0x11070, 4, 0),
+    (0x11073, 0x11074, 4, 0),
+    (0x1107F, 0x11081, 4, 0),
+    (0x110B3, 0x110B6, 4, 0),
+    (0x110B9, 0x110BA, 4, 0),
+    (0x110BD, 0x110BD, 4, 0),
+    (0x110C2, 0x110C2, 4, 0),
+    (0x110CD, 0x110CD, 4, 0),
+    (0x11100, 0x11102, 4, 0),
+    (0x11127, 0x1112B, 4, 0),
+    (0x1112D, 0x11134, 4, 0),
+    (0x11173, 0x11173, 4, 0),
+    (0x11180, 0x11181, 4, 0),
+    (0x111B6, 0x111BE, 4, 0),
+    (0x111C9, 0x111CC, 4, 0),
+    (0x111CF, 0x111CF, 4, 0),
+    (0x1122F, 0x11231, 4, 0),
+    (0x11234, 0x11234, 4, 0),
+    (0x11236, 0x11237, 4, 0),
+    (0x1123E, 0x1123E, 4, 0),
+    (0x11241, 0x11241, 4, 0),
+    (0x112DF, 0x112DF, 4, 0),
+    (0x112E3, 0x112EA, 4, 0),
+    (0x11300, 0x11301, 4, 0),
+    (0x1133B, 0x1133C, 4, 0),
+    (0x11340, 0x11340, 4, 0),
+    (0x11366, 0x1136C, 4, 0),
+    (0x11370, 0x11374, 4, 0),
+    (0x113BB, 0x113C0, 4, 0),
+    (0x113CE, 0x113CE, 4, 0),
+    (0x113D0, 0x113D0, 4, 0),
+    (0x113D2, 0x113D2, 4, 0),
+    (0x113E1, 0x113E2, 4, 0),
+    (0x11438, 0x1143F, 4, 0),
+    (0x11442, 0x11444, 4, 0),
+    (0x11446, 0x11446, 4, 0),
+    (0x1145E, 0x1145E, 4, 0),
+    (0x114B3, 0x114B8, 4, 0),
+    (0x114BA, 0x114BA, 4, 0),
+    (0x114BF, 0x114C0, 4, 0),
+    (0x114C2, 0x114C3, 4, 0),
+    (0x115B2, 0x115B5, 4, 0),
+    (0x115BC, 0x115BD, 4, 0),
+    (0x115BF, 0x115C0, 4, 0),
+    (0x115DC, 0x115DD, 4, 0),
+    (0x11633, 0x1163A, 4, 0),
+    (0x1163D, 0x1163D, 4, 0),
+    (0x1163F, 0x11640, 4, 0),
+    (0x116AB, 0x116AB, 4, 0),
+    (0x116AD, 0x116AD, 4, 0),
+    (0x116B0, 0x116B5, 4, 0),
+    (0x116B7, 0x116B7, 4, 0),
+    (0x1171D, 0x1171F, 4, 0),
+    (0x11722, 0x11725, 4, 0),
+    (0x11727, 0x1172B, 4, 0),
+    (0x1182F, 0x11837, 4, 0),
+    (0x11839, 0x1183A, 4, 0),
+    (0x1193B, 0x1193C, 4, 0),
+    (0x1193E, 0x1193E, 4, 0),
+    (0x11943, 0x11943, 4, 0),
+    (0x119D4, 0x119D7, 4, 0),
+    (0x119DA, 0x119DB, 4, 0),
+    (0x119E0, 0x119E0, 4, 0),
+    (0x11A01, 0x11A0A, 4, 0),
+    (0x11A33, 0x11A38, 4, 0),
+    (0x11A3B, 0x11A3E, 4, 0),
+    (0x11A47, 0x11A47, 4, 0),
+    (0x11A51, 0x11A56, 4, 0),
+    (0x11A59, 0x11A5B, 4, 0),
+    (0x11A8A, 0x11A96, 4, 0),
+    (0x11A98, 0x11A99, 4, 0),
+    (0x11C30, 0x11C36, 4, 0),
+    (0x11C38, 0x11C3D, 4, 0),
+    (0x11C3F, 0x11C3F, 4, 0),
+    (0x11C92, 0x11CA7, 4, 0),
+    (0x11CAA, 0x11CB0, 4, 0),
+    (0x11CB2, 0x11CB3, 4, 0),
+    (0x11CB5, 0x11CB6, 4, 0),
+    (0x11D31, 0x11D36, 4, 0),
+    (0x11D3A, 0x11D3A, 4, 0),
+    (0x11D3C, 0x11D3D, 4, 0),
+    (0x11D3F, 0x11D45, 4, 0),
+    (0x11D47, 0x11D47, 4, 0),
+    (0x11D90, 0x11D91, 4, 0),
+    (0x11D95, 0x11D95, 4, 0),
+    (0x11D97, 0x11D97, 4, 0),
+    (0x11EF3, 0x11EF4, 4, 0),
+    (0x11F00, 0x11F01, 4, 0),
+    (0x11F36, 0x11F3A, 4, 0),
+    (0x11F40, 0x11F40, 4, 0),
+    (0x11F42, 0x11F42, 4, 0),
+    (0x11F5A, 0x11F5A, 4, 0),
+    (0x13430, 0x13440, 4, 0),
+    (0x13447, 0x13455, 4, 0),
+    (0x1611E, 0x16129, 4, 0),
+    (0x1612D, 0x1612F, 4, 0),
+    (0x16AF0, 0x16AF4, 4, 0),
+    (0x16B30, 0x16B36, 4, 0),
+    (0x16F4F, 0x16F4F, 4, 0),
+    (0x16F8F, 0x16F92, 4, 0),
+    (0x16FE4, 0x16FE4, 4, 0),
+    (0x1BC9D, 0x1BC9E, 4, 0),
+    (0x1BCA0, 0x1BCA3, 4, 0),
+    (0x1CF00, 0x1CF2D, 4, 0),
+    (0x1CF30, 0x1CF46, 4, 0),
+    (0x1D167, 0x1D169, 4, 0),
+    (0x1D173, 0x1D182, 4, 0),
+    (0x1D185, 0x1D18B, 4, 0),
+    (0x1D1AA, 0x1D1AD, 4, 0),
+    (0x1D242, 0x1D244, 4, 0),
+    (0x1DA00, 0x1DA36, 4, 0),
+    (0x1DA3B, 0x1DA6C, 4, 0),
+    (0x1DA75, 0x1DA75, 4, 0),
+    (0x1DA84, 0x1DA84, 4, 0),
+    (0x1DA9B, 0x1DA9F, 4, 0),
+    (0x1DAA1, 0x1DAAF, 4, 0),
+    (0x1E000, 0x1E006, 4, 0),
+    (0x1E008, 0x1E018, 4, 0),
+    (0x1E01B, 0x1E021, 4, 0),
+    (0x1E023, 0x1E024, 4, 0),
+    (0x1E026, 0x1E02A, 4, 0),
+    (0x1E08F, 0x1E08F, 4, 0),
+    (0x1E130, 0x1E136, 4, 0),
+    (0x1E2AE, 0x1E2AE, 4, 0),
+    (0x1E2EC, 0x1E2EF, 4, 0),
+    (0x1E4EC, 0x1E4EF, 4, 0),
+    (0x1E5EE, 0x1E5EF, 4, 0),
+    (0x1E8D0, 0x1E8D6, 4, 0),
+    (0x1E900, 0x1E943, 1 + 2, 13),
+    (0x1E944, 0x1E94B, 4, 0),
+    (0xE0001, 0xE0001, 4, 0),
+    (0xE0020, 0xE007F, 4, 0),
+    (0xE0100, 0xE01EF, 4, 0),
 ];
+// 6216 bytes
+// END GENERATED JOINING_LINK
 
 pub static PRES_ALINK: [u8; 275] = [
     //**0*****1*****2*****3*****4*****5*****6*****7*****8*****9*****A*****B*****C*****D*****E*****F*/
@@ -1128,11 +2233,15 @@ pub static CONVERT_FETO06: [u16; 141] = [
 
 pub static IRRELEVANT_POS: [u8; 8] = [0x0, 0x2, 0x4, 0x6, 0x8, 0xA, 0xC, 0xE];
 
+// The shape (0 isolated, 1 final, 2 initial, 3 medial) indexed by the links of the next,
+// the previous and the current character. Unlike ICU, whose entries for left links only
+// (initial presentation forms) are never used, a character joins the previous one if it
+// links right and the previous one links left, and joins the next one the other way around.
 pub static SHAPE_TABLE: [[[u8; 4]; 4]; 4] = [
-    [[0, 0, 0, 0], [0, 0, 0, 0], [0, 1, 0, 3], [0, 1, 0, 1]],
-    [[0, 0, 2, 2], [0, 0, 1, 2], [0, 1, 1, 2], [0, 1, 1, 3]],
-    [[0, 0, 0, 0], [0, 0, 0, 0], [0, 1, 0, 3], [0, 1, 0, 3]],
-    [[0, 0, 1, 2], [0, 0, 1, 2], [0, 1, 1, 2], [0, 1, 1, 3]],
+    [[0, 0, 0, 0], [0, 0, 0, 0], [0, 1, 0, 1], [0, 1, 0, 1]],
+    [[0, 0, 2, 2], [0, 0, 2, 2], [0, 1, 2, 3], [0, 1, 2, 3]],
+    [[0, 0, 0, 0], [0, 0, 0, 0], [0, 1, 0, 1], [0, 1, 0, 1]],
+    [[0, 0, 2, 2], [0, 0, 2, 2], [0, 1, 2, 3], [0, 1, 2, 3]],
 ];
 
 pub const TAMIL_VOWELS: [u16; 11] =
@@ -1140,9 +2249,9 @@ pub const TAMIL_VOWELS: [u16; 11] =
 
 pub const KHMER_SIGN_COENG: u16 = 0x17D2; // 6098
 
-pub const KHMER_DEPENDENT_VOWELS: [u16; 16] = [
-    // dependent vowels
+// BEGIN GENERATED KHMER_DEPENDENT_VOWELS (cargo xtask gen-tables)
+pub const KHMER_DEPENDENT_VOWELS: [u16; 17] = [
     0x17B6, 0x17B7, 0x17B8, 0x17B9, 0x17BA, 0x17BB, 0x17BC, 0x17BD, 0x17BE, 0x17BF, 0x17C0, 0x17C1,
-    0x17C2, 0x17C3, 0x17C4, 0x17C5,
-    // various signs
+    0x17C2, 0x17C3, 0x17C4, 0x17C5, 0x17C8,
 ];
+// END GENERATED KHMER_DEPENDENT_VOWELS
@@ -124,10 +124,10 @@ impl<'a> Definition<'a> {
 
 struct Cluster<'a> {
     pub defs: Vec<Definition<'a>>,
-    pub whitespace: Option<&'a u16>,
+    pub whitespace: Option<&'a [u16]>,
 }
 impl<'a> Cluster<'a> {
-    fn new(defs: Vec<Definition<'a>>, whitespace: Option<&'a u16>) -> Self {
+    fn new(defs: Vec<Definition<'a>>, whitespace: Option<&'a [u16]>) -> Self {
         Self { defs, whitespace }
     }
 
@@ -137,7 +137,7 @@ impl<'a> Cluster<'a> {
         let mut def_idx = 0;
         for idx in 0..defs.len() {
             if defs[idx].m_type == MType::WS || defs[idx].m_type == MType::NJ {
-                clusters.push(Cluster::new(defs[def_idx..idx].to_vec(), Some(&defs[idx].code[0])));
+                clusters.push(Cluster::new(defs[def_idx..idx].to_vec(), Some(defs[idx].code)));
                 def_idx = idx + 1;
             }
         }
@@ -211,7 +211,7 @@ pub fn shape_javanese(input: &mut [u16]) {
         res.append(&mut c.get_sorted());
         // append whitespace of cluster if it exists
         if let Some(ws) = c.whitespace {
-            res.push(*ws);
+            res.extend_from_slice(ws);
         }
     });
 
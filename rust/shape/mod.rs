@@ -4,6 +4,8 @@ pub mod arabic;
 pub mod buginese;
 /// CJK (Chinese, Japanese, or Korean) unicode shaping
 pub mod cjk;
+/// Shaping errors and input validation
+pub mod error;
 mod internal;
 /// Javanese unicode shaping
 pub mod javanese;
@@ -11,6 +13,8 @@ pub mod javanese;
 pub mod khmer;
 /// Myanmar unicode shaping
 pub mod myanmar;
+/// Typed shaping options
+pub mod options;
 mod shared;
 /// Tamil unicode shaping
 pub mod tamil;
@@ -20,26 +24,40 @@ pub mod thai;
 pub mod tibetan;
 
 use crate::*;
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 pub use arabic::*;
 pub use buginese::*;
 pub use cjk::*;
+pub use error::*;
 use internal::*;
 pub use javanese::*;
 pub use khmer::*;
 pub use myanmar::*;
+pub use options::*;
 pub use tamil::*;
 pub use tibetan::*;
 
+// the cluster reordering of the other scripts is neither needed nor reversible when unshaping
+fn shapes_letters(options: &u32) -> bool {
+    let letters = options & U_SHAPE_LETTERS_MASK;
+    letters != U_SHAPE_LETTERS_NOOP && letters != U_SHAPE_LETTERS_UNSHAPE
+}
+
 /// Converts an Arabic Unicode buffer in 06xx Range into a shaped
 /// arabic Unicode buffer in FExx Range
 pub fn shape_unicode(source: &[u16], options: &u32) -> Vec<u16> {
-    let mut output = source.to_vec();
+    // arabic letter and digit shaping
+    if options & (U_SHAPE_LETTERS_MASK | U_SHAPE_DIGITS_MASK) != 0 {
+        shape_other_scripts(shape_arabic(source, options), options)
+    } else {
+        shape_other_scripts(source.to_vec(), options)
+    }
+}
 
+/// The shaping of all other scripts and the bidi reordering after the Arabic shaping
+fn shape_other_scripts(mut output: Vec<u16>, options: &u32) -> Vec<u16> {
     // all other shaping
-    if options & U_SHAPE_LETTERS_MASK != 0 {
-        // arabic shaping
-        output = shape_arabic(&output, options);
+    if shapes_letters(options) {
         // Buginese shaping
         shape_buginese(&mut output);
         // Javanese shaping
@@ -56,12 +74,137 @@ pub fn shape_unicode(source: &[u16], options: &u32) -> Vec<u16> {
 
     // if option to process bidirectional text is set, then reorder the output
     if (options & U_SHAPE_DIRECTION_OUTPUT_BIDI) != 0 {
-        return process_bidi_text(&output);
+        return process_bidi_text(&output, base_direction(options));
     }
 
     output
 }
 
+/// Same as `shape_unicode`, but rejects invalid or incompatible options, input with
+/// unpaired surrogates and characters that do not fit a fixed length mode instead of
+/// shaping them on a best effort basis.
+pub fn try_shape_unicode(source: &[u16], options: &u32) -> Result<Vec<u16>, ShapeError> {
+    validate_options(options)?;
+    validate_utf16(source)?;
+
+    if options & (U_SHAPE_LETTERS_MASK | U_SHAPE_DIGITS_MASK) != 0 {
+        let output = try_shape_arabic_with_map(source, options, &mut Vec::new())?;
+        Ok(shape_other_scripts(output, options))
+    } else {
+        Ok(shape_other_scripts(source.to_vec(), options))
+    }
+}
+
+/// Same as `shape_unicode` for UTF-8 text, e.g. labels of a tile server
+pub fn shape_str(source: &str, options: &u32) -> String {
+    let utf16: Vec<u16> = source.encode_utf16().collect();
+    String::from_utf16_lossy(&shape_unicode(&utf16, options))
+}
+
+/// Same as `shape_unicode` for a slice of code points
+pub fn shape_chars(source: &[char], options: &u32) -> Vec<char> {
+    utf16_to_chars(&shape_unicode(&chars_to_utf16(source), options))
+}
+
+/// The result of `shape_unicode_with_map`: the shaped text together with the index maps
+/// between the input (logical) and output (visual) code units.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapedText {
+    /// The shaped (and possibly reordered) code units, identical to `shape_unicode`
+    pub text: Vec<u16>,
+    /// For each input code unit, the position of the output code unit displaying it.
+    /// Merged characters (e.g. lam-alef ligatures) point to their ligature, removed characters
+    /// (e.g. tashkeel with `U_SHAPE_TASHKEEL_RESIZE`) are `None`.
+    pub logical_to_visual: Vec<Option<usize>>,
+    /// For each output code unit, the position of the input code unit it was shaped from.
+    /// Ligatures point to their first input code unit.
+    pub visual_to_logical: Vec<usize>,
+}
+
+/// Same as `shape_unicode`, but also tracks where every input code unit ended up so callers
+/// can map cursor positions, selections or per-character styling to the shaped output.
+pub fn shape_unicode_with_map(source: &[u16], options: &u32) -> ShapedText {
+    let mut output = source.to_vec();
+    let mut source_map: Vec<usize> = (0..source.len()).collect();
+    let mut logical_to_visual: Vec<Option<usize>> = (0..source.len()).map(Some).collect();
+
+    if options & (U_SHAPE_LETTERS_MASK | U_SHAPE_DIGITS_MASK) != 0 {
+        output = shape_arabic_with_maps(source, options, &mut source_map, &mut logical_to_visual);
+    }
+    // the position of every output code unit in the Arabic output
+    let mut positions: Vec<usize> = (0..output.len()).collect();
+    if shapes_letters(options) {
+        // the remaining shapers only reorder code units within a cluster
+        let arabic_output = output.clone();
+        shape_buginese(&mut output);
+        shape_javanese(&mut output);
+        shape_myanmar(&mut output);
+        shape_tamil(&mut output);
+        shape_tibetan(&mut output);
+        shape_khmer(&mut output);
+        track_reordering(&arabic_output, &output, &mut positions);
+    }
+
+    if (options & U_SHAPE_DIRECTION_OUTPUT_BIDI) != 0 {
+        let mut visual_map = Vec::<usize>::new();
+        output = process_bidi_text_with_map(&output, base_direction(options), &mut visual_map);
+        positions = visual_map.into_iter().map(|idx| positions[idx]).collect();
+    }
+
+    // the inverse of positions
+    let mut visual_of: Vec<usize> = (0..positions.len()).collect();
+    visual_of.sort_unstable_by_key(|visual| positions[*visual]);
+    let visual_to_logical = positions.iter().map(|position| source_map[*position]).collect();
+    let logical_to_visual = logical_to_visual
+        .into_iter()
+        .map(|position| position.map(|position| visual_of[position]))
+        .collect();
+
+    ShapedText { text: output, logical_to_visual, visual_to_logical }
+}
+
+/// Get the base direction of the bidi output from the `U_SHAPE_BASE_DIRECTION_*` options
+fn base_direction(options: &u32) -> BaseDirection {
+    match options & U_SHAPE_BASE_DIRECTION_MASK {
+        U_SHAPE_BASE_DIRECTION_LTR => BaseDirection::Ltr,
+        U_SHAPE_BASE_DIRECTION_RTL => BaseDirection::Rtl,
+        _ => BaseDirection::Auto,
+    }
+}
+
+/// Apply the reordering between `before` and `after` (a permutation of `before`) to `map`.
+/// Equal code units are matched in order, so the result is stable for repeated characters.
+/// If a shaper changed the code units themselves, `map` keeps its positions.
+fn track_reordering(before: &[u16], after: &[u16], map: &mut [usize]) {
+    if before == after {
+        return;
+    }
+    let mut sorted_before = before.to_vec();
+    sorted_before.sort_unstable();
+    let mut sorted_after = after.to_vec();
+    sorted_after.sort_unstable();
+    // changed or added code units have no reordering to follow, so the positions are kept
+    if sorted_before != sorted_after {
+        return;
+    }
+
+    let mut used = Vec::<bool>::with_capacity(before.len());
+    used.resize(before.len(), false);
+    let mut first_unused: usize = 0;
+    let mut reordered = Vec::<usize>::with_capacity(map.len());
+    for c in after {
+        while first_unused < used.len() && used[first_unused] {
+            first_unused += 1;
+        }
+        // always found, as after is a permutation of before
+        if let Some(idx) = (first_unused..before.len()).find(|i| !used[*i] && before[*i] == *c) {
+            used[idx] = true;
+            reordered.push(map[idx]);
+        }
+    }
+    map.copy_from_slice(&reordered);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +250,407 @@ mod tests {
         assert_eq!(result, expected_utf16_ref);
     }
 
+    #[test]
+    fn try_shape() {
+        let input: Vec<u16> = "سلام۳۹".encode_utf16().collect();
+        assert_eq!(
+            try_shape_unicode(&input, &DEFAULT_OPTIONS),
+            Ok(shape_unicode(&input, &DEFAULT_OPTIONS))
+        );
+        assert_eq!(try_shape_unicode(&[], &DEFAULT_OPTIONS), Ok(Vec::new()));
+        assert!(try_shape_unicode(&input, &(DEFAULT_OPTIONS | U_SHAPE_DIGITS_RESERVED)).is_err());
+        assert_eq!(
+            try_shape_unicode(&[0x0628, 0xDD00], &DEFAULT_OPTIONS),
+            Err(ShapeError::UnpairedSurrogate(1))
+        );
+    }
+
+    #[test]
+    fn try_shape_myanmar_mark_at_cluster_start() {
+        // an anusvara or dot below at the start of a cluster has nothing to move before
+        let input: &[u16] = &[0x1036, 0x1037, 0x1094];
+        assert_eq!(try_shape_unicode(input, &0x6000b), Ok(input.to_vec()));
+        let result = try_shape_unicode(&[0x1032, 0x0679, 0x1037, 0x06C5], &0x100008);
+        assert_eq!(result, Ok([0xFBE0, 0x1037, 0xFB66, 0x1032].to_vec()));
+    }
+
+    #[test]
+    fn no_panics() {
+        // empty, whitespace only, non-joiner runs, a virama without kinzi, tashkeel heavy,
+        // lam-alef, seen and yeh-hamza input with every letter, tashkeel, fixed length and
+        // direction mode
+        let inputs: [&[u16]; 12] = [
+            &[],
+            &[0x20],
+            &[0x20, 0x20, 0x20],
+            &[0x200C, 0x200C],
+            &[0x0020, 0x200C, 0x200C],
+            &[0x1000, 0x103A, 0x1039, 0x1000],
+            &[0x0651, 0x064E, 0x20, 0x0644, 0x0627, 0x0651, 0x064F, 0x0628],
+            &[0x064E, 0x0651],
+            &[0xFEFC, 0x20, 0xFEFB],
+            &[0x0644, 0x0627],
+            &[0x0633, 0x0626, 0x20, 0x0626],
+            &[0xFEB2, 0xFE73, 0xFEF0, 0xFE80],
+        ];
+        let letters_modes = [
+            0,
+            U_SHAPE_LETTERS_SHAPE,
+            U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED,
+            U_SHAPE_LETTERS_UNSHAPE,
+        ];
+        let tashkeel_modes = [
+            0,
+            U_SHAPE_TASHKEEL_BEGIN,
+            U_SHAPE_TASHKEEL_END,
+            U_SHAPE_TASHKEEL_RESIZE,
+            U_SHAPE_TASHKEEL_REPLACE_BY_TATWEEL,
+        ];
+        let fixed_length_modes = [
+            0,
+            U_SHAPE_LAMALEF_NEAR,
+            U_SHAPE_LAMALEF_BEGIN,
+            U_SHAPE_LAMALEF_AUTO,
+            U_SHAPE_SEEN_TWOCELL_NEAR | U_SHAPE_YEHHAMZA_TWOCELL_NEAR | U_SHAPE_TAIL_NEW_UNICODE,
+        ];
+        let directions = [U_SHAPE_TEXT_DIRECTION_LOGICAL, U_SHAPE_TEXT_DIRECTION_VISUAL_LTR];
+        for input in inputs {
+            for letters in letters_modes {
+                for tashkeel in tashkeel_modes {
+                    for fixed_length in fixed_length_modes {
+                        for direction in directions {
+                            let options = letters
+                                | tashkeel
+                                | fixed_length
+                                | direction
+                                | U_SHAPE_AGGREGATE_TASHKEEL
+                                | U_SHAPE_DIRECTION_OUTPUT_BIDI;
+                            let shaped = shape_unicode_with_map(input, &options);
+                            assert_eq!(shaped.text, shape_unicode(input, &options));
+                            assert_eq!(shaped.logical_to_visual.len(), input.len());
+                            if let Ok(text) = try_shape_unicode(input, &options) {
+                                assert_eq!(text, shaped.text);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn digit_shaping() {
+        let input: Vec<u16> = "a 12 ب 34".encode_utf16().collect();
+        let shape = |options: u32| String::from_utf16(&shape_unicode(&input, &options)).unwrap();
+        assert_eq!(shape(U_SHAPE_DIGITS_EN2AN), "a ١٢ ب ٣٤");
+        assert_eq!(shape(U_SHAPE_DIGITS_EN2AN | U_SHAPE_DIGIT_TYPE_AN_EXTENDED), "a ۱۲ ب ۳۴");
+        // only the digits following an Arabic letter
+        assert_eq!(shape(U_SHAPE_DIGITS_ALEN2AN_INIT_LR), "a 12 ب ٣٤");
+        let input: Vec<u16> = "12 ب 34 a 56".encode_utf16().collect();
+        let shape = |options: u32| String::from_utf16(&shape_unicode(&input, &options)).unwrap();
+        assert_eq!(shape(U_SHAPE_DIGITS_ALEN2AN_INIT_AL), "١٢ ب ٣٤ a 56");
+        // visual LTR text is read from its end
+        let visual = U_SHAPE_DIGITS_ALEN2AN_INIT_LR | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        assert_eq!(shape(visual), "١٢ ب 34 a 56");
+        assert_eq!(
+            shape(U_SHAPE_DIGITS_ALEN2AN_INIT_AL | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR),
+            "١٢ ب 34 a ٥٦"
+        );
+        // back to European digits, other digit types are kept
+        let input: Vec<u16> = "١٢ ۳۴".encode_utf16().collect();
+        let shaped = shape_unicode(&input, &U_SHAPE_DIGITS_AN2EN);
+        assert_eq!(String::from_utf16(&shaped).unwrap(), "12 ۳۴");
+        let options = U_SHAPE_DIGITS_AN2EN | U_SHAPE_DIGIT_TYPE_AN_EXTENDED;
+        assert_eq!(String::from_utf16(&shape_unicode(&input, &options)).unwrap(), "١٢ 34");
+        // combined with letter shaping and bidi
+        let input: Vec<u16> = "سلام 12".encode_utf16().collect();
+        let shaped = shape_unicode(&input, &(DEFAULT_OPTIONS | U_SHAPE_DIGITS_ALEN2AN_INIT_LR));
+        assert_eq!(String::from_utf16(&shaped).unwrap(), "١٢ ﻡﻼﺳ");
+    }
+
+    #[test]
+    fn aggregate_tashkeel() {
+        // beh with shadda and fatha, in logical and visual order
+        let options = U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED | U_SHAPE_AGGREGATE_TASHKEEL;
+        let result = shape_arabic(&[0x0628, 0x0651, 0x064E], &options);
+        assert_eq!(result, [0xFE8F, 0xFC60]);
+        let visual = options | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        let mut source_map = Vec::<usize>::new();
+        let result = shape_arabic_with_map(&[0x064E, 0x0651, 0x0628], &visual, &mut source_map);
+        assert_eq!(result, [0xFC60, 0xFE8F]);
+        assert_eq!(source_map, [0, 2]);
+    }
+
+    #[test]
+    fn unshape() {
+        // shaped "سلام" with a lam-alef ligature, plus a peh from the FBxx range
+        let shaped: &[u16] = &[0xFEB3, 0xFEFC, 0xFEE1, 0x0020, 0xFB56];
+        let mut source_map = Vec::<usize>::new();
+        let result = shape_arabic_with_map(shaped, &U_SHAPE_LETTERS_UNSHAPE, &mut source_map);
+        assert_eq!(String::from_utf16(&result).unwrap(), "سلام پ");
+        assert_eq!(source_map, [0, 1, 1, 2, 3, 4]);
+        // visual order keeps lam to the right of alef
+        let visual = U_SHAPE_LETTERS_UNSHAPE | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        let result = shape_arabic_with_map(&[0xFEE1, 0xFEFC, 0xFEB3], &visual, &mut source_map);
+        assert_eq!(result, [0x0645, 0x0627, 0x0644, 0x0633]);
+        assert_eq!(source_map, [0, 1, 1, 2]);
+        // round trip through the presentation forms
+        let input: Vec<u16> = "مرحبا بالعالم".encode_utf16().collect();
+        let shaped = shape_unicode(&input, &U_SHAPE_LETTERS_SHAPE);
+        assert_ne!(shaped, input);
+        assert_eq!(shape_unicode(&shaped, &U_SHAPE_LETTERS_UNSHAPE), input);
+    }
+
+    #[test]
+    fn lam_alef_modes() {
+        let input: Vec<u16> = "سلام".encode_utf16().collect();
+        let mut source_map = Vec::<usize>::new();
+        let cases: [(u32, [u16; 4], [usize; 4]); 4] = [
+            (U_SHAPE_LAMALEF_NEAR, [0xFEB3, 0xFEFC, 0x0020, 0xFEE1], [0, 1, 2, 3]),
+            (U_SHAPE_LAMALEF_BEGIN, [0x0020, 0xFEB3, 0xFEFC, 0xFEE1], [2, 0, 1, 3]),
+            (U_SHAPE_LAMALEF_END, [0xFEB3, 0xFEFC, 0xFEE1, 0x0020], [0, 1, 3, 2]),
+            (U_SHAPE_LAMALEF_AUTO, [0xFEB3, 0xFEFC, 0xFEE1, 0x0020], [0, 1, 3, 2]),
+        ];
+        for (mode, expected, expected_map) in cases {
+            let options = U_SHAPE_LETTERS_SHAPE | mode;
+            assert_eq!(shape_arabic_with_map(&input, &options, &mut source_map), expected);
+            assert_eq!(source_map, expected_map);
+            // unshaping consumes the space again
+            assert_eq!(shape_arabic(&expected, &(U_SHAPE_LETTERS_UNSHAPE | mode)), input);
+        }
+        assert_eq!(shape_arabic(&input, &U_SHAPE_LETTERS_SHAPE), [0xFEB3, 0xFEFC, 0xFEE1]);
+
+        // visual text, where begin and end can be relative to the (right to left) text
+        let visual: &[u16] = &[0x0645, 0x0627, 0x0644, 0x0633];
+        let options = U_SHAPE_LETTERS_SHAPE | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        let near = shape_arabic(visual, &(options | U_SHAPE_LAMALEF_NEAR));
+        assert_eq!(near, [0xFEE1, 0x0020, 0xFEFC, 0xFEB3]);
+        let begin = shape_arabic(visual, &(options | U_SHAPE_LAMALEF_BEGIN));
+        assert_eq!(begin, [0x0020, 0xFEE1, 0xFEFC, 0xFEB3]);
+        let relative = options | U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END;
+        let begin = shape_arabic(visual, &(relative | U_SHAPE_LAMALEF_BEGIN));
+        assert_eq!(begin, [0xFEE1, 0xFEFC, 0xFEB3, 0x0020]);
+    }
+
+    #[test]
+    fn lam_alef_no_space() {
+        // the only space is at the end of the text
+        let shaped: &[u16] = &[0xFEB3, 0xFEFC, 0xFEE1, 0x0020];
+        let expected: Vec<u16> = "سلام".encode_utf16().collect();
+        let auto = U_SHAPE_LETTERS_UNSHAPE | U_SHAPE_LAMALEF_AUTO;
+        assert_eq!(try_shape_unicode(shaped, &auto), Ok(expected.clone()));
+        let begin = U_SHAPE_LETTERS_UNSHAPE | U_SHAPE_LAMALEF_BEGIN;
+        assert_eq!(try_shape_unicode(shaped, &begin), Err(ShapeError::NoSpaceAvailable));
+        let near = U_SHAPE_LETTERS_UNSHAPE | U_SHAPE_LAMALEF_NEAR;
+        assert_eq!(try_shape_unicode(shaped, &near), Err(ShapeError::NoSpaceAvailable));
+        // without a space the best effort result grows like U_SHAPE_LAMALEF_RESIZE
+        let mut grown = expected.clone();
+        grown.push(0x0020);
+        assert_eq!(shape_unicode(shaped, &near), grown);
+    }
+
+    #[test]
+    fn tashkeel_modes() {
+        // beh with fatha, teh
+        let input: &[u16] = &[0x0628, 0x064E, 0x062A];
+        let mut source_map = Vec::<usize>::new();
+        let cases: [(u32, &[u16], &[usize]); 3] = [
+            (U_SHAPE_TASHKEEL_RESIZE, &[0xFE91, 0xFE96], &[0, 2]),
+            (U_SHAPE_TASHKEEL_BEGIN, &[0x0020, 0xFE91, 0xFE96], &[1, 0, 2]),
+            (U_SHAPE_TASHKEEL_END, &[0xFE91, 0xFE96, 0x0020], &[0, 2, 1]),
+        ];
+        for (mode, expected, expected_map) in cases {
+            let options = U_SHAPE_LETTERS_SHAPE | mode;
+            assert_eq!(shape_arabic_with_map(input, &options, &mut source_map), expected);
+            assert_eq!(source_map, expected_map);
+        }
+        // shadda is kept
+        let options = U_SHAPE_LETTERS_SHAPE | U_SHAPE_TASHKEEL_END;
+        let shadda = shape_arabic(&[0x0628, 0x0651, 0x062A], &options);
+        assert_eq!(shadda.len(), 3);
+        assert!(!shadda.contains(&0x0020));
+
+        // visual text, where begin and end can be relative to the (right to left) text
+        let visual: &[u16] = &[0x062A, 0x064E, 0x0628];
+        let options = U_SHAPE_LETTERS_SHAPE | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        let begin = shape_arabic(visual, &(options | U_SHAPE_TASHKEEL_BEGIN));
+        assert_eq!(begin, [0x0020, 0xFE96, 0xFE91]);
+        let relative = options | U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END;
+        let begin = shape_arabic(visual, &(relative | U_SHAPE_TASHKEEL_BEGIN));
+        assert_eq!(begin, [0xFE96, 0xFE91, 0x0020]);
+
+        // together with a lam-alef mode
+        let input: Vec<u16> = "سَلام".encode_utf16().collect();
+        let options = U_SHAPE_LETTERS_SHAPE | U_SHAPE_LAMALEF_NEAR | U_SHAPE_TASHKEEL_END;
+        let shaped = shape_arabic(&input, &options);
+        assert_eq!(shaped, [0xFEB3, 0xFEFC, 0x0020, 0xFEE1, 0x0020]);
+        let options = U_SHAPE_LETTERS_SHAPE | U_SHAPE_LAMALEF_BEGIN | U_SHAPE_TASHKEEL_BEGIN;
+        let shaped = shape_unicode_with_map(&input, &options);
+        assert_eq!(shaped.text, [0x0020, 0x0020, 0xFEB3, 0xFEFC, 0xFEE1]);
+        assert_eq!(shaped.logical_to_visual, [2, 0, 3, 1, 4].map(Some));
+    }
+
+    #[test]
+    fn arabic_does_not_join_other_scripts() {
+        let options = U_SHAPE_LETTERS_SHAPE;
+        // Syriac beth, N'Ko a and Mandaic ab are dual joining in their own scripts
+        for other in [0x0712, 0x07CA, 0x0841] {
+            assert_eq!(shape_arabic(&[0x0628, other], &options), [0xFE8F, other]);
+            assert_eq!(shape_arabic(&[other, 0x0628], &options), [other, 0xFE8F]);
+            assert_eq!(shape_arabic(&[0x0628, other, 0x0628], &options), [0xFE8F, other, 0xFE8F]);
+        }
+        // the Arabic Supplement and Extended blocks still join
+        assert_eq!(shape_arabic(&[0x0628, 0x0750], &options), [0xFE91, 0x0750]);
+        assert_eq!(shape_arabic(&[0x0628, 0x08A0], &options), [0xFE91, 0x08A0]);
+    }
+
+    #[test]
+    fn seen_and_yeh_hamza_two_cells() {
+        let seen = U_SHAPE_LETTERS_SHAPE | U_SHAPE_SEEN_TWOCELL_NEAR;
+        let input: Vec<u16> = "بس س".encode_utf16().collect();
+        let tail_new = shape_arabic(&input, &(seen | U_SHAPE_TAIL_NEW_UNICODE));
+        assert_eq!(tail_new, [0xFE91, 0xFEB2, NEW_TAIL_CHAR, 0xFEB1]);
+        assert_eq!(shape_arabic(&input, &seen), [0xFE91, 0xFEB2, OLD_TAIL_CHAR, 0xFEB1]);
+        // the last seen has no space for its tail
+        assert_eq!(try_shape_unicode(&input, &seen), Err(ShapeError::NoSpaceAvailable));
+        // the space after the seen is to its left in visual text
+        let visual: &[u16] = &[0x0020, 0x0633, 0x0628];
+        let options = seen | U_SHAPE_TAIL_NEW_UNICODE | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        assert_eq!(shape_arabic(visual, &options), [NEW_TAIL_CHAR, 0xFEB2, 0xFE91]);
+        // even with output bidi, which shares U_SHAPE_SEEN_MASK
+        let bidi = seen | U_SHAPE_TAIL_NEW_UNICODE | U_SHAPE_DIRECTION_OUTPUT_BIDI;
+        assert_eq!(shape_unicode(&input[..3], &bidi), [NEW_TAIL_CHAR, 0xFEB2, 0xFE91]);
+
+        let yeh_hamza = U_SHAPE_LETTERS_SHAPE | U_SHAPE_YEHHAMZA_TWOCELL_NEAR;
+        let input: Vec<u16> = "بئ ئ ".encode_utf16().collect();
+        let shaped = shape_arabic(&input, &yeh_hamza);
+        assert_eq!(shaped, [0xFE91, 0xFEF0, HAMZAFE_CHAR, 0xFEEF, HAMZAFE_CHAR]);
+        assert_eq!(shape_arabic(&input[..2], &U_SHAPE_LETTERS_SHAPE), [0xFE91, 0xFE8A]);
+
+        // unshaping composes the characters again, leaving the space
+        let unshape = U_SHAPE_LETTERS_UNSHAPE | U_SHAPE_YEHHAMZA_TWOCELL_NEAR;
+        assert_eq!(shape_arabic(&shaped, &unshape), input);
+        let unshape = U_SHAPE_LETTERS_UNSHAPE | U_SHAPE_SEEN_TWOCELL_NEAR;
+        let expected: Vec<u16> = "بس س".encode_utf16().collect();
+        assert_eq!(shape_arabic(&tail_new, &unshape), expected);
+        let visual = unshape | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        assert_eq!(shape_arabic(&[OLD_TAIL_CHAR, 0xFEB2, 0xFE91], &visual), [0x20, 0x0633, 0x0628]);
+        // without the option the tail is kept
+        assert_eq!(
+            shape_arabic(&[0xFEB1, NEW_TAIL_CHAR], &U_SHAPE_LETTERS_UNSHAPE),
+            [0x0633, NEW_TAIL_CHAR]
+        );
+    }
+
+    #[test]
+    fn str_and_chars() {
+        assert_eq!(shape_str("سلام۳۹", &DEFAULT_OPTIONS), "۳۹ﻡﻼﺳ");
+        assert_eq!(shape_str("normal latin text", &DEFAULT_OPTIONS), "normal latin text");
+        let input: Vec<char> = "سلام 😀".chars().collect();
+        let expected: Vec<char> = "😀 ﻡﻼﺳ".chars().collect();
+        assert_eq!(shape_chars(&input, &DEFAULT_OPTIONS), expected);
+    }
+
+    #[test]
+    fn arabic_extended_letters_join() {
+        // beh joins the letters of Arabic Extended-A (U+08A0) and Extended-B (U+0870)
+        let input: &[u16] = &[0x0628, 0x08A0, 0x0020, 0x0628, 0x0870];
+        let result = shape_arabic(input, &U_SHAPE_LETTERS_SHAPE);
+        assert_eq!(result, [0xFE91, 0x08A0, 0x0020, 0xFE91, 0x0870]);
+    }
+
+    #[test]
+    fn arabic_extended_glyph_forms() {
+        use GlyphForm::*;
+        // Arabic Supplement (U+0750, U+076B) and Extended-A/B (U+08A0, U+0870) letters keep
+        // their code points and get a form, next to letters with presentation forms
+        let input: &[u16] = &[0x0750, 0x0750, 0x0750, 0x20, 0x0628, 0x076B, 0x0628, 0x08A0, 0x0870];
+        let mut forms = Vec::<Option<GlyphForm>>::new();
+        let shaped = shape_arabic_with_forms(input, &U_SHAPE_LETTERS_SHAPE, &mut forms);
+        assert_eq!(shaped, [0x0750, 0x0750, 0x0750, 0x20, 0xFE91, 0x076B, 0xFE91, 0x08A0, 0x0870]);
+        let expected =
+            [Initial, Medial, Final, Isolated, Initial, Final, Initial, Medial, Final].map(Some);
+        let expected = [&expected[..3], &[None], &expected[4..]].concat();
+        assert_eq!(forms, expected);
+        // transparent marks are skipped, unless shaped into a tatweel with the mark
+        let input: &[u16] = &[0x0750, 0x0653, 0x0750];
+        let shaped = shape_arabic_with_forms(input, &U_SHAPE_LETTERS_SHAPE, &mut forms);
+        assert_eq!(shaped, input);
+        assert_eq!(forms, [Some(Initial), None, Some(Final)]);
+        let input: &[u16] = &[0x0750, 0x064E, 0x0750];
+        let shaped = shape_arabic_with_forms(input, &U_SHAPE_LETTERS_SHAPE, &mut forms);
+        assert_eq!(shaped, [0x0750, 0xFE77, 0x0750]);
+        assert_eq!(forms, [Some(Initial), Some(Medial), Some(Final)]);
+        // a letter after an initial presentation form is final, not medial
+        assert_eq!(shape_arabic(&[0xFE91, 0x0628], &U_SHAPE_LETTERS_SHAPE), [0xFE91, 0xFE90]);
+        // visual text starts at the right
+        let visual = U_SHAPE_LETTERS_SHAPE | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        let shaped = shape_arabic_with_forms(&[0x0750, 0x0628, 0x0621], &visual, &mut forms);
+        assert_eq!(shaped, [0x0750, 0xFE91, 0x0621]);
+        assert_eq!(forms, [Some(Final), Some(Initial), None]);
+    }
+
+    #[test]
+    fn joining_forms_of_all_scripts() {
+        use GlyphForm::*;
+        // Arabic beh beh alef, with a non joining space and hamza
+        let input: &[u16] = &[0x0628, 0x0628, 0x0627, 0x20, 0x0621, 0x0628];
+        let expected = [Some(Initial), Some(Medial), Some(Final), None, None, Some(Isolated)];
+        assert_eq!(joining_forms(input), expected);
+        // alef maksura and noon ghunna are dual joining without initial and medial presentation
+        // forms, U+065C is a mark
+        let input: &[u16] = &[0x0628, 0x0649, 0x065C, 0x06BA, 0x0628];
+        let expected = [Some(Initial), Some(Medial), None, Some(Medial), Some(Final)];
+        assert_eq!(joining_forms(input), expected);
+        // presentation forms join like the form they represent
+        assert_eq!(joining_forms(&[0xFE91, 0xFE90]), [Initial, Final].map(Some));
+        // Syriac beth alaph, right joining alaph ends the word
+        assert_eq!(joining_forms(&[0x0712, 0x0710, 0x0712]), [Initial, Final, Isolated].map(Some));
+        // N'Ko
+        assert_eq!(joining_forms(&[0x07CA, 0x07CA, 0x07CA]), [Initial, Medial, Final].map(Some));
+        // Mongolian a, the free variation selector is transparent and the nirugu is join causing
+        let input: &[u16] = &[0x1820, 0x180B, 0x1820, 0x1820, 0x180A];
+        let expected = [Some(Initial), None, Some(Medial), Some(Medial), Some(Final)];
+        assert_eq!(joining_forms(input), expected);
+        // Hanifi Rohingya is outside of the BMP: left joining 10D00, dual joining 10D01
+        let chars = ['\u{10D00}', '\u{10D01}'];
+        assert_eq!(joining_forms_chars(&chars), [Initial, Final].map(Some));
+        let input = chars_to_utf16(&chars);
+        assert_eq!(input, [0xD803, 0xDD00, 0xD803, 0xDD01]);
+        assert_eq!(joining_forms(&input), [Initial, Initial, Final, Final].map(Some));
+        // ZWNJ breaks, ZWJ forces the join, unpaired surrogates do not join
+        assert_eq!(
+            joining_forms(&[0x0628, 0x200C, 0x0628]),
+            [Some(Isolated), None, Some(Isolated)]
+        );
+        assert_eq!(joining_forms(&[0x0628, 0x200D]), [Some(Initial), Some(Final)]);
+        assert_eq!(
+            joining_forms(&[0x0628, 0xD803, 0x0628]),
+            [Some(Isolated), None, Some(Isolated)]
+        );
+    }
+
+    #[test]
+    fn joining_forms_break_between_scripts() {
+        use GlyphForm::*;
+        // Arabic beh next to Syriac beth, N'Ko a and Mandaic ab
+        for other in [0x0712, 0x07CA, 0x0841] {
+            assert_eq!(joining_forms(&[0x0628, other]), [Isolated, Isolated].map(Some));
+            assert_eq!(joining_forms(&[other, 0x0628]), [Isolated, Isolated].map(Some));
+        }
+        // a mark between them doesn't join them either
+        let input: &[u16] = &[0x0628, 0x0628, 0x064E, 0x0712, 0x0712];
+        let expected = [Some(Initial), Some(Final), None, Some(Initial), Some(Final)];
+        assert_eq!(joining_forms(input), expected);
+        // tatweel and ZWJ join any script
+        let input: &[u16] = &[0x0712, 0x0640, 0x0712, 0x200D];
+        assert_eq!(joining_forms(input), [Initial, Medial, Medial, Final].map(Some));
+        let chars = ['\u{0628}', '\u{0640}', '\u{0712}'];
+        assert_eq!(joining_forms_chars(&chars), [Initial, Final, Isolated].map(Some));
+        // Arabic presentation forms are Arabic
+        assert_eq!(joining_forms(&[0xFE91, 0x0712]), [Isolated, Isolated].map(Some));
+    }
+
     #[test]
     fn hebrew_string() {
         // Create a Rust string
@@ -219,4 +763,77 @@ mod tests {
         let result: &[u16] = &shape_unicode(input_utf16_ref, &DEFAULT_OPTIONS);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn base_direction_option() {
+        let input: Vec<u16> = "abc سلام".encode_utf16().collect();
+        let rtl = DEFAULT_OPTIONS | U_SHAPE_BASE_DIRECTION_RTL;
+        let expected: Vec<u16> = "ﻡﻼﺳ abc".encode_utf16().collect();
+        assert_eq!(shape_unicode(&input, &rtl), expected);
+        let ltr = DEFAULT_OPTIONS | U_SHAPE_BASE_DIRECTION_LTR;
+        let expected: Vec<u16> = "abc ﻡﻼﺳ".encode_utf16().collect();
+        assert_eq!(shape_unicode(&input, &ltr), expected);
+        assert_eq!(shape_unicode(&input, &DEFAULT_OPTIONS), expected);
+    }
+
+    #[test]
+    fn index_map_arabic() {
+        // "سلام۳۹" => "۳۹ﻡﻼﺳ", the lam-alef merges into a single ligature
+        let input: Vec<u16> = "سلام۳۹".encode_utf16().collect();
+        let shaped = shape_unicode_with_map(&input, &DEFAULT_OPTIONS);
+        assert_eq!(shaped.text, "۳۹ﻡﻼﺳ".encode_utf16().collect::<Vec<u16>>());
+        assert_eq!(shaped.visual_to_logical, [4, 5, 3, 1, 0]);
+        assert_eq!(shaped.logical_to_visual, [4, 3, 3, 2, 0, 1].map(Some));
+        // in visual text the alef comes before its lam
+        let visual = U_SHAPE_LETTERS_SHAPE | U_SHAPE_TEXT_DIRECTION_VISUAL_LTR;
+        let shaped = shape_unicode_with_map(&[0x0645, 0x0627, 0x0644, 0x0633], &visual);
+        assert_eq!(shaped.text, [0xFEE1, 0xFEFC, 0xFEB3]);
+        assert_eq!(shaped.visual_to_logical, [0, 2, 3]);
+        assert_eq!(shaped.logical_to_visual, [0, 1, 1, 2].map(Some));
+        // aggregated marks both point to the shadda ligature
+        let aggregate = U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED | U_SHAPE_AGGREGATE_TASHKEEL;
+        let shaped = shape_unicode_with_map(&[0x0628, 0x0651, 0x064E], &aggregate);
+        assert_eq!(shaped.text.len(), 2);
+        assert_eq!(shaped.visual_to_logical, [0, 1]);
+        assert_eq!(shaped.logical_to_visual, [0, 1, 1].map(Some));
+    }
+
+    #[test]
+    fn index_map_reordering() {
+        // the myanmar vowel sign E is displayed before its consonant
+        let input: &[u16] = &[0x1000, 0x1031, 0x0A, 0x0A, 0x05D0, 0x05D1];
+        let shaped = shape_unicode_with_map(input, &DEFAULT_OPTIONS);
+        assert_eq!(shaped.text, [0x1031, 0x1000, 0x0A, 0x0A, 0x05D1, 0x05D0]);
+        assert_eq!(shaped.visual_to_logical, [1, 0, 2, 3, 5, 4]);
+        assert_eq!(shaped.logical_to_visual, [1, 0, 2, 3, 5, 4].map(Some));
+        assert_eq!(shape_unicode(input, &DEFAULT_OPTIONS), shaped.text);
+    }
+
+    #[test]
+    fn index_map_changed_code_units() {
+        // a shaper that replaces or adds code units keeps the positions instead of panicking
+        let mut map = [3, 4, 5];
+        track_reordering(&[0x1000, 0x1031, 0x20], &[0x1031, 0x1000, 0x0A], &mut map);
+        assert_eq!(map, [3, 4, 5]);
+        track_reordering(&[0x1000, 0x1031, 0x20], &[0x1031, 0x1000, 0x20, 0x20], &mut map);
+        assert_eq!(map, [3, 4, 5]);
+        // a reordering is still followed
+        track_reordering(&[0x1000, 0x1031, 0x20], &[0x1031, 0x1000, 0x20], &mut map);
+        assert_eq!(map, [4, 3, 5]);
+    }
+
+    #[test]
+    fn index_map_removed_tashkeel() {
+        let options = U_SHAPE_LETTERS_SHAPE | U_SHAPE_TASHKEEL_RESIZE;
+        // nothing is kept
+        let shaped = shape_unicode_with_map(&[0x064E, 0x064F], &options);
+        assert_eq!(shaped.text, []);
+        assert_eq!(shaped.logical_to_visual, [None, None]);
+        assert_eq!(shaped.visual_to_logical, []);
+        // tashkeel before the first and after a kept character
+        let shaped = shape_unicode_with_map(&[0x064E, 0x0628, 0x064F], &options);
+        assert_eq!(shaped.text, [0xFE8F]);
+        assert_eq!(shaped.logical_to_visual, [None, Some(0), None]);
+        assert_eq!(shaped.visual_to_logical, [1]);
+    }
 }
@@ -149,12 +149,10 @@ impl<'a> Definition<'a> {
             let code = &input[idx];
             let may_be_kinzi_sequence: bool =
                 idx + 2 < input.len() && input[idx + 1] == 0x103A && input[idx + 2] == 0x1039;
-            clusters.push(Definition::new(MType::from_u16(code, may_be_kinzi_sequence), code));
-            if may_be_kinzi_sequence {
-                idx += 3;
-            } else {
-                idx += 1;
-            }
+            let m_type = MType::from_u16(code, may_be_kinzi_sequence);
+            // only a kinzi takes the following asat and virama with it
+            idx += if m_type == MType::K { 3 } else { 1 };
+            clusters.push(Definition::new(m_type, code));
         }
 
         clusters
@@ -229,7 +227,7 @@ impl<'a> Cluster<'a> {
                 MType::A => {
                     // Anusvara (A) coming immediately after one or more below-base vowels (VBlw)
                     let mut prev_idx = idx;
-                    while prev_idx - 1 > 0 && self.defs[prev_idx - 1].m_type == MType::VBlw {
+                    while prev_idx > 0 && self.defs[prev_idx - 1].m_type == MType::VBlw {
                         prev_idx -= 1;
                     }
                     if prev_idx != idx {
@@ -346,4 +344,13 @@ mod tests {
         shape_myanmar(&mut result);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn myanmar_virama_without_kinzi_test() {
+        // only nga, ra and mon nga start a kinzi, the asat and virama stay in place otherwise
+        let input: &[u16] = &[0x1000, 0x103A, 0x1039, 0x1000];
+        let mut result = input.to_vec();
+        shape_myanmar(&mut result);
+        assert_eq!(result, input);
+    }
 }
@@ -0,0 +1,466 @@
+use crate::shape::*;
+use core::fmt;
+
+/// Letter shaping mode (`U_SHAPE_LETTERS_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum LettersMode {
+    /// Do not shape letters
+    #[default]
+    Noop,
+    /// Replace abstract letters by their shaped presentation forms
+    Shape,
+    /// Replace shaped presentation forms by abstract letters
+    Unshape,
+    /// Same as `Shape`, but tashkeel are always shaped into their isolated form
+    ShapeTashkeelIsolated,
+}
+
+/// Digit shaping mode (`U_SHAPE_DIGITS_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DigitsMode {
+    /// Do not shape digits
+    #[default]
+    Noop,
+    /// Replace European digits by Arabic-Indic digits
+    En2An,
+    /// Replace Arabic-Indic digits by European digits
+    An2En,
+    /// Replace European digits by Arabic-Indic digits if the most recent strong character is
+    /// an Arabic letter, assuming a left-to-right (or Hebrew) character before the text
+    AlEn2AnInitLr,
+    /// Same as `AlEn2AnInitLr`, assuming an Arabic letter before the text
+    AlEn2AnInitAl,
+}
+
+/// The Arabic-Indic digits used by digit shaping (`U_SHAPE_DIGIT_TYPE_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DigitType {
+    /// Arabic-Indic digits (U+0660–U+0669)
+    #[default]
+    An,
+    /// Eastern (Extended) Arabic-Indic digits (U+06F0–U+06F9)
+    AnExtended,
+}
+
+/// Where the space freed or needed by a lam-alef ligature goes (`U_SHAPE_LAMALEF_*`, aliased by
+/// `U_SHAPE_LENGTH_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum LamAlefMode {
+    /// The output length may differ from the input
+    #[default]
+    Resize,
+    /// Keep the length, using the space next to the ligature
+    Near,
+    /// Keep the length, using spaces at the end of the text
+    End,
+    /// Keep the length, using spaces at the beginning of the text
+    Begin,
+    /// Keep the length, using spaces at the end, then the beginning, then next to the ligature
+    Auto,
+}
+
+/// What happens to tashkeel when shaping (`U_SHAPE_TASHKEEL_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum TashkeelMode {
+    /// Keep tashkeel
+    #[default]
+    Noop,
+    /// Replace tashkeel by spaces at the beginning of the text
+    Begin,
+    /// Replace tashkeel by spaces at the end of the text
+    End,
+    /// Remove tashkeel, the output shrinks
+    Resize,
+    /// Replace tashkeel by a tatweel if it joins its neighbours, otherwise by a space
+    ReplaceByTatweel,
+}
+
+/// The order of the input text (`U_SHAPE_TEXT_DIRECTION_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Logical (keyboard) order, also used for visual right-to-left text
+    #[default]
+    Logical,
+    /// Visual left-to-right order, the leftmost displayed character first
+    VisualLtr,
+}
+
+/// Where the tail of a seen family letter goes (`U_SHAPE_SEEN_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum SeenMode {
+    /// Shape seen family letters into a single cell
+    #[default]
+    Noop,
+    /// Expand seen family letters into two cells, using the space next to them
+    TwoCellNear,
+}
+
+/// Where the hamza of a yeh-hamza goes (`U_SHAPE_YEHHAMZA_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum YehHamzaMode {
+    /// Shape yeh-hamza into a single cell
+    #[default]
+    Noop,
+    /// Expand yeh-hamza into two cells, using the space next to it
+    TwoCellNear,
+}
+
+/// The tail character of two cell seen family letters (`U_SHAPE_TAIL_*`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum TailType {
+    /// The old unofficial tail, U+200B
+    #[default]
+    Old,
+    /// The Unicode tail, U+FE73
+    NewUnicode,
+}
+
+/// Typed options of `shape_unicode`, which convert to and from the `U_SHAPE_*` bits.
+/// Pass them to the shaping functions as `&options.into()` or `&options.bits()`. The bits
+/// themselves are only exported by the JavaScript package, so in Rust these options are the
+/// way to build them, e.g. the `U_SHAPE_BASE_DIRECTION_*` bits through `base_direction`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ShapeOptions {
+    /// Letter shaping mode
+    pub letters: LettersMode,
+    /// Digit shaping mode
+    pub digits: DigitsMode,
+    /// The Arabic-Indic digits used by digit shaping
+    pub digit_type: DigitType,
+    /// Where the space of lam-alef ligatures goes
+    pub lam_alef: LamAlefMode,
+    /// What happens to tashkeel
+    pub tashkeel: TashkeelMode,
+    /// The order of the input text
+    pub text_direction: TextDirection,
+    /// Aggregate shadda with another tashkeel into a single ligature
+    pub aggregate_tashkeel: bool,
+    /// Where the tail of seen family letters goes
+    pub seen: SeenMode,
+    /// Where the hamza of yeh-hamza goes
+    pub yeh_hamza: YehHamzaMode,
+    /// The tail character of two cell seen family letters
+    pub tail: TailType,
+    /// Swap the meaning of begin and end for visual left-to-right text
+    pub spaces_relative_to_text: bool,
+    /// Reorder the output into visual order with the bidi algorithm
+    pub bidi: bool,
+    /// The base direction of the bidi output, the only way to set the
+    /// `U_SHAPE_BASE_DIRECTION_*` bits in Rust
+    pub base_direction: BaseDirection,
+}
+impl ShapeOptions {
+    /// Create options that leave the text as it is
+    pub fn new() -> ShapeOptions {
+        ShapeOptions::default()
+    }
+
+    /// Set the letter shaping mode
+    pub fn letters(mut self, letters: LettersMode) -> ShapeOptions {
+        self.letters = letters;
+        self
+    }
+
+    /// Set the digit shaping mode
+    pub fn digits(mut self, digits: DigitsMode) -> ShapeOptions {
+        self.digits = digits;
+        self
+    }
+
+    /// Set the Arabic-Indic digits used by digit shaping
+    pub fn digit_type(mut self, digit_type: DigitType) -> ShapeOptions {
+        self.digit_type = digit_type;
+        self
+    }
+
+    /// Set where the space of lam-alef ligatures goes
+    pub fn lam_alef(mut self, lam_alef: LamAlefMode) -> ShapeOptions {
+        self.lam_alef = lam_alef;
+        self
+    }
+
+    /// Set what happens to tashkeel
+    pub fn tashkeel(mut self, tashkeel: TashkeelMode) -> ShapeOptions {
+        self.tashkeel = tashkeel;
+        self
+    }
+
+    /// Set the order of the input text
+    pub fn text_direction(mut self, text_direction: TextDirection) -> ShapeOptions {
+        self.text_direction = text_direction;
+        self
+    }
+
+    /// Set whether shadda is aggregated with another tashkeel
+    pub fn aggregate_tashkeel(mut self, aggregate_tashkeel: bool) -> ShapeOptions {
+        self.aggregate_tashkeel = aggregate_tashkeel;
+        self
+    }
+
+    /// Set where the tail of seen family letters goes
+    pub fn seen(mut self, seen: SeenMode) -> ShapeOptions {
+        self.seen = seen;
+        self
+    }
+
+    /// Set where the hamza of yeh-hamza goes
+    pub fn yeh_hamza(mut self, yeh_hamza: YehHamzaMode) -> ShapeOptions {
+        self.yeh_hamza = yeh_hamza;
+        self
+    }
+
+    /// Set the tail character of two cell seen family letters
+    pub fn tail(mut self, tail: TailType) -> ShapeOptions {
+        self.tail = tail;
+        self
+    }
+
+    /// Set whether begin and end are swapped for visual left-to-right text
+    pub fn spaces_relative_to_text(mut self, spaces_relative_to_text: bool) -> ShapeOptions {
+        self.spaces_relative_to_text = spaces_relative_to_text;
+        self
+    }
+
+    /// Set whether the output is reordered with the bidi algorithm
+    pub fn bidi(mut self, bidi: bool) -> ShapeOptions {
+        self.bidi = bidi;
+        self
+    }
+
+    /// Set the base direction of the bidi output, i.e. the `U_SHAPE_BASE_DIRECTION_*` bits of
+    /// `bits()`
+    pub fn base_direction(mut self, base_direction: BaseDirection) -> ShapeOptions {
+        self.base_direction = base_direction;
+        self
+    }
+
+    /// Get the `U_SHAPE_*` bits of the options
+    pub fn bits(&self) -> u32 {
+        let letters = match self.letters {
+            LettersMode::Noop => U_SHAPE_LETTERS_NOOP,
+            LettersMode::Shape => U_SHAPE_LETTERS_SHAPE,
+            LettersMode::Unshape => U_SHAPE_LETTERS_UNSHAPE,
+            LettersMode::ShapeTashkeelIsolated => U_SHAPE_LETTERS_SHAPE_TASHKEEL_ISOLATED,
+        };
+        let digits = match self.digits {
+            DigitsMode::Noop => U_SHAPE_DIGITS_NOOP,
+            DigitsMode::En2An => U_SHAPE_DIGITS_EN2AN,
+            DigitsMode::An2En => U_SHAPE_DIGITS_AN2EN,
+            DigitsMode::AlEn2AnInitLr => U_SHAPE_DIGITS_ALEN2AN_INIT_LR,
+            DigitsMode::AlEn2AnInitAl => U_SHAPE_DIGITS_ALEN2AN_INIT_AL,
+        };
+        let digit_type = match self.digit_type {
+            DigitType::An => U_SHAPE_DIGIT_TYPE_AN,
+            DigitType::AnExtended => U_SHAPE_DIGIT_TYPE_AN_EXTENDED,
+        };
+        let lam_alef = match self.lam_alef {
+            LamAlefMode::Resize => U_SHAPE_LAMALEF_RESIZE,
+            LamAlefMode::Near => U_SHAPE_LAMALEF_NEAR,
+            LamAlefMode::End => U_SHAPE_LAMALEF_END,
+            LamAlefMode::Begin => U_SHAPE_LAMALEF_BEGIN,
+            LamAlefMode::Auto => U_SHAPE_LAMALEF_AUTO,
+        };
+        let tashkeel = match self.tashkeel {
+            TashkeelMode::Noop => 0,
+            TashkeelMode::Begin => U_SHAPE_TASHKEEL_BEGIN,
+            TashkeelMode::End => U_SHAPE_TASHKEEL_END,
+            TashkeelMode::Resize => U_SHAPE_TASHKEEL_RESIZE,
+            TashkeelMode::ReplaceByTatweel => U_SHAPE_TASHKEEL_REPLACE_BY_TATWEEL,
+        };
+        let text_direction = match self.text_direction {
+            TextDirection::Logical => U_SHAPE_TEXT_DIRECTION_LOGICAL,
+            TextDirection::VisualLtr => U_SHAPE_TEXT_DIRECTION_VISUAL_LTR,
+        };
+        let seen = match self.seen {
+            SeenMode::Noop => 0,
+            SeenMode::TwoCellNear => U_SHAPE_SEEN_TWOCELL_NEAR,
+        };
+        let yeh_hamza = match self.yeh_hamza {
+            YehHamzaMode::Noop => 0,
+            YehHamzaMode::TwoCellNear => U_SHAPE_YEHHAMZA_TWOCELL_NEAR,
+        };
+        let tail = match self.tail {
+            TailType::Old => 0,
+            TailType::NewUnicode => U_SHAPE_TAIL_NEW_UNICODE,
+        };
+        let base_direction = match self.base_direction {
+            BaseDirection::Auto => U_SHAPE_BASE_DIRECTION_AUTO,
+            BaseDirection::Ltr => U_SHAPE_BASE_DIRECTION_LTR,
+            BaseDirection::Rtl => U_SHAPE_BASE_DIRECTION_RTL,
+        };
+        let flag = |set: bool, bit: u32| if set { bit } else { 0 };
+
+        letters
+            | digits
+            | digit_type
+            | lam_alef
+            | tashkeel
+            | text_direction
+            | seen
+            | yeh_hamza
+            | tail
+            | base_direction
+            | flag(self.aggregate_tashkeel, U_SHAPE_AGGREGATE_TASHKEEL)
+            | flag(self.spaces_relative_to_text, U_SHAPE_SPACES_RELATIVE_TO_TEXT_BEGIN_END)
+            | flag(self.bidi, U_SHAPE_DIRECTION_OUTPUT_BIDI)
+    }
+
+    /// Parse `U_SHAPE_*` bits. Reserved values and bits that are not part of any option are
+    /// rejected.
+    pub fn from_bits(bits: u32) -> Result<ShapeOptions, InvalidShapeOptions> {
+        let invalid = InvalidShapeOptions(bits);
+        let letters = match bits & U_SHAPE_LETTERS_MASK {
+            U_SHAPE_LETTERS_NOOP => LettersMode::Noop,
+            U_SHAPE_LETTERS_SHAPE => LettersMode::Shape,
+            U_SHAPE_LETTERS_UNSHAPE => LettersMode::Unshape,
+            _ => LettersMode::ShapeTashkeelIsolated,
+        };
+        let digits = match bits & U_SHAPE_DIGITS_MASK {
+            U_SHAPE_DIGITS_NOOP => DigitsMode::Noop,
+            U_SHAPE_DIGITS_EN2AN => DigitsMode::En2An,
+            U_SHAPE_DIGITS_AN2EN => DigitsMode::An2En,
+            U_SHAPE_DIGITS_ALEN2AN_INIT_LR => DigitsMode::AlEn2AnInitLr,
+            U_SHAPE_DIGITS_ALEN2AN_INIT_AL => DigitsMode::AlEn2AnInitAl,
+            _ => return Err(invalid),
+        };
+        let digit_type = match bits & U_SHAPE_DIGIT_TYPE_MASK {
+            U_SHAPE_DIGIT_TYPE_AN => DigitType::An,
+            U_SHAPE_DIGIT_TYPE_AN_EXTENDED => DigitType::AnExtended,
+            _ => return Err(invalid),
+        };
+        let lam_alef = match bits & U_SHAPE_LAMALEF_MASK {
+            U_SHAPE_LAMALEF_RESIZE => LamAlefMode::Resize,
+            U_SHAPE_LAMALEF_NEAR => LamAlefMode::Near,
+            U_SHAPE_LAMALEF_END => LamAlefMode::End,
+            U_SHAPE_LAMALEF_BEGIN => LamAlefMode::Begin,
+            U_SHAPE_LAMALEF_AUTO => LamAlefMode::Auto,
+            _ => return Err(invalid),
+        };
+        let tashkeel = match bits & U_SHAPE_TASHKEEL_MASK {
+            0 => TashkeelMode::Noop,
+            U_SHAPE_TASHKEEL_BEGIN => TashkeelMode::Begin,
+            U_SHAPE_TASHKEEL_END => TashkeelMode::End,
+            U_SHAPE_TASHKEEL_RESIZE => TashkeelMode::Resize,
+            U_SHAPE_TASHKEEL_REPLACE_BY_TATWEEL => TashkeelMode::ReplaceByTatweel,
+            _ => return Err(invalid),
+        };
+        let text_direction = match bits & U_SHAPE_TEXT_DIRECTION_MASK {
+            U_SHAPE_TEXT_DIRECTION_LOGICAL => TextDirection::Logical,
+            _ => TextDirection::VisualLtr,
+        };
+        // U_SHAPE_DIRECTION_OUTPUT_BIDI is the lowest bit of U_SHAPE_SEEN_MASK
+        let seen = match bits & U_SHAPE_SEEN_MASK & !U_SHAPE_DIRECTION_OUTPUT_BIDI {
+            0 => SeenMode::Noop,
+            U_SHAPE_SEEN_TWOCELL_NEAR => SeenMode::TwoCellNear,
+            _ => return Err(invalid),
+        };
+        let yeh_hamza = match bits & U_SHAPE_YEHHAMZA_MASK {
+            0 => YehHamzaMode::Noop,
+            U_SHAPE_YEHHAMZA_TWOCELL_NEAR => YehHamzaMode::TwoCellNear,
+            _ => return Err(invalid),
+        };
+        let tail = match bits & U_SHAPE_TAIL_TYPE_MASK {
+            0 => TailType::Old,
+            _ => TailType::NewUnicode,
+        };
+        let base_direction = match bits & U_SHAPE_BASE_DIRECTION_MASK {
+            U_SHAPE_BASE_DIRECTION_AUTO => BaseDirection::Auto,
+            U_SHAPE_BASE_DIRECTION_LTR => BaseDirection::Ltr,
+            U_SHAPE_BASE_DIRECTION_RTL => BaseDirection::Rtl,
+            _ => return Err(invalid),
+        };
+        let options = ShapeOptions {
+            letters,
+            digits,
+            digit_type,
+            lam_alef,
+            tashkeel,
+            text_direction,
+            aggregate_tashkeel: bits & U_SHAPE_AGGREGATE_TASHKEEL_MASK != 0,
+            seen,
+            yeh_hamza,
+            tail,
+            spaces_relative_to_text: bits & U_SHAPE_SPACES_RELATIVE_TO_TEXT_MASK != 0,
+            bidi: bits & U_SHAPE_DIRECTION_OUTPUT_BIDI != 0,
+            base_direction,
+        };
+        // every bit must belong to an option
+        if options.bits() != bits {
+            return Err(invalid);
+        }
+
+        Ok(options)
+    }
+}
+
+impl From<ShapeOptions> for u32 {
+    fn from(options: ShapeOptions) -> u32 {
+        options.bits()
+    }
+}
+
+impl TryFrom<u32> for ShapeOptions {
+    type Error = InvalidShapeOptions;
+
+    fn try_from(bits: u32) -> Result<ShapeOptions, InvalidShapeOptions> {
+        ShapeOptions::from_bits(bits)
+    }
+}
+
+/// `U_SHAPE_*` bits with a reserved value or a bit that is not part of any option
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidShapeOptions(pub u32);
+impl fmt::Display for InvalidShapeOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid shape options: {:#x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_bits() {
+        let options = ShapeOptions::new().letters(LettersMode::Shape).bidi(true);
+        assert_eq!(
+            options.bits(),
+            U_SHAPE_LETTERS_SHAPE | U_SHAPE_TEXT_DIRECTION_LOGICAL | U_SHAPE_DIRECTION_OUTPUT_BIDI
+        );
+        assert_eq!(ShapeOptions::new().bits(), 0);
+        let options = ShapeOptions::new()
+            .letters(LettersMode::ShapeTashkeelIsolated)
+            .digits(DigitsMode::AlEn2AnInitAl)
+            .digit_type(DigitType::AnExtended)
+            .lam_alef(LamAlefMode::Auto)
+            .tashkeel(TashkeelMode::ReplaceByTatweel)
+            .text_direction(TextDirection::VisualLtr)
+            .aggregate_tashkeel(true)
+            .seen(SeenMode::TwoCellNear)
+            .yeh_hamza(YehHamzaMode::TwoCellNear)
+            .tail(TailType::NewUnicode)
+            .spaces_relative_to_text(true)
+            .bidi(true)
+            .base_direction(BaseDirection::Rtl);
+        assert_eq!(ShapeOptions::try_from(u32::from(options)), Ok(options));
+    }
+
+    #[test]
+    fn invalid_bits() {
+        assert_eq!(
+            ShapeOptions::from_bits(U_SHAPE_DIGITS_RESERVED),
+            Err(InvalidShapeOptions(0xa0))
+        );
+        assert!(ShapeOptions::from_bits(U_SHAPE_DIGIT_TYPE_RESERVED).is_err());
+        assert!(ShapeOptions::from_bits(U_SHAPE_LAMALEF_AUTO | U_SHAPE_LAMALEF_NEAR).is_err());
+        assert!(ShapeOptions::from_bits(U_SHAPE_TASHKEEL_MASK).is_err());
+        assert!(ShapeOptions::from_bits(U_SHAPE_BASE_DIRECTION_MASK).is_err());
+        assert!(ShapeOptions::from_bits(0x400).is_err());
+        // presentation forms in the input are always kept as they are
+        assert!(ShapeOptions::from_bits(U_SHAPE_PRESERVE_PRESENTATION).is_err());
+        // output bidi and two cell seen share U_SHAPE_SEEN_MASK
+        let options =
+            ShapeOptions::from_bits(U_SHAPE_DIRECTION_OUTPUT_BIDI | U_SHAPE_SEEN_TWOCELL_NEAR);
+        assert_eq!(options, Ok(ShapeOptions::new().bidi(true).seen(SeenMode::TwoCellNear)));
+    }
+}
@@ -0,0 +1,321 @@
+// https://www.unicode.org/reports/tr9/#Explicit_Levels_and_Directions
+// https://www.unicode.org/reports/tr9/#Preparations_for_Implicit_Processing
+
+use super::internal::*;
+use alloc::vec::Vec;
+
+/// The maximum explicit embedding level (BD2)
+pub const MAX_DEPTH: u8 = 125;
+
+/// Get the least odd level greater than `level`
+fn next_odd(level: u8) -> u8 {
+    (level + 1) | 1
+}
+
+/// Get the least even level greater than `level`
+fn next_even(level: u8) -> u8 {
+    (level + 2) & !1
+}
+
+/// Find the matching PDI of every isolate initiator (BD9). The result has one entry
+/// per code unit, only isolate initiators with a matching PDI store `Some(pdi_index)`.
+pub fn match_isolates(input: &[u16]) -> Vec<Option<usize>> {
+    let mut matches: Vec<Option<usize>> = Vec::with_capacity(input.len());
+    matches.resize(input.len(), None);
+    let mut open = Vec::<usize>::new();
+    for (idx, c) in input.iter().enumerate() {
+        match get_explicit(c) {
+            Some(e) if e.is_isolate_initiator() => open.push(idx),
+            Some(Explicit::Pdi) => {
+                if let Some(start) = open.pop() {
+                    matches[start] = Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    matches
+}
+
+/// P2 & P3: Find the level of the first strong character, skipping over any characters
+/// between an isolate initiator and its matching PDI. Returns `None` if no strong
+/// character is found.
+pub fn first_strong_level(input: &[u16]) -> Option<u8> {
+    let mut isolate_depth: usize = 0;
+    for (c, class) in input.iter().zip(get_bidi_classes(input)) {
+        match get_explicit(c) {
+            Some(e) if e.is_isolate_initiator() => isolate_depth += 1,
+            Some(Explicit::Pdi) => {
+                isolate_depth = isolate_depth.saturating_sub(1);
+            }
+            Some(_) => {}
+            None => {
+                if isolate_depth == 0 {
+                    match class {
+                        BidiClass::R | BidiClass::AL => return Some(1),
+                        BidiClass::L => return Some(0),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// An entry of the directional status stack (X1)
+#[derive(Copy, Clone)]
+struct Status {
+    level: u8,
+    r#override: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Apply rules X1–X9 to a paragraph. `classes` starts as the Bidi_Class of each character and
+/// picks up any directional overrides, `levels` receives the explicit embedding levels and
+/// `removed` flags the characters X9 removes (embeddings, overrides, PDF and BN).
+pub fn resolve_explicit(
+    input: &[u16],
+    para_level: u8,
+    matches: &[Option<usize>],
+    classes: &mut [BidiClass],
+    levels: &mut [u8],
+    removed: &mut [bool],
+) {
+    // X1
+    let mut stack = Vec::<Status>::with_capacity(MAX_DEPTH as usize + 2);
+    stack.push(Status { level: para_level, r#override: None, isolate: false });
+    let mut overflow_isolate_count: usize = 0;
+    let mut overflow_embedding_count: usize = 0;
+    let mut valid_isolate_count: usize = 0;
+
+    for (idx, c) in input.iter().enumerate() {
+        let last = *stack.last().unwrap();
+        match get_explicit(c) {
+            // X2–X5
+            Some(e @ (Explicit::Rle | Explicit::Lre | Explicit::Rlo | Explicit::Lro)) => {
+                levels[idx] = last.level;
+                removed[idx] = true;
+                let is_rtl = e == Explicit::Rle || e == Explicit::Rlo;
+                let level = if is_rtl { next_odd(last.level) } else { next_even(last.level) };
+                if level <= MAX_DEPTH
+                    && overflow_isolate_count == 0
+                    && overflow_embedding_count == 0
+                {
+                    let r#override = match e {
+                        Explicit::Rlo => Some(BidiClass::R),
+                        Explicit::Lro => Some(BidiClass::L),
+                        _ => None,
+                    };
+                    stack.push(Status { level, r#override, isolate: false });
+                } else if overflow_isolate_count == 0 {
+                    overflow_embedding_count += 1;
+                }
+            }
+            // X5a–X5c
+            Some(e @ (Explicit::Rli | Explicit::Lri | Explicit::Fsi)) => {
+                levels[idx] = last.level;
+                // isolate initiators and PDI are neutrals for the implicit rules
+                classes[idx] = last.r#override.unwrap_or(BidiClass::ON);
+                let is_rtl = match e {
+                    Explicit::Rli => true,
+                    Explicit::Fsi => {
+                        let end = matches[idx].unwrap_or(input.len());
+                        first_strong_level(&input[idx + 1..end]) == Some(1)
+                    }
+                    _ => false,
+                };
+                let level = if is_rtl { next_odd(last.level) } else { next_even(last.level) };
+                if level <= MAX_DEPTH
+                    && overflow_isolate_count == 0
+                    && overflow_embedding_count == 0
+                {
+                    valid_isolate_count += 1;
+                    stack.push(Status { level, r#override: None, isolate: true });
+                } else {
+                    overflow_isolate_count += 1;
+                }
+            }
+            // X6a
+            Some(Explicit::Pdi) => {
+                if overflow_isolate_count > 0 {
+                    overflow_isolate_count -= 1;
+                } else if valid_isolate_count > 0 {
+                    overflow_embedding_count = 0;
+                    while !stack.last().unwrap().isolate {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolate_count -= 1;
+                }
+                let last = *stack.last().unwrap();
+                levels[idx] = last.level;
+                classes[idx] = last.r#override.unwrap_or(BidiClass::ON);
+            }
+            // X7
+            Some(Explicit::Pdf) => {
+                levels[idx] = last.level;
+                removed[idx] = true;
+                if overflow_isolate_count > 0 {
+                    // do nothing
+                } else if overflow_embedding_count > 0 {
+                    overflow_embedding_count -= 1;
+                } else if !last.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            // X8: paragraph separators end all embeddings, overrides and isolates
+            None if classes[idx] == BidiClass::B => {
+                levels[idx] = para_level;
+            }
+            // X6 & X9
+            None => {
+                levels[idx] = last.level;
+                if classes[idx] == BidiClass::BN {
+                    removed[idx] = true;
+                } else if let Some(t) = last.r#override {
+                    classes[idx] = t;
+                }
+            }
+        }
+    }
+}
+
+/// A sequence of level runs that are resolved together (BD13)
+pub struct IsolatingRunSequence {
+    /// Indices of the characters in the sequence, in logical order
+    pub indices: Vec<usize>,
+    /// The embedding level shared by all characters of the sequence
+    pub level: u8,
+    /// The start-of-sequence type (R or L)
+    pub sos: BidiClass,
+    /// The end-of-sequence type (R or L)
+    pub eos: BidiClass,
+}
+
+/// Get the direction of an embedding level
+fn level_type(level: u8) -> BidiClass {
+    if level & 1 == 1 {
+        BidiClass::R
+    } else {
+        BidiClass::L
+    }
+}
+
+/// X10: Compute the isolating run sequences of a paragraph, skipping removed characters.
+pub fn isolating_run_sequences(
+    input: &[u16],
+    para_level: u8,
+    matches: &[Option<usize>],
+    levels: &[u8],
+    removed: &[bool],
+) -> Vec<IsolatingRunSequence> {
+    // BD7: level runs
+    let mut runs = Vec::<Vec<usize>>::new();
+    let mut run_level: u8 = 0;
+    for idx in 0..input.len() {
+        if removed[idx] {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if levels[idx] == run_level => run.push(idx),
+            _ => {
+                run_level = levels[idx];
+                runs.push([idx].to_vec());
+            }
+        }
+    }
+
+    // the run that starts at each matched PDI
+    let mut run_by_start = Vec::<Option<usize>>::with_capacity(input.len());
+    run_by_start.resize(input.len(), None);
+    for (run_idx, run) in runs.iter().enumerate() {
+        run_by_start[run[0]] = Some(run_idx);
+    }
+    let mut is_matched_pdi = Vec::<bool>::with_capacity(input.len());
+    is_matched_pdi.resize(input.len(), false);
+    for pdi in matches.iter().flatten() {
+        is_matched_pdi[*pdi] = true;
+    }
+
+    // BD13: chain runs that end in an isolate initiator to the run of its matching PDI
+    let mut sequences = Vec::<IsolatingRunSequence>::new();
+    for run in runs.iter() {
+        if is_matched_pdi[run[0]] {
+            continue;
+        }
+        let mut indices = run.clone();
+        while let Some(pdi) = matches[*indices.last().unwrap()] {
+            match run_by_start[pdi] {
+                Some(next_run) => indices.extend_from_slice(&runs[next_run]),
+                None => break,
+            }
+        }
+        let level = levels[indices[0]];
+        let first = indices[0];
+        let last = *indices.last().unwrap();
+        // sos: the higher of the sequence level and the preceding character level
+        let prev_level = (0..first).rev().find(|i| !removed[*i]).map_or(para_level, |i| levels[i]);
+        // eos: the higher of the sequence level and the following character level,
+        // unless the sequence ends with an isolate initiator
+        let ends_in_isolate = get_explicit(&input[last]).is_some_and(|e| e.is_isolate_initiator());
+        let next_level = if ends_in_isolate {
+            para_level
+        } else {
+            (last + 1..input.len()).find(|i| !removed[*i]).map_or(para_level, |i| levels[i])
+        };
+        sequences.push(IsolatingRunSequence {
+            indices,
+            level,
+            sos: level_type(level.max(prev_level)),
+            eos: level_type(level.max(next_level)),
+        });
+    }
+
+    sequences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedding_levels() {
+        // a RLE b PDF c
+        let input: &[u16] = &[0x61, 0x202B, 0x62, 0x202C, 0x63];
+        let matches = match_isolates(input);
+        let mut classes: Vec<BidiClass> = input.iter().map(get_bidi_class).collect();
+        let mut levels: Vec<u8> = [0; 5].to_vec();
+        let mut removed: Vec<bool> = [false; 5].to_vec();
+        resolve_explicit(input, 0, &matches, &mut classes, &mut levels, &mut removed);
+        assert_eq!(levels, [0, 0, 1, 1, 0]);
+        assert_eq!(removed, [false, true, false, true, false]);
+    }
+
+    #[test]
+    fn override_and_isolate() {
+        // RLO a b PDF LRI c PDI
+        let input: &[u16] = &[0x202E, 0x61, 0x62, 0x202C, 0x2066, 0x63, 0x2069];
+        let matches = match_isolates(input);
+        assert_eq!(matches[4], Some(6));
+        let mut classes: Vec<BidiClass> = input.iter().map(get_bidi_class).collect();
+        let mut levels: Vec<u8> = [0; 7].to_vec();
+        let mut removed: Vec<bool> = [false; 7].to_vec();
+        resolve_explicit(input, 0, &matches, &mut classes, &mut levels, &mut removed);
+        assert_eq!(levels, [0, 1, 1, 1, 0, 2, 0]);
+        assert!(classes[1] == BidiClass::R && classes[2] == BidiClass::R);
+
+        let sequences = isolating_run_sequences(input, 0, &matches, &levels, &removed);
+        assert_eq!(sequences.len(), 3);
+        assert_eq!(sequences[1].indices, [4, 6]);
+    }
+
+    #[test]
+    fn first_strong_skips_isolates() {
+        // RLI alef PDI b
+        let input: &[u16] = &[0x2067, 0x05D0, 0x2069, 0x62];
+        assert_eq!(first_strong_level(input), Some(0));
+        assert_eq!(first_strong_level(&input[1..2]), Some(1));
+        assert_eq!(first_strong_level(&[0x20, 0x31]), None);
+    }
+}
@@ -0,0 +1,223 @@
+// https://www.unicode.org/reports/tr9/#Resolving_Weak_Types
+// https://www.unicode.org/reports/tr9/#Resolving_Neutral_Types
+// https://www.unicode.org/reports/tr9/#Resolving_Implicit_Levels
+
+use super::{explicit::IsolatingRunSequence, internal::*};
+use alloc::vec::Vec;
+
+/// The maximum depth of the bracket pair stack (BD16)
+const MAX_BRACKET_STACK: usize = 63;
+
+/// Resolve the weak (W1–W7) and neutral (N0–N2) types of an isolating run sequence, then
+/// apply I1 & I2 to the embedding levels. `classes` are the Bidi_Class values of the
+/// paragraph after explicit processing (overrides applied).
+pub fn resolve_implicit(
+    input: &[u16],
+    seq: &IsolatingRunSequence,
+    classes: &[BidiClass],
+    levels: &mut [u8],
+) {
+    let embedding_class = if seq.level & 1 == 1 { BidiClass::R } else { BidiClass::L };
+    let mut resolved: Vec<BidiClass> = seq.indices.iter().map(|idx| classes[*idx]).collect();
+    resolve_weak(seq, &mut resolved);
+    // N0: resolve paired brackets
+    resolve_brackets(input, seq, classes, embedding_class, &mut resolved);
+    // N1 & N2: resolve neutral types using the surrounding strong types
+    let mut i: usize = 0;
+    while i < resolved.len() {
+        if !is_neutral_or_isolate(resolved[i]) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < resolved.len() && is_neutral_or_isolate(resolved[i]) {
+            i += 1;
+        }
+        let prev = if start == 0 { Some(seq.sos) } else { strong_direction(resolved[start - 1]) };
+        let next = if i == resolved.len() { Some(seq.eos) } else { strong_direction(resolved[i]) };
+        let dir = if prev == next { prev.unwrap_or(embedding_class) } else { embedding_class };
+        for t in resolved[start..i].iter_mut() {
+            *t = dir;
+        }
+    }
+    // I1 & I2
+    for (idx, t) in seq.indices.iter().zip(resolved) {
+        let level = levels[*idx];
+        levels[*idx] = match (level & 1 == 1, t) {
+            (false, BidiClass::R) => level + 1,
+            (false, BidiClass::AN | BidiClass::EN) => level + 2,
+            (true, BidiClass::L | BidiClass::AN | BidiClass::EN) => level + 1,
+            _ => level,
+        };
+    }
+}
+
+/// W1–W7: Resolve the weak types of an isolating run sequence
+fn resolve_weak(seq: &IsolatingRunSequence, resolved: &mut [BidiClass]) {
+    // W1: NSM takes the type of the previous character (isolate initiators and PDI are
+    // already ON after explicit processing)
+    let mut prev = seq.sos;
+    for t in resolved.iter_mut() {
+        if *t == BidiClass::NSM {
+            *t = prev;
+        }
+        prev = *t;
+    }
+    // W2: EN after AL becomes AN, W3: AL becomes R
+    let mut last_strong = seq.sos;
+    for t in resolved.iter_mut() {
+        match *t {
+            BidiClass::L | BidiClass::R => last_strong = *t,
+            BidiClass::AL => {
+                last_strong = BidiClass::AL;
+                *t = BidiClass::R;
+            }
+            BidiClass::EN if last_strong == BidiClass::AL => *t = BidiClass::AN,
+            _ => {}
+        }
+    }
+    // W4: a single separator between two numbers of the same type joins them
+    for i in 1..resolved.len().saturating_sub(1) {
+        match (resolved[i - 1], resolved[i], resolved[i + 1]) {
+            (BidiClass::EN, BidiClass::ES | BidiClass::CS, BidiClass::EN) => {
+                resolved[i] = BidiClass::EN
+            }
+            (BidiClass::AN, BidiClass::CS, BidiClass::AN) => resolved[i] = BidiClass::AN,
+            _ => {}
+        }
+    }
+    // W5: terminators next to European numbers become European numbers
+    let mut i: usize = 0;
+    while i < resolved.len() {
+        if resolved[i] != BidiClass::ET {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < resolved.len() && resolved[i] == BidiClass::ET {
+            i += 1;
+        }
+        let next_to_number = (start > 0 && resolved[start - 1] == BidiClass::EN)
+            || (i < resolved.len() && resolved[i] == BidiClass::EN);
+        if next_to_number {
+            for t in resolved[start..i].iter_mut() {
+                *t = BidiClass::EN;
+            }
+        }
+    }
+    // W6: remaining separators and terminators become ON
+    for t in resolved.iter_mut() {
+        if matches!(*t, BidiClass::ES | BidiClass::ET | BidiClass::CS) {
+            *t = BidiClass::ON;
+        }
+    }
+    // W7: EN after L (or a L sos) becomes L
+    let mut last_strong = seq.sos;
+    for t in resolved.iter_mut() {
+        match *t {
+            BidiClass::L | BidiClass::R => last_strong = *t,
+            BidiClass::EN if last_strong == BidiClass::L => *t = BidiClass::L,
+            _ => {}
+        }
+    }
+}
+
+/// Check if a resolved type is a neutral or isolate formatting character (NI)
+fn is_neutral_or_isolate(t: BidiClass) -> bool {
+    matches!(
+        t,
+        BidiClass::B
+            | BidiClass::S
+            | BidiClass::WS
+            | BidiClass::ON
+            | BidiClass::BN
+            | BidiClass::LRI
+            | BidiClass::RLI
+            | BidiClass::FSI
+            | BidiClass::PDI
+    )
+}
+
+/// Get the strong direction of a resolved type, numbers act as R for neutrals (N1)
+fn strong_direction(t: BidiClass) -> Option<BidiClass> {
+    match t {
+        BidiClass::L => Some(BidiClass::L),
+        BidiClass::R | BidiClass::AL | BidiClass::EN | BidiClass::AN => Some(BidiClass::R),
+        _ => None,
+    }
+}
+
+/// BD16: Find the bracket pairs of an isolating run sequence. Pairs are stored as positions
+/// within the sequence, sorted by the position of the opening bracket.
+fn find_bracket_pairs(
+    input: &[u16],
+    seq: &IsolatingRunSequence,
+    resolved: &[BidiClass],
+) -> Vec<[usize; 2]> {
+    let mut stack = Vec::<(u16, usize)>::with_capacity(MAX_BRACKET_STACK);
+    let mut pairs = Vec::<[usize; 2]>::new();
+    for (pos, idx) in seq.indices.iter().enumerate() {
+        // only brackets that are still neutral (e.g. not overridden) can pair
+        if resolved[pos] != BidiClass::ON {
+            continue;
+        }
+        match get_bracket(&input[*idx]) {
+            Some((BracketType::Open, open)) => {
+                if stack.len() == MAX_BRACKET_STACK {
+                    break;
+                }
+                stack.push((open, pos));
+            }
+            Some((BracketType::Close, open)) => {
+                if let Some(depth) = stack.iter().rposition(|(o, _)| *o == open) {
+                    pairs.push([stack[depth].1, pos]);
+                    stack.truncate(depth);
+                }
+            }
+            None => {}
+        }
+    }
+    pairs.sort_by_key(|pair| pair[0]);
+
+    pairs
+}
+
+/// N0: Resolve each bracket pair to the embedding direction if it contains a strong type
+/// matching it, otherwise to the opposite direction if both its contents and its preceding
+/// context have that direction. Nonspacing marks following a resolved bracket take its type.
+fn resolve_brackets(
+    input: &[u16],
+    seq: &IsolatingRunSequence,
+    classes: &[BidiClass],
+    embedding_class: BidiClass,
+    resolved: &mut [BidiClass],
+) {
+    for [open, close] in find_bracket_pairs(input, seq, resolved) {
+        let mut found_embedding = false;
+        let mut found_opposite = false;
+        for t in resolved[open + 1..close].iter().filter_map(|t| strong_direction(*t)) {
+            if t == embedding_class {
+                found_embedding = true;
+                break;
+            }
+            found_opposite = true;
+        }
+        let dir = if found_embedding {
+            embedding_class
+        } else if found_opposite {
+            // check the context before the opening bracket
+            resolved[..open].iter().rev().find_map(|t| strong_direction(*t)).unwrap_or(seq.sos)
+        } else {
+            continue;
+        };
+        for bracket in [open, close] {
+            resolved[bracket] = dir;
+            for pos in bracket + 1..resolved.len() {
+                if classes[seq.indices[pos]] != BidiClass::NSM {
+                    break;
+                }
+                resolved[pos] = dir;
+            }
+        }
+    }
+}
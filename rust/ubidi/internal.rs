@@ -1,529 +1,1544 @@
-/// If a RTL set is reversed, but surounded by (), [], {}, or <>, then mirror the sets.
-/// This also tracks other special characters that need to be mirrored. Some examples:
-/// '«', '»', '∕', '∟', '∠', '∡', '∢', '∤', '≃', '≅', '≌', '⊘', '⊦', '⊨',
-pub static MIRROR_CHAR: [[u16; 2]; 48] = [
-    [40, 41],
-    [41, 40],
-    [60, 62],
-    [62, 60],
-    [91, 93],
-    [93, 91],
-    [123, 125],
-    [125, 123],
-    [171, 187],
-    [187, 171],
-    [8725, 10741],
-    [8735, 11262],
-    [8736, 10659],
-    [8737, 10651],
-    [8738, 10656],
-    [8740, 10990],
-    [8771, 8909],
-    [8773, 8780],
-    [8780, 8773],
-    [8856, 10680],
-    [8870, 10974],
-    [8872, 10980],
-    [8873, 10979],
-    [8875, 10981],
-    [8888, 10204],
-    [8909, 8771],
-    [8946, 8954],
-    [8947, 8955],
-    [8948, 8956],
-    [8950, 8957],
-    [8951, 8958],
-    [8954, 8946],
-    [8955, 8947],
-    [8956, 8948],
-    [8957, 8950],
-    [8958, 8951],
-    [10204, 8888],
-    [10651, 8737],
-    [10656, 8738],
-    [10659, 8736],
-    [10680, 8856],
-    [10741, 8725],
-    [10974, 8870],
-    [10979, 8873],
-    [10980, 8872],
-    [10981, 8875],
-    [10990, 8740],
-    [11262, 8735],
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Bidi_Mirroring_Glyph pairs [character, mirrored glyph] generated from BidiMirroring.txt,
+/// sorted by character. If a RTL run is reversed, the characters in it are replaced by their
+/// mirrored glyph, e.g. '(' and ')', '«' and '»', '≤' and '≥' or '「' and '」'.
+// BEGIN GENERATED MIRROR_CHAR (cargo xtask gen-tables)
+pub static MIRROR_CHAR: [[u16; 2]; 428] = [
+    [0x0028, 0x0029],
+    [0x0029, 0x0028],
+    [0x003C, 0x003E],
+    [0x003E, 0x003C],
+    [0x005B, 0x005D],
+    [0x005D, 0x005B],
+    [0x007B, 0x007D],
+    [0x007D, 0x007B],
+    [0x00AB, 0x00BB],
+    [0x00BB, 0x00AB],
+    [0x0F3A, 0x0F3B],
+    [0x0F3B, 0x0F3A],
+    [0x0F3C, 0x0F3D],
+    [0x0F3D, 0x0F3C],
+    [0x169B, 0x169C],
+    [0x169C, 0x169B],
+    [0x2039, 0x203A],
+    [0x203A, 0x2039],
+    [0x2045, 0x2046],
+    [0x2046, 0x2045],
+    [0x207D, 0x207E],
+    [0x207E, 0x207D],
+    [0x208D, 0x208E],
+    [0x208E, 0x208D],
+    [0x2208, 0x220B],
+    [0x2209, 0x220C],
+    [0x220A, 0x220D],
+    [0x220B, 0x2208],
+    [0x220C, 0x2209],
+    [0x220D, 0x220A],
+    [0x2215, 0x29F5],
+    [0x221F, 0x2BFE],
+    [0x2220, 0x29A3],
+    [0x2221, 0x299B],
+    [0x2222, 0x29A0],
+    [0x2224, 0x2AEE],
+    [0x223C, 0x223D],
+    [0x223D, 0x223C],
+    [0x2243, 0x22CD],
+    [0x2245, 0x224C],
+    [0x224C, 0x2245],
+    [0x2252, 0x2253],
+    [0x2253, 0x2252],
+    [0x2254, 0x2255],
+    [0x2255, 0x2254],
+    [0x2264, 0x2265],
+    [0x2265, 0x2264],
+    [0x2266, 0x2267],
+    [0x2267, 0x2266],
+    [0x2268, 0x2269],
+    [0x2269, 0x2268],
+    [0x226A, 0x226B],
+    [0x226B, 0x226A],
+    [0x226E, 0x226F],
+    [0x226F, 0x226E],
+    [0x2270, 0x2271],
+    [0x2271, 0x2270],
+    [0x2272, 0x2273],
+    [0x2273, 0x2272],
+    [0x2274, 0x2275],
+    [0x2275, 0x2274],
+    [0x2276, 0x2277],
+    [0x2277, 0x2276],
+    [0x2278, 0x2279],
+    [0x2279, 0x2278],
+    [0x227A, 0x227B],
+    [0x227B, 0x227A],
+    [0x227C, 0x227D],
+    [0x227D, 0x227C],
+    [0x227E, 0x227F],
+    [0x227F, 0x227E],
+    [0x2280, 0x2281],
+    [0x2281, 0x2280],
+    [0x2282, 0x2283],
+    [0x2283, 0x2282],
+    [0x2284, 0x2285],
+    [0x2285, 0x2284],
+    [0x2286, 0x2287],
+    [0x2287, 0x2286],
+    [0x2288, 0x2289],
+    [0x2289, 0x2288],
+    [0x228A, 0x228B],
+    [0x228B, 0x228A],
+    [0x228F, 0x2290],
+    [0x2290, 0x228F],
+    [0x2291, 0x2292],
+    [0x2292, 0x2291],
+    [0x2298, 0x29B8],
+    [0x22A2, 0x22A3],
+    [0x22A3, 0x22A2],
+    [0x22A6, 0x2ADE],
+    [0x22A8, 0x2AE4],
+    [0x22A9, 0x2AE3],
+    [0x22AB, 0x2AE5],
+    [0x22B0, 0x22B1],
+    [0x22B1, 0x22B0],
+    [0x22B2, 0x22B3],
+    [0x22B3, 0x22B2],
+    [0x22B4, 0x22B5],
+    [0x22B5, 0x22B4],
+    [0x22B6, 0x22B7],
+    [0x22B7, 0x22B6],
+    [0x22B8, 0x27DC],
+    [0x22C9, 0x22CA],
+    [0x22CA, 0x22C9],
+    [0x22CB, 0x22CC],
+    [0x22CC, 0x22CB],
+    [0x22CD, 0x2243],
+    [0x22D0, 0x22D1],
+    [0x22D1, 0x22D0],
+    [0x22D6, 0x22D7],
+    [0x22D7, 0x22D6],
+    [0x22D8, 0x22D9],
+    [0x22D9, 0x22D8],
+    [0x22DA, 0x22DB],
+    [0x22DB, 0x22DA],
+    [0x22DC, 0x22DD],
+    [0x22DD, 0x22DC],
+    [0x22DE, 0x22DF],
+    [0x22DF, 0x22DE],
+    [0x22E0, 0x22E1],
+    [0x22E1, 0x22E0],
+    [0x22E2, 0x22E3],
+    [0x22E3, 0x22E2],
+    [0x22E4, 0x22E5],
+    [0x22E5, 0x22E4],
+    [0x22E6, 0x22E7],
+    [0x22E7, 0x22E6],
+    [0x22E8, 0x22E9],
+    [0x22E9, 0x22E8],
+    [0x22EA, 0x22EB],
+    [0x22EB, 0x22EA],
+    [0x22EC, 0x22ED],
+    [0x22ED, 0x22EC],
+    [0x22F0, 0x22F1],
+    [0x22F1, 0x22F0],
+    [0x22F2, 0x22FA],
+    [0x22F3, 0x22FB],
+    [0x22F4, 0x22FC],
+    [0x22F6, 0x22FD],
+    [0x22F7, 0x22FE],
+    [0x22FA, 0x22F2],
+    [0x22FB, 0x22F3],
+    [0x22FC, 0x22F4],
+    [0x22FD, 0x22F6],
+    [0x22FE, 0x22F7],
+    [0x2308, 0x2309],
+    [0x2309, 0x2308],
+    [0x230A, 0x230B],
+    [0x230B, 0x230A],
+    [0x2329, 0x232A],
+    [0x232A, 0x2329],
+    [0x2768, 0x2769],
+    [0x2769, 0x2768],
+    [0x276A, 0x276B],
+    [0x276B, 0x276A],
+    [0x276C, 0x276D],
+    [0x276D, 0x276C],
+    [0x276E, 0x276F],
+    [0x276F, 0x276E],
+    [0x2770, 0x2771],
+    [0x2771, 0x2770],
+    [0x2772, 0x2773],
+    [0x2773, 0x2772],
+    [0x2774, 0x2775],
+    [0x2775, 0x2774],
+    [0x27C3, 0x27C4],
+    [0x27C4, 0x27C3],
+    [0x27C5, 0x27C6],
+    [0x27C6, 0x27C5],
+    [0x27C8, 0x27C9],
+    [0x27C9, 0x27C8],
+    [0x27CB, 0x27CD],
+    [0x27CD, 0x27CB],
+    [0x27D5, 0x27D6],
+    [0x27D6, 0x27D5],
+    [0x27DC, 0x22B8],
+    [0x27DD, 0x27DE],
+    [0x27DE, 0x27DD],
+    [0x27E2, 0x27E3],
+    [0x27E3, 0x27E2],
+    [0x27E4, 0x27E5],
+    [0x27E5, 0x27E4],
+    [0x27E6, 0x27E7],
+    [0x27E7, 0x27E6],
+    [0x27E8, 0x27E9],
+    [0x27E9, 0x27E8],
+    [0x27EA, 0x27EB],
+    [0x27EB, 0x27EA],
+    [0x27EC, 0x27ED],
+    [0x27ED, 0x27EC],
+    [0x27EE, 0x27EF],
+    [0x27EF, 0x27EE],
+    [0x2983, 0x2984],
+    [0x2984, 0x2983],
+    [0x2985, 0x2986],
+    [0x2986, 0x2985],
+    [0x2987, 0x2988],
+    [0x2988, 0x2987],
+    [0x2989, 0x298A],
+    [0x298A, 0x2989],
+    [0x298B, 0x298C],
+    [0x298C, 0x298B],
+    [0x298D, 0x2990],
+    [0x298E, 0x298F],
+    [0x298F, 0x298E],
+    [0x2990, 0x298D],
+    [0x2991, 0x2992],
+    [0x2992, 0x2991],
+    [0x2993, 0x2994],
+    [0x2994, 0x2993],
+    [0x2995, 0x2996],
+    [0x2996, 0x2995],
+    [0x2997, 0x2998],
+    [0x2998, 0x2997],
+    [0x299B, 0x2221],
+    [0x29A0, 0x2222],
+    [0x29A3, 0x2220],
+    [0x29A4, 0x29A5],
+    [0x29A5, 0x29A4],
+    [0x29A8, 0x29A9],
+    [0x29A9, 0x29A8],
+    [0x29AA, 0x29AB],
+    [0x29AB, 0x29AA],
+    [0x29AC, 0x29AD],
+    [0x29AD, 0x29AC],
+    [0x29AE, 0x29AF],
+    [0x29AF, 0x29AE],
+    [0x29B8, 0x2298],
+    [0x29C0, 0x29C1],
+    [0x29C1, 0x29C0],
+    [0x29C4, 0x29C5],
+    [0x29C5, 0x29C4],
+    [0x29CF, 0x29D0],
+    [0x29D0, 0x29CF],
+    [0x29D1, 0x29D2],
+    [0x29D2, 0x29D1],
+    [0x29D4, 0x29D5],
+    [0x29D5, 0x29D4],
+    [0x29D8, 0x29D9],
+    [0x29D9, 0x29D8],
+    [0x29DA, 0x29DB],
+    [0x29DB, 0x29DA],
+    [0x29E8, 0x29E9],
+    [0x29E9, 0x29E8],
+    [0x29F5, 0x2215],
+    [0x29F8, 0x29F9],
+    [0x29F9, 0x29F8],
+    [0x29FC, 0x29FD],
+    [0x29FD, 0x29FC],
+    [0x2A2B, 0x2A2C],
+    [0x2A2C, 0x2A2B],
+    [0x2A2D, 0x2A2E],
+    [0x2A2E, 0x2A2D],
+    [0x2A34, 0x2A35],
+    [0x2A35, 0x2A34],
+    [0x2A3C, 0x2A3D],
+    [0x2A3D, 0x2A3C],
+    [0x2A64, 0x2A65],
+    [0x2A65, 0x2A64],
+    [0x2A79, 0x2A7A],
+    [0x2A7A, 0x2A79],
+    [0x2A7B, 0x2A7C],
+    [0x2A7C, 0x2A7B],
+    [0x2A7D, 0x2A7E],
+    [0x2A7E, 0x2A7D],
+    [0x2A7F, 0x2A80],
+    [0x2A80, 0x2A7F],
+    [0x2A81, 0x2A82],
+    [0x2A82, 0x2A81],
+    [0x2A83, 0x2A84],
+    [0x2A84, 0x2A83],
+    [0x2A85, 0x2A86],
+    [0x2A86, 0x2A85],
+    [0x2A87, 0x2A88],
+    [0x2A88, 0x2A87],
+    [0x2A89, 0x2A8A],
+    [0x2A8A, 0x2A89],
+    [0x2A8B, 0x2A8C],
+    [0x2A8C, 0x2A8B],
+    [0x2A8D, 0x2A8E],
+    [0x2A8E, 0x2A8D],
+    [0x2A8F, 0x2A90],
+    [0x2A90, 0x2A8F],
+    [0x2A91, 0x2A92],
+    [0x2A92, 0x2A91],
+    [0x2A93, 0x2A94],
+    [0x2A94, 0x2A93],
+    [0x2A95, 0x2A96],
+    [0x2A96, 0x2A95],
+    [0x2A97, 0x2A98],
+    [0x2A98, 0x2A97],
+    [0x2A99, 0x2A9A],
+    [0x2A9A, 0x2A99],
+    [0x2A9B, 0x2A9C],
+    [0x2A9C, 0x2A9B],
+    [0x2A9D, 0x2A9E],
+    [0x2A9E, 0x2A9D],
+    [0x2A9F, 0x2AA0],
+    [0x2AA0, 0x2A9F],
+    [0x2AA1, 0x2AA2],
+    [0x2AA2, 0x2AA1],
+    [0x2AA6, 0x2AA7],
+    [0x2AA7, 0x2AA6],
+    [0x2AA8, 0x2AA9],
+    [0x2AA9, 0x2AA8],
+    [0x2AAA, 0x2AAB],
+    [0x2AAB, 0x2AAA],
+    [0x2AAC, 0x2AAD],
+    [0x2AAD, 0x2AAC],
+    [0x2AAF, 0x2AB0],
+    [0x2AB0, 0x2AAF],
+    [0x2AB1, 0x2AB2],
+    [0x2AB2, 0x2AB1],
+    [0x2AB3, 0x2AB4],
+    [0x2AB4, 0x2AB3],
+    [0x2AB5, 0x2AB6],
+    [0x2AB6, 0x2AB5],
+    [0x2AB7, 0x2AB8],
+    [0x2AB8, 0x2AB7],
+    [0x2AB9, 0x2ABA],
+    [0x2ABA, 0x2AB9],
+    [0x2ABB, 0x2ABC],
+    [0x2ABC, 0x2ABB],
+    [0x2ABD, 0x2ABE],
+    [0x2ABE, 0x2ABD],
+    [0x2ABF, 0x2AC0],
+    [0x2AC0, 0x2ABF],
+    [0x2AC1, 0x2AC2],
+    [0x2AC2, 0x2AC1],
+    [0x2AC3, 0x2AC4],
+    [0x2AC4, 0x2AC3],
+    [0x2AC5, 0x2AC6],
+    [0x2AC6, 0x2AC5],
+    [0x2AC7, 0x2AC8],
+    [0x2AC8, 0x2AC7],
+    [0x2AC9, 0x2ACA],
+    [0x2ACA, 0x2AC9],
+    [0x2ACB, 0x2ACC],
+    [0x2ACC, 0x2ACB],
+    [0x2ACD, 0x2ACE],
+    [0x2ACE, 0x2ACD],
+    [0x2ACF, 0x2AD0],
+    [0x2AD0, 0x2ACF],
+    [0x2AD1, 0x2AD2],
+    [0x2AD2, 0x2AD1],
+    [0x2AD3, 0x2AD4],
+    [0x2AD4, 0x2AD3],
+    [0x2AD5, 0x2AD6],
+    [0x2AD6, 0x2AD5],
+    [0x2ADE, 0x22A6],
+    [0x2AE3, 0x22A9],
+    [0x2AE4, 0x22A8],
+    [0x2AE5, 0x22AB],
+    [0x2AEC, 0x2AED],
+    [0x2AED, 0x2AEC],
+    [0x2AEE, 0x2224],
+    [0x2AF7, 0x2AF8],
+    [0x2AF8, 0x2AF7],
+    [0x2AF9, 0x2AFA],
+    [0x2AFA, 0x2AF9],
+    [0x2BFE, 0x221F],
+    [0x2E02, 0x2E03],
+    [0x2E03, 0x2E02],
+    [0x2E04, 0x2E05],
+    [0x2E05, 0x2E04],
+    [0x2E09, 0x2E0A],
+    [0x2E0A, 0x2E09],
+    [0x2E0C, 0x2E0D],
+    [0x2E0D, 0x2E0C],
+    [0x2E1C, 0x2E1D],
+    [0x2E1D, 0x2E1C],
+    [0x2E20, 0x2E21],
+    [0x2E21, 0x2E20],
+    [0x2E22, 0x2E23],
+    [0x2E23, 0x2E22],
+    [0x2E24, 0x2E25],
+    [0x2E25, 0x2E24],
+    [0x2E26, 0x2E27],
+    [0x2E27, 0x2E26],
+    [0x2E28, 0x2E29],
+    [0x2E29, 0x2E28],
+    [0x2E55, 0x2E56],
+    [0x2E56, 0x2E55],
+    [0x2E57, 0x2E58],
+    [0x2E58, 0x2E57],
+    [0x2E59, 0x2E5A],
+    [0x2E5A, 0x2E59],
+    [0x2E5B, 0x2E5C],
+    [0x2E5C, 0x2E5B],
+    [0x3008, 0x3009],
+    [0x3009, 0x3008],
+    [0x300A, 0x300B],
+    [0x300B, 0x300A],
+    [0x300C, 0x300D],
+    [0x300D, 0x300C],
+    [0x300E, 0x300F],
+    [0x300F, 0x300E],
+    [0x3010, 0x3011],
+    [0x3011, 0x3010],
+    [0x3014, 0x3015],
+    [0x3015, 0x3014],
+    [0x3016, 0x3017],
+    [0x3017, 0x3016],
+    [0x3018, 0x3019],
+    [0x3019, 0x3018],
+    [0x301A, 0x301B],
+    [0x301B, 0x301A],
+    [0xFE59, 0xFE5A],
+    [0xFE5A, 0xFE59],
+    [0xFE5B, 0xFE5C],
+    [0xFE5C, 0xFE5B],
+    [0xFE5D, 0xFE5E],
+    [0xFE5E, 0xFE5D],
+    [0xFE64, 0xFE65],
+    [0xFE65, 0xFE64],
+    [0xFF08, 0xFF09],
+    [0xFF09, 0xFF08],
+    [0xFF1C, 0xFF1E],
+    [0xFF1E, 0xFF1C],
+    [0xFF3B, 0xFF3D],
+    [0xFF3D, 0xFF3B],
+    [0xFF5B, 0xFF5D],
+    [0xFF5D, 0xFF5B],
+    [0xFF5F, 0xFF60],
+    [0xFF60, 0xFF5F],
+    [0xFF62, 0xFF63],
+    [0xFF63, 0xFF62],
 ];
-// 192 bytes
+// 1712 bytes
+// END GENERATED MIRROR_CHAR
 
 /// Adjust string characters that have mirrored characters. Examples:
 /// '«', '»', '∕', '∟', '∠', '∡', '∢', '∤', '≃', '≅', '≌', '⊘', '⊦', '⊨',
 pub fn mirror_adjust_string(s: &mut [u16]) {
     for c in s {
-        for from_to in MIRROR_CHAR {
-            if *c == from_to[0] {
-                *c = from_to[1];
-                break;
-            }
-        }
+        *c = get_mirror(c);
     }
 }
 
-/// A RandALCat character is a character with unambiguously right-to-left directionality.
-pub static RAND_AL_CAT: [[u16; 2]; 45] = [
-    [0x05BE, 0x05BE],
-    [0x05C0, 0x05C0],
-    [0x05C3, 0x05C3],
-    [0x05C6, 0x05C6],
-    [0x05D0, 0x05EA],
-    [0x05F0, 0x05F4],
-    [0x0608, 0x0608],
-    [0x060B, 0x060B],
-    [0x060D, 0x060D],
-    [0x061B, 0x061B],
-    [0x061E, 0x064A],
-    [0x066D, 0x066F],
-    [0x0671, 0x06D5],
-    [0x06E5, 0x06E6],
-    [0x06EE, 0x06EF],
-    [0x06FA, 0x070D],
-    [0x0710, 0x0710],
-    [0x0712, 0x072F],
-    [0x074D, 0x07A5],
-    [0x07B1, 0x07B1],
-    [0x07C0, 0x07EA],
-    [0x07F4, 0x07F5],
-    [0x07FA, 0x07FA],
-    [0x0800, 0x0815],
-    [0x081A, 0x081A],
-    [0x0824, 0x0824],
-    [0x0828, 0x0828],
-    [0x0830, 0x083E],
-    [0x0840, 0x0858],
-    [0x085E, 0x085E],
-    [0x200F, 0x200F],
-    [0xFB1D, 0xFB1D],
-    [0xFB1F, 0xFB28],
-    [0xFB2A, 0xFB36],
-    [0xFB38, 0xFB3C],
-    [0xFB3E, 0xFB3E],
-    [0xFB40, 0xFB41],
-    [0xFB43, 0xFB44],
-    [0xFB46, 0xFBC1],
-    [0xFBD3, 0xFD3D],
-    [0xFD50, 0xFD8F],
-    [0xFD92, 0xFDC7],
-    [0xFDF0, 0xFDFC],
-    [0xFE70, 0xFE74],
-    [0xFE76, 0xFEFC],
+/// Get the mirrored character of `c`, or `c` itself if it has no mirror
+pub fn get_mirror(c: &u16) -> u16 {
+    match MIRROR_CHAR.binary_search_by(|from_to| from_to[0].cmp(c)) {
+        Ok(idx) => MIRROR_CHAR[idx][1],
+        Err(_) => *c,
+    }
+}
+
+/// Bidi_Paired_Bracket pairs [bracket, paired bracket, type] generated from BidiBrackets.txt,
+/// sorted by bracket, where type 0 is an opening and 1 a closing bracket (BD14, BD15).
+// BEGIN GENERATED BIDI_BRACKETS (cargo xtask gen-tables)
+pub static BIDI_BRACKETS: [[u16; 3]; 128] = [
+    [0x0028, 0x0029, 0],
+    [0x0029, 0x0028, 1],
+    [0x005B, 0x005D, 0],
+    [0x005D, 0x005B, 1],
+    [0x007B, 0x007D, 0],
+    [0x007D, 0x007B, 1],
+    [0x0F3A, 0x0F3B, 0],
+    [0x0F3B, 0x0F3A, 1],
+    [0x0F3C, 0x0F3D, 0],
+    [0x0F3D, 0x0F3C, 1],
+    [0x169B, 0x169C, 0],
+    [0x169C, 0x169B, 1],
+    [0x2045, 0x2046, 0],
+    [0x2046, 0x2045, 1],
+    [0x207D, 0x207E, 0],
+    [0x207E, 0x207D, 1],
+    [0x208D, 0x208E, 0],
+    [0x208E, 0x208D, 1],
+    [0x2308, 0x2309, 0],
+    [0x2309, 0x2308, 1],
+    [0x230A, 0x230B, 0],
+    [0x230B, 0x230A, 1],
+    [0x2329, 0x232A, 0],
+    [0x232A, 0x2329, 1],
+    [0x2768, 0x2769, 0],
+    [0x2769, 0x2768, 1],
+    [0x276A, 0x276B, 0],
+    [0x276B, 0x276A, 1],
+    [0x276C, 0x276D, 0],
+    [0x276D, 0x276C, 1],
+    [0x276E, 0x276F, 0],
+    [0x276F, 0x276E, 1],
+    [0x2770, 0x2771, 0],
+    [0x2771, 0x2770, 1],
+    [0x2772, 0x2773, 0],
+    [0x2773, 0x2772, 1],
+    [0x2774, 0x2775, 0],
+    [0x2775, 0x2774, 1],
+    [0x27C5, 0x27C6, 0],
+    [0x27C6, 0x27C5, 1],
+    [0x27E6, 0x27E7, 0],
+    [0x27E7, 0x27E6, 1],
+    [0x27E8, 0x27E9, 0],
+    [0x27E9, 0x27E8, 1],
+    [0x27EA, 0x27EB, 0],
+    [0x27EB, 0x27EA, 1],
+    [0x27EC, 0x27ED, 0],
+    [0x27ED, 0x27EC, 1],
+    [0x27EE, 0x27EF, 0],
+    [0x27EF, 0x27EE, 1],
+    [0x2983, 0x2984, 0],
+    [0x2984, 0x2983, 1],
+    [0x2985, 0x2986, 0],
+    [0x2986, 0x2985, 1],
+    [0x2987, 0x2988, 0],
+    [0x2988, 0x2987, 1],
+    [0x2989, 0x298A, 0],
+    [0x298A, 0x2989, 1],
+    [0x298B, 0x298C, 0],
+    [0x298C, 0x298B, 1],
+    [0x298D, 0x2990, 0],
+    [0x298E, 0x298F, 1],
+    [0x298F, 0x298E, 0],
+    [0x2990, 0x298D, 1],
+    [0x2991, 0x2992, 0],
+    [0x2992, 0x2991, 1],
+    [0x2993, 0x2994, 0],
+    [0x2994, 0x2993, 1],
+    [0x2995, 0x2996, 0],
+    [0x2996, 0x2995, 1],
+    [0x2997, 0x2998, 0],
+    [0x2998, 0x2997, 1],
+    [0x29D8, 0x29D9, 0],
+    [0x29D9, 0x29D8, 1],
+    [0x29DA, 0x29DB, 0],
+    [0x29DB, 0x29DA, 1],
+    [0x29FC, 0x29FD, 0],
+    [0x29FD, 0x29FC, 1],
+    [0x2E22, 0x2E23, 0],
+    [0x2E23, 0x2E22, 1],
+    [0x2E24, 0x2E25, 0],
+    [0x2E25, 0x2E24, 1],
+    [0x2E26, 0x2E27, 0],
+    [0x2E27, 0x2E26, 1],
+    [0x2E28, 0x2E29, 0],
+    [0x2E29, 0x2E28, 1],
+    [0x2E55, 0x2E56, 0],
+    [0x2E56, 0x2E55, 1],
+    [0x2E57, 0x2E58, 0],
+    [0x2E58, 0x2E57, 1],
+    [0x2E59, 0x2E5A, 0],
+    [0x2E5A, 0x2E59, 1],
+    [0x2E5B, 0x2E5C, 0],
+    [0x2E5C, 0x2E5B, 1],
+    [0x3008, 0x3009, 0],
+    [0x3009, 0x3008, 1],
+    [0x300A, 0x300B, 0],
+    [0x300B, 0x300A, 1],
+    [0x300C, 0x300D, 0],
+    [0x300D, 0x300C, 1],
+    [0x300E, 0x300F, 0],
+    [0x300F, 0x300E, 1],
+    [0x3010, 0x3011, 0],
+    [0x3011, 0x3010, 1],
+    [0x3014, 0x3015, 0],
+    [0x3015, 0x3014, 1],
+    [0x3016, 0x3017, 0],
+    [0x3017, 0x3016, 1],
+    [0x3018, 0x3019, 0],
+    [0x3019, 0x3018, 1],
+    [0x301A, 0x301B, 0],
+    [0x301B, 0x301A, 1],
+    [0xFE59, 0xFE5A, 0],
+    [0xFE5A, 0xFE59, 1],
+    [0xFE5B, 0xFE5C, 0],
+    [0xFE5C, 0xFE5B, 1],
+    [0xFE5D, 0xFE5E, 0],
+    [0xFE5E, 0xFE5D, 1],
+    [0xFF08, 0xFF09, 0],
+    [0xFF09, 0xFF08, 1],
+    [0xFF3B, 0xFF3D, 0],
+    [0xFF3D, 0xFF3B, 1],
+    [0xFF5B, 0xFF5D, 0],
+    [0xFF5D, 0xFF5B, 1],
+    [0xFF5F, 0xFF60, 0],
+    [0xFF60, 0xFF5F, 1],
+    [0xFF62, 0xFF63, 0],
+    [0xFF63, 0xFF62, 1],
 ];
-// 168 bytes
+// 768 bytes
+// END GENERATED BIDI_BRACKETS
 
-/// Check if a character is RandALCat (Right-to-left reading characters)
-pub fn is_rtl(c: &u16) -> bool {
-    for arr in RAND_AL_CAT {
-        if *c >= arr[0] && *c <= arr[1] {
-            return true;
-        }
+/// Paired bracket type (Bidi_Paired_Bracket_Type)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BracketType {
+    /// Opening paired bracket
+    Open,
+    /// Closing paired bracket
+    Close,
+}
+
+/// Map a bracket to its canonical equivalent, U+2329 and U+232A decompose to
+/// U+3008 and U+3009
+fn canonical_bracket(c: u16) -> u16 {
+    match c {
+        0x2329 => 0x3008,
+        0x232A => 0x3009,
+        _ => c,
     }
-    false
 }
 
-/// NEUTRAL characters are those with no inherent directionality, which can be
-/// treated as being part of any adjacent runs of text with other directionality.
-pub static NEUTRAL: [[u16; 2]; 137] = [
-    [0x0009, 0x000D],
-    [0x001C, 0x0022],
-    [0x0026, 0x002A],
-    [0x003B, 0x0040],
-    [0x005B, 0x0060],
-    [0x007B, 0x007E],
-    [0x0085, 0x0085],
-    [0x00A1, 0x00A1],
-    [0x00A6, 0x00A9],
-    [0x00AB, 0x00AC],
-    [0x00AE, 0x00AF],
-    [0x00B4, 0x00B4],
-    [0x00B6, 0x00B8],
-    [0x00BB, 0x00BF],
-    [0x00D7, 0x00D7],
-    [0x00F7, 0x00F7],
-    [0x02B9, 0x02BA],
-    [0x02C2, 0x02CF],
-    [0x02D2, 0x02DF],
-    [0x02E5, 0x02ED],
-    [0x02EF, 0x02FF],
-    [0x0374, 0x0375],
-    [0x037E, 0x037E],
-    [0x0384, 0x0385],
-    [0x0387, 0x0387],
-    [0x03F6, 0x03F6],
-    [0x058A, 0x058A],
-    [0x0606, 0x0607],
-    [0x060E, 0x060F],
-    [0x06DE, 0x06DE],
-    [0x06E9, 0x06E9],
-    [0x07F6, 0x07F9],
-    [0x0BF3, 0x0BF8],
-    [0x0BFA, 0x0BFA],
-    [0x0C78, 0x0C7E],
-    [0x0F3A, 0x0F3D],
-    [0x1390, 0x1399],
-    [0x1400, 0x1400],
-    [0x1680, 0x1680],
-    [0x169B, 0x169C],
-    [0x17F0, 0x17F9],
-    [0x1800, 0x180A],
-    [0x180E, 0x180E],
-    [0x1940, 0x1940],
-    [0x1944, 0x1945],
-    [0x19DE, 0x19FF],
-    [0x1FBD, 0x1FBD],
-    [0x1FBF, 0x1FC1],
-    [0x1FCD, 0x1FCF],
-    [0x1FDD, 0x1FDF],
-    [0x1FED, 0x1FEF],
-    [0x1FFD, 0x1FFE],
-    [0x2000, 0x200A],
-    [0x2010, 0x202E],
-    [0x2035, 0x2043],
-    [0x2045, 0x205F],
-    [0x207C, 0x207E],
-    [0x208C, 0x208E],
-    [0x2100, 0x2101],
-    [0x2103, 0x2106],
-    [0x2108, 0x2109],
-    [0x2114, 0x2114],
-    [0x2116, 0x2118],
-    [0x211E, 0x2123],
-    [0x2125, 0x2125],
-    [0x2127, 0x2127],
-    [0x2129, 0x2129],
-    [0x213A, 0x213B],
-    [0x2140, 0x2144],
-    [0x214A, 0x214D],
-    [0x2150, 0x215F],
-    [0x2189, 0x2189],
-    [0x2190, 0x2211],
-    [0x2214, 0x2335],
-    [0x237B, 0x2394],
-    [0x2396, 0x23F3],
-    [0x2400, 0x2426],
-    [0x2440, 0x244A],
-    [0x2460, 0x2487],
-    [0x24EA, 0x26AB],
-    [0x26AD, 0x26FF],
-    [0x2701, 0x27CA],
-    [0x27CC, 0x27CC],
-    [0x27CE, 0x27FF],
-    [0x2900, 0x2B4C],
-    [0x2B50, 0x2B59],
-    [0x2CE5, 0x2CEA],
-    [0x2CF9, 0x2CFF],
-    [0x2E00, 0x2E31],
-    [0x2E80, 0x2E99],
-    [0x2E9B, 0x2EF3],
-    [0x2F00, 0x2FD5],
-    [0x2FF0, 0x2FFB],
-    [0x3000, 0x3004],
-    [0x3008, 0x3020],
-    [0x3030, 0x3030],
-    [0x3036, 0x3037],
-    [0x303D, 0x303F],
-    [0x309B, 0x309C],
-    [0x30A0, 0x30A0],
-    [0x30FB, 0x30FB],
-    [0x31C0, 0x31E3],
-    [0x321D, 0x321E],
-    [0x3250, 0x325F],
-    [0x327C, 0x327E],
-    [0x32B1, 0x32BF],
-    [0x32CC, 0x32CF],
-    [0x3377, 0x337A],
-    [0x33DE, 0x33DF],
-    [0x33FF, 0x33FF],
-    [0x4DC0, 0x4DFF],
-    [0xA490, 0xA4C6],
-    [0xA60D, 0xA60F],
-    [0xA673, 0xA673],
-    [0xA67E, 0xA67F],
-    [0xA700, 0xA721],
-    [0xA788, 0xA788],
-    [0xA828, 0xA82B],
-    [0xA874, 0xA877],
-    [0xFD3E, 0xFD3F],
-    [0xFDFD, 0xFDFD],
-    [0xFE10, 0xFE19],
-    [0xFE30, 0xFE4F],
-    [0xFE51, 0xFE51],
-    [0xFE54, 0xFE54],
-    [0xFE56, 0xFE5E],
-    [0xFE60, 0xFE61],
-    [0xFE64, 0xFE66],
-    [0xFE68, 0xFE68],
-    [0xFE6B, 0xFE6B],
-    [0xFF01, 0xFF02],
-    [0xFF06, 0xFF0A],
-    [0xFF1B, 0xFF20],
-    [0xFF3B, 0xFF40],
-    [0xFF5B, 0xFF65],
-    [0xFFE2, 0xFFE4],
-    [0xFFE8, 0xFFEE],
+/// Get the bracket type of a character along with the canonical opening bracket of its pair,
+/// so that an opening and closing bracket match when their opening brackets are equal
+pub fn get_bracket(c: &u16) -> Option<(BracketType, u16)> {
+    let idx = BIDI_BRACKETS.binary_search_by(|bracket| bracket[0].cmp(c)).ok()?;
+    match BIDI_BRACKETS[idx] {
+        [open, _, 0] => Some((BracketType::Open, canonical_bracket(open))),
+        [_, open, _] => Some((BracketType::Close, canonical_bracket(open))),
+    }
+}
+
+/// Bidi_Class property values (https://www.unicode.org/reports/tr44/#Bidi_Class_Values)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BidiClass {
+    /// Left-to-right
+    L,
+    /// Right-to-left
+    R,
+    /// Right-to-left Arabic
+    AL,
+    /// European number
+    EN,
+    /// European number separator
+    ES,
+    /// European number terminator
+    ET,
+    /// Arabic number
+    AN,
+    /// Common number separator
+    CS,
+    /// Nonspacing mark
+    NSM,
+    /// Boundary neutral
+    BN,
+    /// Paragraph separator
+    B,
+    /// Segment separator
+    S,
+    /// Whitespace
+    WS,
+    /// Other neutrals
+    ON,
+    /// Left-to-right embedding
+    LRE,
+    /// Left-to-right override
+    LRO,
+    /// Right-to-left embedding
+    RLE,
+    /// Right-to-left override
+    RLO,
+    /// Pop directional format
+    PDF,
+    /// Left-to-right isolate
+    LRI,
+    /// Right-to-left isolate
+    RLI,
+    /// First strong isolate
+    FSI,
+    /// Pop directional isolate
+    PDI,
+}
+
+/// Bidi_Class of the Basic Multilingual Plane as [first, last, class] ranges, generated from
+/// UnicodeData.txt with the DerivedBidiClass.txt defaults for unassigned code points.
+/// Code points not covered by a range are `BidiClass::L`.
+// BEGIN GENERATED BIDI_CLASS (cargo xtask gen-tables)
+pub static BIDI_CLASS: [(u16, u16, BidiClass); 475] = [
+    (0x0000, 0x0008, BidiClass::BN),
+    (0x0009, 0x0009, BidiClass::S),
+    (0x000A, 0x000A, BidiClass::B),
+    (0x000B, 0x000B, BidiClass::S),
+    (0x000C, 0x000C, BidiClass::WS),
+    (0x000D, 0x000D, BidiClass::B),
+    (0x000E, 0x001B, BidiClass::BN),
+    (0x001C, 0x001E, BidiClass::B),
+    (0x001F, 0x001F, BidiClass::S),
+    (0x0020, 0x0020, BidiClass::WS),
+    (0x0021, 0x0022, BidiClass::ON),
+    (0x0023, 0x0025, BidiClass::ET),
+    (0x0026, 0x002A, BidiClass::ON),
+    (0x002B, 0x002B, BidiClass::ES),
+    (0x002C, 0x002C, BidiClass::CS),
+    (0x002D, 0x002D, BidiClass::ES),
+    (0x002E, 0x002F, BidiClass::CS),
+    (0x0030, 0x0039, BidiClass::EN),
+    (0x003A, 0x003A, BidiClass::CS),
+    (0x003B, 0x0040, BidiClass::ON),
+    (0x005B, 0x0060, BidiClass::ON),
+    (0x007B, 0x007E, BidiClass::ON),
+    (0x007F, 0x0084, BidiClass::BN),
+    (0x0085, 0x0085, BidiClass::B),
+    (0x0086, 0x009F, BidiClass::BN),
+    (0x00A0, 0x00A0, BidiClass::CS),
+    (0x00A1, 0x00A1, BidiClass::ON),
+    (0x00A2, 0x00A5, BidiClass::ET),
+    (0x00A6, 0x00A9, BidiClass::ON),
+    (0x00AB, 0x00AC, BidiClass::ON),
+    (0x00AD, 0x00AD, BidiClass::BN),
+    (0x00AE, 0x00AF, BidiClass::ON),
+    (0x00B0, 0x00B1, BidiClass::ET),
+    (0x00B2, 0x00B3, BidiClass::EN),
+    (0x00B4, 0x00B4, BidiClass::ON),
+    (0x00B6, 0x00B8, BidiClass::ON),
+    (0x00B9, 0x00B9, BidiClass::EN),
+    (0x00BB, 0x00BF, BidiClass::ON),
+    (0x00D7, 0x00D7, BidiClass::ON),
+    (0x00F7, 0x00F7, BidiClass::ON),
+    (0x02B9, 0x02BA, BidiClass::ON),
+    (0x02C2, 0x02CF, BidiClass::ON),
+    (0x02D2, 0x02DF, BidiClass::ON),
+    (0x02E5, 0x02ED, BidiClass::ON),
+    (0x02EF, 0x02FF, BidiClass::ON),
+    (0x0300, 0x036F, BidiClass::NSM),
+    (0x0374, 0x0375, BidiClass::ON),
+    (0x037E, 0x037E, BidiClass::ON),
+    (0x0384, 0x0385, BidiClass::ON),
+    (0x0387, 0x0387, BidiClass::ON),
+    (0x03F6, 0x03F6, BidiClass::ON),
+    (0x0483, 0x0489, BidiClass::NSM),
+    (0x058A, 0x058A, BidiClass::ON),
+    (0x058D, 0x058E, BidiClass::ON),
+    (0x058F, 0x058F, BidiClass::ET),
+    (0x0590, 0x0590, BidiClass::R),
+    (0x0591, 0x05BD, BidiClass::NSM),
+    (0x05BE, 0x05BE, BidiClass::R),
+    (0x05BF, 0x05BF, BidiClass::NSM),
+    (0x05C0, 0x05C0, BidiClass::R),
+    (0x05C1, 0x05C2, BidiClass::NSM),
+    (0x05C3, 0x05C3, BidiClass::R),
+    (0x05C4, 0x05C5, BidiClass::NSM),
+    (0x05C6, 0x05C6, BidiClass::R),
+    (0x05C7, 0x05C7, BidiClass::NSM),
+    (0x05C8, 0x05FF, BidiClass::R),
+    (0x0600, 0x0605, BidiClass::AN),
+    (0x0606, 0x0607, BidiClass::ON),
+    (0x0608, 0x0608, BidiClass::AL),
+    (0x0609, 0x060A, BidiClass::ET),
+    (0x060B, 0x060B, BidiClass::AL),
+    (0x060C, 0x060C, BidiClass::CS),
+    (0x060D, 0x060D, BidiClass::AL),
+    (0x060E, 0x060F, BidiClass::ON),
+    (0x0610, 0x061A, BidiClass::NSM),
+    (0x061B, 0x064A, BidiClass::AL),
+    (0x064B, 0x065F, BidiClass::NSM),
+    (0x0660, 0x0669, BidiClass::AN),
+    (0x066A, 0x066A, BidiClass::ET),
+    (0x066B, 0x066C, BidiClass::AN),
+    (0x066D, 0x066F, BidiClass::AL),
+    (0x0670, 0x0670, BidiClass::NSM),
+    (0x0671, 0x06D5, BidiClass::AL),
+    (0x06D6, 0x06DC, BidiClass::NSM),
+    (0x06DD, 0x06DD, BidiClass::AN),
+    (0x06DE, 0x06DE, BidiClass::ON),
+    (0x06DF, 0x06E4, BidiClass::NSM),
+    (0x06E5, 0x06E6, BidiClass::AL),
+    (0x06E7, 0x06E8, BidiClass::NSM),
+    (0x06E9, 0x06E9, BidiClass::ON),
+    (0x06EA, 0x06ED, BidiClass::NSM),
+    (0x06EE, 0x06EF, BidiClass::AL),
+    (0x06F0, 0x06F9, BidiClass::EN),
+    (0x06FA, 0x0710, BidiClass::AL),
+    (0x0711, 0x0711, BidiClass::NSM),
+    (0x0712, 0x072F, BidiClass::AL),
+    (0x0730, 0x074A, BidiClass::NSM),
+    (0x074B, 0x07A5, BidiClass::AL),
+    (0x07A6, 0x07B0, BidiClass::NSM),
+    (0x07B1, 0x07BF, BidiClass::AL),
+    (0x07C0, 0x07EA, BidiClass::R),
+    (0x07EB, 0x07F3, BidiClass::NSM),
+    (0x07F4, 0x07F5, BidiClass::R),
+    (0x07F6, 0x07F9, BidiClass::ON),
+    (0x07FA, 0x07FC, BidiClass::R),
+    (0x07FD, 0x07FD, BidiClass::NSM),
+    (0x07FE, 0x0815, BidiClass::R),
+    (0x0816, 0x0819, BidiClass::NSM),
+    (0x081A, 0x081A, BidiClass::R),
+    (0x081B, 0x0823, BidiClass::NSM),
+    (0x0824, 0x0824, BidiClass::R),
+    (0x0825, 0x0827, BidiClass::NSM),
+    (0x0828, 0x0828, BidiClass::R),
+    (0x0829, 0x082D, BidiClass::NSM),
+    (0x082E, 0x0858, BidiClass::R),
+    (0x0859, 0x085B, BidiClass::NSM),
+    (0x085C, 0x085F, BidiClass::R),
+    (0x0860, 0x088F, BidiClass::AL),
+    (0x0890, 0x0891, BidiClass::AN),
+    (0x0892, 0x0896, BidiClass::AL),
+    (0x0897, 0x089F, BidiClass::NSM),
+    (0x08A0, 0x08C9, BidiClass::AL),
+    (0x08CA, 0x08E1, BidiClass::NSM),
+    (0x08E2, 0x08E2, BidiClass::AN),
+    (0x08E3, 0x0902, BidiClass::NSM),
+    (0x093A, 0x093A, BidiClass::NSM),
+    (0x093C, 0x093C, BidiClass::NSM),
+    (0x0941, 0x0948, BidiClass::NSM),
+    (0x094D, 0x094D, BidiClass::NSM),
+    (0x0951, 0x0957, BidiClass::NSM),
+    (0x0962, 0x0963, BidiClass::NSM),
+    (0x0981, 0x0981, BidiClass::NSM),
+    (0x09BC, 0x09BC, BidiClass::NSM),
+    (0x09C1, 0x09C4, BidiClass::NSM),
+    (0x09CD, 0x09CD, BidiClass::NSM),
+    (0x09E2, 0x09E3, BidiClass::NSM),
+    (0x09F2, 0x09F3, BidiClass::ET),
+    (0x09FB, 0x09FB, BidiClass::ET),
+    (0x09FE, 0x09FE, BidiClass::NSM),
+    (0x0A01, 0x0A02, BidiClass::NSM),
+    (0x0A3C, 0x0A3C, BidiClass::NSM),
+    (0x0A41, 0x0A42, BidiClass::NSM),
+    (0x0A47, 0x0A48, BidiClass::NSM),
+    (0x0A4B, 0x0A4D, BidiClass::NSM),
+    (0x0A51, 0x0A51, BidiClass::NSM),
+    (0x0A70, 0x0A71, BidiClass::NSM),
+    (0x0A75, 0x0A75, BidiClass::NSM),
+    (0x0A81, 0x0A82, BidiClass::NSM),
+    (0x0ABC, 0x0ABC, BidiClass::NSM),
+    (0x0AC1, 0x0AC5, BidiClass::NSM),
+    (0x0AC7, 0x0AC8, BidiClass::NSM),
+    (0x0ACD, 0x0ACD, BidiClass::NSM),
+    (0x0AE2, 0x0AE3, BidiClass::NSM),
+    (0x0AF1, 0x0AF1, BidiClass::ET),
+    (0x0AFA, 0x0AFF, BidiClass::NSM),
+    (0x0B01, 0x0B01, BidiClass::NSM),
+    (0x0B3C, 0x0B3C, BidiClass::NSM),
+    (0x0B3F, 0x0B3F, BidiClass::NSM),
+    (0x0B41, 0x0B44, BidiClass::NSM),
+    (0x0B4D, 0x0B4D, BidiClass::NSM),
+    (0x0B55, 0x0B56, BidiClass::NSM),
+    (0x0B62, 0x0B63, BidiClass::NSM),
+    (0x0B82, 0x0B82, BidiClass::NSM),
+    (0x0BC0, 0x0BC0, BidiClass::NSM),
+    (0x0BCD, 0x0BCD, BidiClass::NSM),
+    (0x0BF3, 0x0BF8, BidiClass::ON),
+    (0x0BF9, 0x0BF9, BidiClass::ET),
+    (0x0BFA, 0x0BFA, BidiClass::ON),
+    (0x0C00, 0x0C00, BidiClass::NSM),
+    (0x0C04, 0x0C04, BidiClass::NSM),
+    (0x0C3C, 0x0C3C, BidiClass::NSM),
+    (0x0C3E, 0x0C40, BidiClass::NSM),
+    (0x0C46, 0x0C48, BidiClass::NSM),
+    (0x0C4A, 0x0C4D, BidiClass::NSM),
+    (0x0C55, 0x0C56, BidiClass::NSM),
+    (0x0C62, 0x0C63, BidiClass::NSM),
+    (0x0C78, 0x0C7E, BidiClass::ON),
+    (0x0C81, 0x0C81, BidiClass::NSM),
+    (0x0CBC, 0x0CBC, BidiClass::NSM),
+    (0x0CCC, 0x0CCD, BidiClass::NSM),
+    (0x0CE2, 0x0CE3, BidiClass::NSM),
+    (0x0D00, 0x0D01, BidiClass::NSM),
+    (0x0D3B, 0x0D3C, BidiClass::NSM),
+    (0x0D41, 0x0D44, BidiClass::NSM),
+    (0x0D4D, 0x0D4D, BidiClass::NSM),
+    (0x0D62, 0x0D63, BidiClass::NSM),
+    (0x0D81, 0x0D81, BidiClass::NSM),
+    (0x0DCA, 0x0DCA, BidiClass::NSM),
+    (0x0DD2, 0x0DD4, BidiClass::NSM),
+    (0x0DD6, 0x0DD6, BidiClass::NSM),
+    (0x0E31, 0x0E31, BidiClass::NSM),
+    (0x0E34, 0x0E3A, BidiClass::NSM),
+    (0x0E3F, 0x0E3F, BidiClass::ET),
+    (0x0E47, 0x0E4E, BidiClass::NSM),
+    (0x0EB1, 0x0EB1, BidiClass::NSM),
+    (0x0EB4, 0x0EBC, BidiClass::NSM),
+    (0x0EC8, 0x0ECE, BidiClass::NSM),
+    (0x0F18, 0x0F19, BidiClass::NSM),
+    (0x0F35, 0x0F35, BidiClass::NSM),
+    (0x0F37, 0x0F37, BidiClass::NSM),
+    (0x0F39, 0x0F39, BidiClass::NSM),
+    (0x0F3A, 0x0F3D, BidiClass::ON),
+    (0x0F71, 0x0F7E, BidiClass::NSM),
+    (0x0F80, 0x0F84, BidiClass::NSM),
+    (0x0F86, 0x0F87, BidiClass::NSM),
+    (0x0F8D, 0x0F97, BidiClass::NSM),
+    (0x0F99, 0x0FBC, BidiClass::NSM),
+    (0x0FC6, 0x0FC6, BidiClass::NSM),
+    (0x102D, 0x1030, BidiClass::NSM),
+    (0x1032, 0x1037, BidiClass::NSM),
+    (0x1039, 0x103A, BidiClass::NSM),
+    (0x103D, 0x103E, BidiClass::NSM),
+    (0x1058, 0x1059, BidiClass::NSM),
+    (0x105E, 0x1060, BidiClass::NSM),
+    (0x1071, 0x1074, BidiClass::NSM),
+    (0x1082, 0x1082, BidiClass::NSM),
+    (0x1085, 0x1086, BidiClass::NSM),
+    (0x108D, 0x108D, BidiClass::NSM),
+    (0x109D, 0x109D, BidiClass::NSM),
+    (0x135D, 0x135F, BidiClass::NSM),
+    (0x1390, 0x1399, BidiClass::ON),
+    (0x1400, 0x1400, BidiClass::ON),
+    (0x1680, 0x1680, BidiClass::WS),
+    (0x169B, 0x169C, BidiClass::ON),
+    (0x1712, 0x1714, BidiClass::NSM),
+    (0x1732, 0x1733, BidiClass::NSM),
+    (0x1752, 0x1753, BidiClass::NSM),
+    (0x1772, 0x1773, BidiClass::NSM),
+    (0x17B4, 0x17B5, BidiClass::NSM),
+    (0x17B7, 0x17BD, BidiClass::NSM),
+    (0x17C6, 0x17C6, BidiClass::NSM),
+    (0x17C9, 0x17D3, BidiClass::NSM),
+    (0x17DB, 0x17DB, BidiClass::ET),
+    (0x17DD, 0x17DD, BidiClass::NSM),
+    (0x17F0, 0x17F9, BidiClass::ON),
+    (0x1800, 0x180A, BidiClass::ON),
+    (0x180B, 0x180D, BidiClass::NSM),
+    (0x180E, 0x180E, BidiClass::BN),
+    (0x180F, 0x180F, BidiClass::NSM),
+    (0x1885, 0x1886, BidiClass::NSM),
+    (0x18A9, 0x18A9, BidiClass::NSM),
+    (0x1920, 0x1922, BidiClass::NSM),
+    (0x1927, 0x1928, BidiClass::NSM),
+    (0x1932, 0x1932, BidiClass::NSM),
+    (0x1939, 0x193B, BidiClass::NSM),
+    (0x1940, 0x1940, BidiClass::ON),
+    (0x1944, 0x1945, BidiClass::ON),
+    (0x19DE, 0x19FF, BidiClass::ON),
+    (0x1A17, 0x1A18, BidiClass::NSM),
+    (0x1A1B, 0x1A1B, BidiClass::NSM),
+    (0x1A56, 0x1A56, BidiClass::NSM),
+    (0x1A58, 0x1A5E, BidiClass::NSM),
+    (0x1A60, 0x1A60, BidiClass::NSM),
+    (0x1A62, 0x1A62, BidiClass::NSM),
+    (0x1A65, 0x1A6C, BidiClass::NSM),
+    (0x1A73, 0x1A7C, BidiClass::NSM),
+    (0x1A7F, 0x1A7F, BidiClass::NSM),
+    (0x1AB0, 0x1ACE, BidiClass::NSM),
+    (0x1B00, 0x1B03, BidiClass::NSM),
+    (0x1B34, 0x1B34, BidiClass::NSM),
+    (0x1B36, 0x1B3A, BidiClass::NSM),
+    (0x1B3C, 0x1B3C, BidiClass::NSM),
+    (0x1B42, 0x1B42, BidiClass::NSM),
+    (0x1B6B, 0x1B73, BidiClass::NSM),
+    (0x1B80, 0x1B81, BidiClass::NSM),
+    (0x1BA2, 0x1BA5, BidiClass::NSM),
+    (0x1BA8, 0x1BA9, BidiClass::NSM),
+    (0x1BAB, 0x1BAD, BidiClass::NSM),
+    (0x1BE6, 0x1BE6, BidiClass::NSM),
+    (0x1BE8, 0x1BE9, BidiClass::NSM),
+    (0x1BED, 0x1BED, BidiClass::NSM),
+    (0x1BEF, 0x1BF1, BidiClass::NSM),
+    (0x1C2C, 0x1C33, BidiClass::NSM),
+    (0x1C36, 0x1C37, BidiClass::NSM),
+    (0x1CD0, 0x1CD2, BidiClass::NSM),
+    (0x1CD4, 0x1CE0, BidiClass::NSM),
+    (0x1CE2, 0x1CE8, BidiClass::NSM),
+    (0x1CED, 0x1CED, BidiClass::NSM),
+    (0x1CF4, 0x1CF4, BidiClass::NSM),
+    (0x1CF8, 0x1CF9, BidiClass::NSM),
+    (0x1DC0, 0x1DFF, BidiClass::NSM),
+    (0x1FBD, 0x1FBD, BidiClass::ON),
+    (0x1FBF, 0x1FC1, BidiClass::ON),
+    (0x1FCD, 0x1FCF, BidiClass::ON),
+    (0x1FDD, 0x1FDF, BidiClass::ON),
+    (0x1FED, 0x1FEF, BidiClass::ON),
+    (0x1FFD, 0x1FFE, BidiClass::ON),
+    (0x2000, 0x200A, BidiClass::WS),
+    (0x200B, 0x200D, BidiClass::BN),
+    (0x200F, 0x200F, BidiClass::R),
+    (0x2010, 0x2027, BidiClass::ON),
+    (0x2028, 0x2028, BidiClass::WS),
+    (0x2029, 0x2029, BidiClass::B),
+    (0x202A, 0x202A, BidiClass::LRE),
+    (0x202B, 0x202B, BidiClass::RLE),
+    (0x202C, 0x202C, BidiClass::PDF),
+    (0x202D, 0x202D, BidiClass::LRO),
+    (0x202E, 0x202E, BidiClass::RLO),
+    (0x202F, 0x202F, BidiClass::CS),
+    (0x2030, 0x2034, BidiClass::ET),
+    (0x2035, 0x2043, BidiClass::ON),
+    (0x2044, 0x2044, BidiClass::CS),
+    (0x2045, 0x205E, BidiClass::ON),
+    (0x205F, 0x205F, BidiClass::WS),
+    (0x2060, 0x2065, BidiClass::BN),
+    (0x2066, 0x2066, BidiClass::LRI),
+    (0x2067, 0x2067, BidiClass::RLI),
+    (0x2068, 0x2068, BidiClass::FSI),
+    (0x2069, 0x2069, BidiClass::PDI),
+    (0x206A, 0x206F, BidiClass::BN),
+    (0x2070, 0x2070, BidiClass::EN),
+    (0x2074, 0x2079, BidiClass::EN),
+    (0x207A, 0x207B, BidiClass::ES),
+    (0x207C, 0x207E, BidiClass::ON),
+    (0x2080, 0x2089, BidiClass::EN),
+    (0x208A, 0x208B, BidiClass::ES),
+    (0x208C, 0x208E, BidiClass::ON),
+    (0x20A0, 0x20CF, BidiClass::ET),
+    (0x20D0, 0x20F0, BidiClass::NSM),
+    (0x2100, 0x2101, BidiClass::ON),
+    (0x2103, 0x2106, BidiClass::ON),
+    (0x2108, 0x2109, BidiClass::ON),
+    (0x2114, 0x2114, BidiClass::ON),
+    (0x2116, 0x2118, BidiClass::ON),
+    (0x211E, 0x2123, BidiClass::ON),
+    (0x2125, 0x2125, BidiClass::ON),
+    (0x2127, 0x2127, BidiClass::ON),
+    (0x2129, 0x2129, BidiClass::ON),
+    (0x212E, 0x212E, BidiClass::ET),
+    (0x213A, 0x213B, BidiClass::ON),
+    (0x2140, 0x2144, BidiClass::ON),
+    (0x214A, 0x214D, BidiClass::ON),
+    (0x2150, 0x215F, BidiClass::ON),
+    (0x2189, 0x218B, BidiClass::ON),
+    (0x2190, 0x2211, BidiClass::ON),
+    (0x2212, 0x2212, BidiClass::ES),
+    (0x2213, 0x2213, BidiClass::ET),
+    (0x2214, 0x2335, BidiClass::ON),
+    (0x237B, 0x2394, BidiClass::ON),
+    (0x2396, 0x2429, BidiClass::ON),
+    (0x2440, 0x244A, BidiClass::ON),
+    (0x2460, 0x2487, BidiClass::ON),
+    (0x2488, 0x249B, BidiClass::EN),
+    (0x24EA, 0x26AB, BidiClass::ON),
+    (0x26AD, 0x27FF, BidiClass::ON),
+    (0x2900, 0x2B73, BidiClass::ON),
+    (0x2B76, 0x2B95, BidiClass::ON),
+    (0x2B97, 0x2BFF, BidiClass::ON),
+    (0x2CE5, 0x2CEA, BidiClass::ON),
+    (0x2CEF, 0x2CF1, BidiClass::NSM),
+    (0x2CF9, 0x2CFF, BidiClass::ON),
+    (0x2D7F, 0x2D7F, BidiClass::NSM),
+    (0x2DE0, 0x2DFF, BidiClass::NSM),
+    (0x2E00, 0x2E5D, BidiClass::ON),
+    (0x2E80, 0x2E99, BidiClass::ON),
+    (0x2E9B, 0x2EF3, BidiClass::ON),
+    (0x2F00, 0x2FD5, BidiClass::ON),
+    (0x2FF0, 0x2FFF, BidiClass::ON),
+    (0x3000, 0x3000, BidiClass::WS),
+    (0x3001, 0x3004, BidiClass::ON),
+    (0x3008, 0x3020, BidiClass::ON),
+    (0x302A, 0x302D, BidiClass::NSM),
+    (0x3030, 0x3030, BidiClass::ON),
+    (0x3036, 0x3037, BidiClass::ON),
+    (0x303D, 0x303F, BidiClass::ON),
+    (0x3099, 0x309A, BidiClass::NSM),
+    (0x309B, 0x309C, BidiClass::ON),
+    (0x30A0, 0x30A0, BidiClass::ON),
+    (0x30FB, 0x30FB, BidiClass::ON),
+    (0x31C0, 0x31E5, BidiClass::ON),
+    (0x31EF, 0x31EF, BidiClass::ON),
+    (0x321D, 0x321E, BidiClass::ON),
+    (0x3250, 0x325F, BidiClass::ON),
+    (0x327C, 0x327E, BidiClass::ON),
+    (0x32B1, 0x32BF, BidiClass::ON),
+    (0x32CC, 0x32CF, BidiClass::ON),
+    (0x3377, 0x337A, BidiClass::ON),
+    (0x33DE, 0x33DF, BidiClass::ON),
+    (0x33FF, 0x33FF, BidiClass::ON),
+    (0x4DC0, 0x4DFF, BidiClass::ON),
+    (0xA490, 0xA4C6, BidiClass::ON),
+    (0xA60D, 0xA60F, BidiClass::ON),
+    (0xA66F, 0xA672, BidiClass::NSM),
+    (0xA673, 0xA673, BidiClass::ON),
+    (0xA674, 0xA67D, BidiClass::NSM),
+    (0xA67E, 0xA67F, BidiClass::ON),
+    (0xA69E, 0xA69F, BidiClass::NSM),
+    (0xA6F0, 0xA6F1, BidiClass::NSM),
+    (0xA700, 0xA721, BidiClass::ON),
+    (0xA788, 0xA788, BidiClass::ON),
+    (0xA802, 0xA802, BidiClass::NSM),
+    (0xA806, 0xA806, BidiClass::NSM),
+    (0xA80B, 0xA80B, BidiClass::NSM),
+    (0xA825, 0xA826, BidiClass::NSM),
+    (0xA828, 0xA82B, BidiClass::ON),
+    (0xA82C, 0xA82C, BidiClass::NSM),
+    (0xA838, 0xA839, BidiClass::ET),
+    (0xA874, 0xA877, BidiClass::ON),
+    (0xA8C4, 0xA8C5, BidiClass::NSM),
+    (0xA8E0, 0xA8F1, BidiClass::NSM),
+    (0xA8FF, 0xA8FF, BidiClass::NSM),
+    (0xA926, 0xA92D, BidiClass::NSM),
+    (0xA947, 0xA951, BidiClass::NSM),
+    (0xA980, 0xA982, BidiClass::NSM),
+    (0xA9B3, 0xA9B3, BidiClass::NSM),
+    (0xA9B6, 0xA9B9, BidiClass::NSM),
+    (0xA9BC, 0xA9BD, BidiClass::NSM),
+    (0xA9E5, 0xA9E5, BidiClass::NSM),
+    (0xAA29, 0xAA2E, BidiClass::NSM),
+    (0xAA31, 0xAA32, BidiClass::NSM),
+    (0xAA35, 0xAA36, BidiClass::NSM),
+    (0xAA43, 0xAA43, BidiClass::NSM),
+    (0xAA4C, 0xAA4C, BidiClass::NSM),
+    (0xAA7C, 0xAA7C, BidiClass::NSM),
+    (0xAAB0, 0xAAB0, BidiClass::NSM),
+    (0xAAB2, 0xAAB4, BidiClass::NSM),
+    (0xAAB7, 0xAAB8, BidiClass::NSM),
+    (0xAABE, 0xAABF, BidiClass::NSM),
+    (0xAAC1, 0xAAC1, BidiClass::NSM),
+    (0xAAEC, 0xAAED, BidiClass::NSM),
+    (0xAAF6, 0xAAF6, BidiClass::NSM),
+    (0xAB6A, 0xAB6B, BidiClass::ON),
+    (0xABE5, 0xABE5, BidiClass::NSM),
+    (0xABE8, 0xABE8, BidiClass::NSM),
+    (0xABED, 0xABED, BidiClass::NSM),
+    (0xFB1D, 0xFB1D, BidiClass::R),
+    (0xFB1E, 0xFB1E, BidiClass::NSM),
+    (0xFB1F, 0xFB28, BidiClass::R),
+    (0xFB29, 0xFB29, BidiClass::ES),
+    (0xFB2A, 0xFB4F, BidiClass::R),
+    (0xFB50, 0xFD3D, BidiClass::AL),
+    (0xFD3E, 0xFD4F, BidiClass::ON),
+    (0xFD50, 0xFDCE, BidiClass::AL),
+    (0xFDCF, 0xFDCF, BidiClass::ON),
+    (0xFDD0, 0xFDEF, BidiClass::BN),
+    (0xFDF0, 0xFDFC, BidiClass::AL),
+    (0xFDFD, 0xFDFF, BidiClass::ON),
+    (0xFE00, 0xFE0F, BidiClass::NSM),
+    (0xFE10, 0xFE19, BidiClass::ON),
+    (0xFE20, 0xFE2F, BidiClass::NSM),
+    (0xFE30, 0xFE4F, BidiClass::ON),
+    (0xFE50, 0xFE50, BidiClass::CS),
+    (0xFE51, 0xFE51, BidiClass::ON),
+    (0xFE52, 0xFE52, BidiClass::CS),
+    (0xFE54, 0xFE54, BidiClass::ON),
+    (0xFE55, 0xFE55, BidiClass::CS),
+    (0xFE56, 0xFE5E, BidiClass::ON),
+    (0xFE5F, 0xFE5F, BidiClass::ET),
+    (0xFE60, 0xFE61, BidiClass::ON),
+    (0xFE62, 0xFE63, BidiClass::ES),
+    (0xFE64, 0xFE66, BidiClass::ON),
+    (0xFE68, 0xFE68, BidiClass::ON),
+    (0xFE69, 0xFE6A, BidiClass::ET),
+    (0xFE6B, 0xFE6B, BidiClass::ON),
+    (0xFE70, 0xFEFE, BidiClass::AL),
+    (0xFEFF, 0xFEFF, BidiClass::BN),
+    (0xFF01, 0xFF02, BidiClass::ON),
+    (0xFF03, 0xFF05, BidiClass::ET),
+    (0xFF06, 0xFF0A, BidiClass::ON),
+    (0xFF0B, 0xFF0B, BidiClass::ES),
+    (0xFF0C, 0xFF0C, BidiClass::CS),
+    (0xFF0D, 0xFF0D, BidiClass::ES),
+    (0xFF0E, 0xFF0F, BidiClass::CS),
+    (0xFF10, 0xFF19, BidiClass::EN),
+    (0xFF1A, 0xFF1A, BidiClass::CS),
+    (0xFF1B, 0xFF20, BidiClass::ON),
+    (0xFF3B, 0xFF40, BidiClass::ON),
+    (0xFF5B, 0xFF65, BidiClass::ON),
+    (0xFFE0, 0xFFE1, BidiClass::ET),
+    (0xFFE2, 0xFFE4, BidiClass::ON),
+    (0xFFE5, 0xFFE6, BidiClass::ET),
+    (0xFFE8, 0xFFEE, BidiClass::ON),
+    (0xFFF0, 0xFFF8, BidiClass::BN),
+    (0xFFF9, 0xFFFD, BidiClass::ON),
+    (0xFFFE, 0xFFFF, BidiClass::BN),
 ];
-// 536 bytes
+// 2850 bytes
+// END GENERATED BIDI_CLASS
 
-/// Check if a character is NeutralCat (Neutral characters)
-pub fn is_neutral(c: &u16) -> bool {
-    for arr in NEUTRAL {
-        if *c >= arr[0] && *c <= arr[1] {
-            return true;
+/// Bidi_Class of the supplementary planes (U+10000–U+10FFFF), like `BIDI_CLASS`
+// BEGIN GENERATED BIDI_CLASS_SUPPLEMENTARY (cargo xtask gen-tables)
+pub static BIDI_CLASS_SUPPLEMENTARY: [(u32, u32, BidiClass); 270] = [
+    (0x10101, 0x10101, BidiClass::ON),
+    (0x10140, 0x1018C, BidiClass::ON),
+    (0x10190, 0x1019C, BidiClass::ON),
+    (0x101A0, 0x101A0, BidiClass::ON),
+    (0x101FD, 0x101FD, BidiClass::NSM),
+    (0x102E0, 0x102E0, BidiClass::NSM),
+    (0x102E1, 0x102FB, BidiClass::EN),
+    (0x10376, 0x1037A, BidiClass::NSM),
+    (0x10800, 0x1091E, BidiClass::R),
+    (0x1091F, 0x1091F, BidiClass::ON),
+    (0x10920, 0x10A00, BidiClass::R),
+    (0x10A01, 0x10A03, BidiClass::NSM),
+    (0x10A04, 0x10A04, BidiClass::R),
+    (0x10A05, 0x10A06, BidiClass::NSM),
+    (0x10A07, 0x10A0B, BidiClass::R),
+    (0x10A0C, 0x10A0F, BidiClass::NSM),
+    (0x10A10, 0x10A37, BidiClass::R),
+    (0x10A38, 0x10A3A, BidiClass::NSM),
+    (0x10A3B, 0x10A3E, BidiClass::R),
+    (0x10A3F, 0x10A3F, BidiClass::NSM),
+    (0x10A40, 0x10AE4, BidiClass::R),
+    (0x10AE5, 0x10AE6, BidiClass::NSM),
+    (0x10AE7, 0x10B38, BidiClass::R),
+    (0x10B39, 0x10B3F, BidiClass::ON),
+    (0x10B40, 0x10CFF, BidiClass::R),
+    (0x10D00, 0x10D23, BidiClass::AL),
+    (0x10D24, 0x10D27, BidiClass::NSM),
+    (0x10D28, 0x10D2F, BidiClass::AL),
+    (0x10D30, 0x10D39, BidiClass::AN),
+    (0x10D3A, 0x10D3F, BidiClass::AL),
+    (0x10D40, 0x10D49, BidiClass::AN),
+    (0x10D4A, 0x10D68, BidiClass::R),
+    (0x10D69, 0x10D6D, BidiClass::NSM),
+    (0x10D6E, 0x10D6E, BidiClass::ON),
+    (0x10D6F, 0x10E5F, BidiClass::R),
+    (0x10E60, 0x10E7E, BidiClass::AN),
+    (0x10E7F, 0x10EAA, BidiClass::R),
+    (0x10EAB, 0x10EAC, BidiClass::NSM),
+    (0x10EAD, 0x10EBF, BidiClass::R),
+    (0x10EC0, 0x10EFB, BidiClass::AL),
+    (0x10EFC, 0x10EFF, BidiClass::NSM),
+    (0x10F00, 0x10F2F, BidiClass::R),
+    (0x10F30, 0x10F45, BidiClass::AL),
+    (0x10F46, 0x10F50, BidiClass::NSM),
+    (0x10F51, 0x10F6F, BidiClass::AL),
+    (0x10F70, 0x10F81, BidiClass::R),
+    (0x10F82, 0x10F85, BidiClass::NSM),
+    (0x10F86, 0x10FFF, BidiClass::R),
+    (0x11001, 0x11001, BidiClass::NSM),
+    (0x11038, 0x11046, BidiClass::NSM),
+    (0x11052, 0x11065, BidiClass::ON),
+    (0x11070, 0x11070, BidiClass::NSM),
+    (0x11073, 0x11074, BidiClass::NSM),
+    (0x1107F, 0x11081, BidiClass::NSM),
+    (0x110B3, 0x110B6, BidiClass::NSM),
+    (0x110B9, 0x110BA, BidiClass::NSM),
+    (0x110C2, 0x110C2, BidiClass::NSM),
+    (0x11100, 0x11102, BidiClass::NSM),
+    (0x11127, 0x1112B, BidiClass::NSM),
+    (0x1112D, 0x11134, BidiClass::NSM),
+    (0x11173, 0x11173, BidiClass::NSM),
+    (0x11180, 0x11181, BidiClass::NSM),
+    (0x111B6, 0x111BE, BidiClass::NSM),
+    (0x111C9, 0x111CC, BidiClass::NSM),
+    (0x111CF, 0x111CF, BidiClass::NSM),
+    (0x1122F, 0x11231, BidiClass::NSM),
+    (0x11234, 0x11234, BidiClass::NSM),
+    (0x11236, 0x11237, BidiClass::NSM),
+    (0x1123E, 0x1123E, BidiClass::NSM),
+    (0x11241, 0x11241, BidiClass::NSM),
+    (0x112DF, 0x112DF, BidiClass::NSM),
+    (0x112E3, 0x112EA, BidiClass::NSM),
+    (0x11300, 0x11301, BidiClass::NSM),
+    (0x1133B, 0x1133C, BidiClass::NSM),
+    (0x11340, 0x11340, BidiClass::NSM),
+    (0x11366, 0x1136C, BidiClass::NSM),
+    (0x11370, 0x11374, BidiClass::NSM),
+    (0x113BB, 0x113C0, BidiClass::NSM),
+    (0x113CE, 0x113CE, BidiClass::NSM),
+    (0x113D0, 0x113D0, BidiClass::NSM),
+    (0x113D2, 0x113D2, BidiClass::NSM),
+    (0x113E1, 0x113E2, BidiClass::NSM),
+    (0x11438, 0x1143F, BidiClass::NSM),
+    (0x11442, 0x11444, BidiClass::NSM),
+    (0x11446, 0x11446, BidiClass::NSM),
+    (0x1145E, 0x1145E, BidiClass::NSM),
+    (0x114B3, 0x114B8, BidiClass::NSM),
+    (0x114BA, 0x114BA, BidiClass::NSM),
+    (0x114BF, 0x114C0, BidiClass::NSM),
+    (0x114C2, 0x114C3, BidiClass::NSM),
+    (0x115B2, 0x115B5, BidiClass::NSM),
+    (0x115BC, 0x115BD, BidiClass::NSM),
+    (0x115BF, 0x115C0, BidiClass::NSM),
+    (0x115DC, 0x115DD, BidiClass::NSM),
+    (0x11633, 0x1163A, BidiClass::NSM),
+    (0x1163D, 0x1163D, BidiClass::NSM),
+    (0x1163F, 0x11640, BidiClass::NSM),
+    (0x11660, 0x1166C, BidiClass::ON),
+    (0x116AB, 0x116AB, BidiClass::NSM),
+    (0x116AD, 0x116AD, BidiClass::NSM),
+    (0x116B0, 0x116B5, BidiClass::NSM),
+    (0x116B7, 0x116B7, BidiClass::NSM),
+    (0x1171D, 0x1171D, BidiClass::NSM),
+    (0x1171F, 0x1171F, BidiClass::NSM),
+    (0x11722, 0x11725, BidiClass::NSM),
+    (0x11727, 0x1172B, BidiClass::NSM),
+    (0x1182F, 0x11837, BidiClass::NSM),
+    (0x11839, 0x1183A, BidiClass::NSM),
+    (0x1193B, 0x1193C, BidiClass::NSM),
+    (0x1193E, 0x1193E, BidiClass::NSM),
+    (0x11943, 0x11943, BidiClass::NSM),
+    (0x119D4, 0x119D7, BidiClass::NSM),
+    (0x119DA, 0x119DB, BidiClass::NSM),
+    (0x119E0, 0x119E0, BidiClass::NSM),
+    (0x11A01, 0x11A06, BidiClass::NSM),
+    (0x11A09, 0x11A0A, BidiClass::NSM),
+    (0x11A33, 0x11A38, BidiClass::NSM),
+    (0x11A3B, 0x11A3E, BidiClass::NSM),
+    (0x11A47, 0x11A47, BidiClass::NSM),
+    (0x11A51, 0x11A56, BidiClass::NSM),
+    (0x11A59, 0x11A5B, BidiClass::NSM),
+    (0x11A8A, 0x11A96, BidiClass::NSM),
+    (0x11A98, 0x11A99, BidiClass::NSM),
+    (0x11C30, 0x11C36, BidiClass::NSM),
+    (0x11C38, 0x11C3D, BidiClass::NSM),
+    (0x11C92, 0x11CA7, BidiClass::NSM),
+    (0x11CAA, 0x11CB0, BidiClass::NSM),
+    (0x11CB2, 0x11CB3, BidiClass::NSM),
+    (0x11CB5, 0x11CB6, BidiClass::NSM),
+    (0x11D31, 0x11D36, BidiClass::NSM),
+    (0x11D3A, 0x11D3A, BidiClass::NSM),
+    (0x11D3C, 0x11D3D, BidiClass::NSM),
+    (0x11D3F, 0x11D45, BidiClass::NSM),
+    (0x11D47, 0x11D47, BidiClass::NSM),
+    (0x11D90, 0x11D91, BidiClass::NSM),
+    (0x11D95, 0x11D95, BidiClass::NSM),
+    (0x11D97, 0x11D97, BidiClass::NSM),
+    (0x11EF3, 0x11EF4, BidiClass::NSM),
+    (0x11F00, 0x11F01, BidiClass::NSM),
+    (0x11F36, 0x11F3A, BidiClass::NSM),
+    (0x11F40, 0x11F40, BidiClass::NSM),
+    (0x11F42, 0x11F42, BidiClass::NSM),
+    (0x11F5A, 0x11F5A, BidiClass::NSM),
+    (0x11FD5, 0x11FDC, BidiClass::ON),
+    (0x11FDD, 0x11FE0, BidiClass::ET),
+    (0x11FE1, 0x11FF1, BidiClass::ON),
+    (0x13440, 0x13440, BidiClass::NSM),
+    (0x13447, 0x13455, BidiClass::NSM),
+    (0x1611E, 0x16129, BidiClass::NSM),
+    (0x1612D, 0x1612F, BidiClass::NSM),
+    (0x16AF0, 0x16AF4, BidiClass::NSM),
+    (0x16B30, 0x16B36, BidiClass::NSM),
+    (0x16F4F, 0x16F4F, BidiClass::NSM),
+    (0x16F8F, 0x16F92, BidiClass::NSM),
+    (0x16FE2, 0x16FE2, BidiClass::ON),
+    (0x16FE4, 0x16FE4, BidiClass::NSM),
+    (0x1BC9D, 0x1BC9E, BidiClass::NSM),
+    (0x1BCA0, 0x1BCA3, BidiClass::BN),
+    (0x1CC00, 0x1CCD5, BidiClass::ON),
+    (0x1CCF0, 0x1CCF9, BidiClass::EN),
+    (0x1CD00, 0x1CEB3, BidiClass::ON),
+    (0x1CF00, 0x1CF2D, BidiClass::NSM),
+    (0x1CF30, 0x1CF46, BidiClass::NSM),
+    (0x1D167, 0x1D169, BidiClass::NSM),
+    (0x1D173, 0x1D17A, BidiClass::BN),
+    (0x1D17B, 0x1D182, BidiClass::NSM),
+    (0x1D185, 0x1D18B, BidiClass::NSM),
+    (0x1D1AA, 0x1D1AD, BidiClass::NSM),
+    (0x1D1E9, 0x1D1EA, BidiClass::ON),
+    (0x1D200, 0x1D241, BidiClass::ON),
+    (0x1D242, 0x1D244, BidiClass::NSM),
+    (0x1D245, 0x1D245, BidiClass::ON),
+    (0x1D300, 0x1D356, BidiClass::ON),
+    (0x1D6C1, 0x1D6C1, BidiClass::ON),
+    (0x1D6DB, 0x1D6DB, BidiClass::ON),
+    (0x1D6FB, 0x1D6FB, BidiClass::ON),
+    (0x1D715, 0x1D715, BidiClass::ON),
+    (0x1D735, 0x1D735, BidiClass::ON),
+    (0x1D74F, 0x1D74F, BidiClass::ON),
+    (0x1D76F, 0x1D76F, BidiClass::ON),
+    (0x1D789, 0x1D789, BidiClass::ON),
+    (0x1D7A9, 0x1D7A9, BidiClass::ON),
+    (0x1D7C3, 0x1D7C3, BidiClass::ON),
+    (0x1D7CE, 0x1D7FF, BidiClass::EN),
+    (0x1DA00, 0x1DA36, BidiClass::NSM),
+    (0x1DA3B, 0x1DA6C, BidiClass::NSM),
+    (0x1DA75, 0x1DA75, BidiClass::NSM),
+    (0x1DA84, 0x1DA84, BidiClass::NSM),
+    (0x1DA9B, 0x1DA9F, BidiClass::NSM),
+    (0x1DAA1, 0x1DAAF, BidiClass::NSM),
+    (0x1E000, 0x1E006, BidiClass::NSM),
+    (0x1E008, 0x1E018, BidiClass::NSM),
+    (0x1E01B, 0x1E021, BidiClass::NSM),
+    (0x1E023, 0x1E024, BidiClass::NSM),
+    (0x1E026, 0x1E02A, BidiClass::NSM),
+    (0x1E08F, 0x1E08F, BidiClass::NSM),
+    (0x1E130, 0x1E136, BidiClass::NSM),
+    (0x1E2AE, 0x1E2AE, BidiClass::NSM),
+    (0x1E2EC, 0x1E2EF, BidiClass::NSM),
+    (0x1E2FF, 0x1E2FF, BidiClass::ET),
+    (0x1E4EC, 0x1E4EF, BidiClass::NSM),
+    (0x1E5EE, 0x1E5EF, BidiClass::NSM),
+    (0x1E800, 0x1E8CF, BidiClass::R),
+    (0x1E8D0, 0x1E8D6, BidiClass::NSM),
+    (0x1E8D7, 0x1E943, BidiClass::R),
+    (0x1E944, 0x1E94A, BidiClass::NSM),
+    (0x1E94B, 0x1EC6F, BidiClass::R),
+    (0x1EC70, 0x1ECBF, BidiClass::AL),
+    (0x1ECC0, 0x1ECFF, BidiClass::R),
+    (0x1ED00, 0x1ED4F, BidiClass::AL),
+    (0x1ED50, 0x1EDFF, BidiClass::R),
+    (0x1EE00, 0x1EEEF, BidiClass::AL),
+    (0x1EEF0, 0x1EEF1, BidiClass::ON),
+    (0x1EEF2, 0x1EEFF, BidiClass::AL),
+    (0x1EF00, 0x1EFFF, BidiClass::R),
+    (0x1F000, 0x1F02B, BidiClass::ON),
+    (0x1F030, 0x1F093, BidiClass::ON),
+    (0x1F0A0, 0x1F0AE, BidiClass::ON),
+    (0x1F0B1, 0x1F0BF, BidiClass::ON),
+    (0x1F0C1, 0x1F0CF, BidiClass::ON),
+    (0x1F0D1, 0x1F0F5, BidiClass::ON),
+    (0x1F100, 0x1F10A, BidiClass::EN),
+    (0x1F10B, 0x1F10F, BidiClass::ON),
+    (0x1F12F, 0x1F12F, BidiClass::ON),
+    (0x1F16A, 0x1F16F, BidiClass::ON),
+    (0x1F1AD, 0x1F1AD, BidiClass::ON),
+    (0x1F260, 0x1F265, BidiClass::ON),
+    (0x1F300, 0x1F6D7, BidiClass::ON),
+    (0x1F6DC, 0x1F6EC, BidiClass::ON),
+    (0x1F6F0, 0x1F6FC, BidiClass::ON),
+    (0x1F700, 0x1F776, BidiClass::ON),
+    (0x1F77B, 0x1F7D9, BidiClass::ON),
+    (0x1F7E0, 0x1F7EB, BidiClass::ON),
+    (0x1F7F0, 0x1F7F0, BidiClass::ON),
+    (0x1F800, 0x1F80B, BidiClass::ON),
+    (0x1F810, 0x1F847, BidiClass::ON),
+    (0x1F850, 0x1F859, BidiClass::ON),
+    (0x1F860, 0x1F887, BidiClass::ON),
+    (0x1F890, 0x1F8AD, BidiClass::ON),
+    (0x1F8B0, 0x1F8BB, BidiClass::ON),
+    (0x1F8C0, 0x1F8C1, BidiClass::ON),
+    (0x1F900, 0x1FA53, BidiClass::ON),
+    (0x1FA60, 0x1FA6D, BidiClass::ON),
+    (0x1FA70, 0x1FA7C, BidiClass::ON),
+    (0x1FA80, 0x1FA89, BidiClass::ON),
+    (0x1FA8F, 0x1FAC6, BidiClass::ON),
+    (0x1FACE, 0x1FADC, BidiClass::ON),
+    (0x1FADF, 0x1FAE9, BidiClass::ON),
+    (0x1FAF0, 0x1FAF8, BidiClass::ON),
+    (0x1FB00, 0x1FB92, BidiClass::ON),
+    (0x1FB94, 0x1FBEF, BidiClass::ON),
+    (0x1FBF0, 0x1FBF9, BidiClass::EN),
+    (0x1FFFE, 0x1FFFF, BidiClass::BN),
+    (0x2FFFE, 0x2FFFF, BidiClass::BN),
+    (0x3FFFE, 0x3FFFF, BidiClass::BN),
+    (0x4FFFE, 0x4FFFF, BidiClass::BN),
+    (0x5FFFE, 0x5FFFF, BidiClass::BN),
+    (0x6FFFE, 0x6FFFF, BidiClass::BN),
+    (0x7FFFE, 0x7FFFF, BidiClass::BN),
+    (0x8FFFE, 0x8FFFF, BidiClass::BN),
+    (0x9FFFE, 0x9FFFF, BidiClass::BN),
+    (0xAFFFE, 0xAFFFF, BidiClass::BN),
+    (0xBFFFE, 0xBFFFF, BidiClass::BN),
+    (0xCFFFE, 0xCFFFF, BidiClass::BN),
+    (0xDFFFE, 0xE00FF, BidiClass::BN),
+    (0xE0100, 0xE01EF, BidiClass::NSM),
+    (0xE01F0, 0xE0FFF, BidiClass::BN),
+    (0xEFFFE, 0xEFFFF, BidiClass::BN),
+    (0xFFFFE, 0xFFFFF, BidiClass::BN),
+    (0x10FFFE, 0x10FFFF, BidiClass::BN),
+];
+// 3240 bytes
+// END GENERATED BIDI_CLASS_SUPPLEMENTARY
+
+/// Find the class of `c` in a table of [first, last, class] ranges, `BidiClass::L` if it has none
+fn find_class<T: Ord + Copy>(table: &[(T, T, BidiClass)], c: T) -> BidiClass {
+    match table.binary_search_by(|(first, last, _)| {
+        if *last < c {
+            Ordering::Less
+        } else if *first > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
         }
+    }) {
+        Ok(idx) => table[idx].2,
+        Err(_) => BidiClass::L,
     }
-    false
 }
 
-/// List of WeakCat (Weak characters)
-pub static WEAK: [[u16; 2]; 228] = [
-    [0x0000, 0x0008],
-    [0x000E, 0x001B],
-    [0x0023, 0x0025],
-    [0x002B, 0x003A],
-    [0x007F, 0x0084],
-    [0x0086, 0x00A0],
-    [0x00A2, 0x00A5],
-    [0x00AD, 0x00AD],
-    [0x00B0, 0x00B3],
-    [0x00B9, 0x00B9],
-    [0x0300, 0x036F],
-    [0x0483, 0x0489],
-    [0x0591, 0x05BD],
-    [0x05BF, 0x05BF],
-    [0x05C1, 0x05C2],
-    [0x05C4, 0x05C5],
-    [0x05C7, 0x05C7],
-    [0x0600, 0x0603],
-    [0x0609, 0x060A],
-    [0x060C, 0x060C],
-    [0x0610, 0x061A],
-    [0x064B, 0x066C],
-    [0x0670, 0x0670],
-    [0x06D6, 0x06DD],
-    [0x06DF, 0x06E4],
-    [0x06E7, 0x06E8],
-    [0x06EA, 0x06ED],
-    [0x06F0, 0x06F9],
-    [0x070F, 0x070F],
-    [0x0711, 0x0711],
-    [0x0730, 0x074A],
-    [0x07A6, 0x07B0],
-    [0x07EB, 0x07F3],
-    [0x0816, 0x0819],
-    [0x081B, 0x0823],
-    [0x0825, 0x0827],
-    [0x0829, 0x082D],
-    [0x0859, 0x085B],
-    [0x0900, 0x0902],
-    [0x093A, 0x093A],
-    [0x093C, 0x093C],
-    [0x0941, 0x0948],
-    [0x094D, 0x094D],
-    [0x0951, 0x0957],
-    [0x0962, 0x0963],
-    [0x0981, 0x0981],
-    [0x09BC, 0x09BC],
-    [0x09C1, 0x09C4],
-    [0x09CD, 0x09CD],
-    [0x09E2, 0x09E3],
-    [0x09F2, 0x09F3],
-    [0x09FB, 0x09FB],
-    [0x0A01, 0x0A02],
-    [0x0A3C, 0x0A3C],
-    [0x0A41, 0x0A42],
-    [0x0A47, 0x0A48],
-    [0x0A4B, 0x0A4D],
-    [0x0A51, 0x0A51],
-    [0x0A70, 0x0A71],
-    [0x0A75, 0x0A75],
-    [0x0A81, 0x0A82],
-    [0x0ABC, 0x0ABC],
-    [0x0AC1, 0x0AC5],
-    [0x0AC7, 0x0AC8],
-    [0x0ACD, 0x0ACD],
-    [0x0AE2, 0x0AE3],
-    [0x0AF1, 0x0AF1],
-    [0x0B01, 0x0B01],
-    [0x0B3C, 0x0B3C],
-    [0x0B3F, 0x0B3F],
-    [0x0B41, 0x0B44],
-    [0x0B4D, 0x0B4D],
-    [0x0B56, 0x0B56],
-    [0x0B62, 0x0B63],
-    [0x0B82, 0x0B82],
-    [0x0BC0, 0x0BC0],
-    [0x0BCD, 0x0BCD],
-    [0x0BF9, 0x0BF9],
-    [0x0C3E, 0x0C40],
-    [0x0C46, 0x0C48],
-    [0x0C4A, 0x0C4D],
-    [0x0C55, 0x0C56],
-    [0x0C62, 0x0C63],
-    [0x0CBC, 0x0CBC],
-    [0x0CCC, 0x0CCD],
-    [0x0CE2, 0x0CE3],
-    [0x0D41, 0x0D44],
-    [0x0D4D, 0x0D4D],
-    [0x0D62, 0x0D63],
-    [0x0DCA, 0x0DCA],
-    [0x0DD2, 0x0DD4],
-    [0x0DD6, 0x0DD6],
-    [0x0E31, 0x0E31],
-    [0x0E34, 0x0E3A],
-    [0x0E3F, 0x0E3F],
-    [0x0E47, 0x0E4E],
-    [0x0EB1, 0x0EB1],
-    [0x0EB4, 0x0EB9],
-    [0x0EBB, 0x0EBC],
-    [0x0EC8, 0x0ECD],
-    [0x0F18, 0x0F19],
-    [0x0F35, 0x0F35],
-    [0x0F37, 0x0F37],
-    [0x0F39, 0x0F39],
-    [0x0F71, 0x0F7E],
-    [0x0F80, 0x0F84],
-    [0x0F86, 0x0F87],
-    [0x0F8D, 0x0F97],
-    [0x0F99, 0x0FBC],
-    [0x0FC6, 0x0FC6],
-    [0x102D, 0x1030],
-    [0x1032, 0x1037],
-    [0x1039, 0x103A],
-    [0x103D, 0x103E],
-    [0x1058, 0x1059],
-    [0x105E, 0x1060],
-    [0x1071, 0x1074],
-    [0x1082, 0x1082],
-    [0x1085, 0x1086],
-    [0x108D, 0x108D],
-    [0x109D, 0x109D],
-    [0x135D, 0x135F],
-    [0x1712, 0x1714],
-    [0x1732, 0x1734],
-    [0x1752, 0x1753],
-    [0x1772, 0x1773],
-    [0x17B7, 0x17BD],
-    [0x17C6, 0x17C6],
-    [0x17C9, 0x17D3],
-    [0x17DB, 0x17DB],
-    [0x17DD, 0x17DD],
-    [0x180B, 0x180D],
-    [0x18A9, 0x18A9],
-    [0x1920, 0x1922],
-    [0x1927, 0x1928],
-    [0x1932, 0x1932],
-    [0x1939, 0x193B],
-    [0x1A17, 0x1A18],
-    [0x1A56, 0x1A56],
-    [0x1A58, 0x1A5E],
-    [0x1A60, 0x1A60],
-    [0x1A62, 0x1A62],
-    [0x1A65, 0x1A6C],
-    [0x1A73, 0x1A7C],
-    [0x1A7F, 0x1A7F],
-    [0x1B00, 0x1B03],
-    [0x1B34, 0x1B34],
-    [0x1B36, 0x1B3A],
-    [0x1B3C, 0x1B3C],
-    [0x1B42, 0x1B42],
-    [0x1B6B, 0x1B73],
-    [0x1B80, 0x1B81],
-    [0x1BA2, 0x1BA5],
-    [0x1BA8, 0x1BA9],
-    [0x1BE6, 0x1BE6],
-    [0x1BE8, 0x1BE9],
-    [0x1BED, 0x1BED],
-    [0x1BEF, 0x1BF1],
-    [0x1C2C, 0x1C33],
-    [0x1C36, 0x1C37],
-    [0x1CD0, 0x1CD2],
-    [0x1CD4, 0x1CE0],
-    [0x1CE2, 0x1CE8],
-    [0x1CED, 0x1CED],
-    [0x1DC0, 0x1DE6],
-    [0x1DFC, 0x1DFF],
-    [0x200B, 0x200D],
-    [0x202F, 0x2034],
-    [0x2044, 0x2044],
-    [0x2060, 0x2064],
-    [0x206A, 0x2070],
-    [0x2074, 0x207B],
-    [0x2080, 0x208B],
-    [0x20A0, 0x20B9],
-    [0x20D0, 0x20F0],
-    [0x212E, 0x212E],
-    [0x2212, 0x2213],
-    [0x2488, 0x249B],
-    [0x2CEF, 0x2CF1],
-    [0x2D7F, 0x2D7F],
-    [0x2DE0, 0x2DFF],
-    [0x302A, 0x302F],
-    [0x3099, 0x309A],
-    [0xA66F, 0xA672],
-    [0xA67C, 0xA67D],
-    [0xA6F0, 0xA6F1],
-    [0xA802, 0xA802],
-    [0xA806, 0xA806],
-    [0xA80B, 0xA80B],
-    [0xA825, 0xA826],
-    [0xA838, 0xA839],
-    [0xA8C4, 0xA8C4],
-    [0xA8E0, 0xA8F1],
-    [0xA926, 0xA92D],
-    [0xA947, 0xA951],
-    [0xA980, 0xA982],
-    [0xA9B3, 0xA9B3],
-    [0xA9B6, 0xA9B9],
-    [0xA9BC, 0xA9BC],
-    [0xA9BC, 0xA9BC],
-    [0xAA29, 0xAA2E],
-    [0xAA31, 0xAA32],
-    [0xAA35, 0xAA36],
-    [0xAA43, 0xAA43],
-    [0xAA4C, 0xAA4C],
-    [0xAAB0, 0xAAB0],
-    [0xAAB2, 0xAAB4],
-    [0xAAB7, 0xAAB8],
-    [0xAABE, 0xAABF],
-    [0xAAC1, 0xAAC1],
-    [0xABE5, 0xABE5],
-    [0xABE8, 0xABE8],
-    [0xABED, 0xABED],
-    [0xFB1E, 0xFB1E],
-    [0xFB29, 0xFB29],
-    [0xFE00, 0xFE0F],
-    [0xFE20, 0xFE26],
-    [0xFE50, 0xFE50],
-    [0xFE52, 0xFE52],
-    [0xFE55, 0xFE55],
-    [0xFE5F, 0xFE5F],
-    [0xFE62, 0xFE63],
-    [0xFE69, 0xFE6A],
-    [0xFEFF, 0xFEFF],
-    [0xFF03, 0xFF05],
-    [0xFF0B, 0xFF1A],
-    [0xFFE0, 0xFFE1],
-    [0xFFE5, 0xFFE6],
-];
-// 900 bytes
+/// Get the Bidi_Class of a character (a UTF-16 code unit), a lone surrogate is `BidiClass::L`
+pub fn get_bidi_class(c: &u16) -> BidiClass {
+    find_class(&BIDI_CLASS, *c)
+}
 
-/// Check if a character is WeakCat (Weak characters)
-pub fn is_weak(c: &u16) -> bool {
-    for arr in WEAK {
-        if *c >= arr[0] && *c <= arr[1] {
-            return true;
+/// Get the Bidi_Class of a code point, including the supplementary planes
+pub fn get_bidi_class_code_point(c: &u32) -> BidiClass {
+    match u16::try_from(*c) {
+        Ok(c) => find_class(&BIDI_CLASS, c),
+        Err(_) => find_class(&BIDI_CLASS_SUPPLEMENTARY, *c),
+    }
+}
+
+/// Get the Bidi_Class of every code unit of a UTF-16 string. Both code units of a surrogate
+/// pair take the class of the code point they encode.
+pub fn get_bidi_classes(input: &[u16]) -> Vec<BidiClass> {
+    let mut classes: Vec<BidiClass> = Vec::with_capacity(input.len());
+    for c in char::decode_utf16(input.iter().copied()) {
+        match c {
+            Ok(c) => {
+                let class = get_bidi_class_code_point(&c.into());
+                classes.resize(classes.len() + c.len_utf16(), class);
+            }
+            Err(err) => classes.push(get_bidi_class(&err.unpaired_surrogate())),
         }
     }
-    false
+    classes
+}
+
+/// Encode a slice of code points as UTF-16
+pub fn chars_to_utf16(input: &[char]) -> Vec<u16> {
+    let mut utf16 = Vec::<u16>::with_capacity(input.len());
+    for c in input {
+        utf16.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+    }
+    utf16
+}
+
+/// Decode UTF-16 into code points, unpaired surrogates become U+FFFD
+pub fn utf16_to_chars(input: &[u16]) -> Vec<char> {
+    char::decode_utf16(input.iter().copied())
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Check if a character is RandALCat (Right-to-left reading characters)
+pub fn is_rtl(c: &u16) -> bool {
+    matches!(get_bidi_class(c), BidiClass::R | BidiClass::AL)
+}
+
+/// Same as `is_rtl`, but for a code point, including the supplementary planes
+pub fn is_rtl_code_point(c: &u32) -> bool {
+    matches!(get_bidi_class_code_point(c), BidiClass::R | BidiClass::AL)
+}
+
+/// Check if a character is NeutralCat (Neutral characters), i.e. it has no inherent
+/// directionality and can be treated as being part of any adjacent run of text.
+pub fn is_neutral(c: &u16) -> bool {
+    matches!(
+        get_bidi_class(c),
+        BidiClass::BN
+            | BidiClass::B
+            | BidiClass::S
+            | BidiClass::WS
+            | BidiClass::ON
+            | BidiClass::LRE
+            | BidiClass::LRO
+            | BidiClass::RLE
+            | BidiClass::RLO
+            | BidiClass::PDF
+            | BidiClass::LRI
+            | BidiClass::RLI
+            | BidiClass::FSI
+            | BidiClass::PDI
+    )
+}
+
+/// Check if a character is WeakCat (Weak characters)
+pub fn is_weak(c: &u16) -> bool {
+    matches!(
+        get_bidi_class(c),
+        BidiClass::EN
+            | BidiClass::ES
+            | BidiClass::ET
+            | BidiClass::AN
+            | BidiClass::CS
+            | BidiClass::NSM
+    )
 }
 
 /// Text types
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Type {
     /// Right-to-left
     Rtl,
@@ -535,18 +1550,75 @@ pub enum Type {
     Ltr,
 }
 
+impl From<BidiClass> for Type {
+    fn from(class: BidiClass) -> Type {
+        match class {
+            BidiClass::L => Type::Ltr,
+            BidiClass::R | BidiClass::AL => Type::Rtl,
+            BidiClass::EN
+            | BidiClass::ES
+            | BidiClass::ET
+            | BidiClass::AN
+            | BidiClass::CS
+            | BidiClass::NSM => Type::Weak,
+            _ => Type::Neutral,
+        }
+    }
+}
+
 /// Get the type of a character
 pub fn get_type(c: &u16) -> Type {
-    if is_rtl(c) {
-        return Type::Rtl;
-    }
-    if is_neutral(c) {
-        return Type::Neutral;
+    get_bidi_class(c).into()
+}
+
+/// Same as `get_type`, but for a code point, including the supplementary planes
+pub fn get_type_code_point(c: &u32) -> Type {
+    get_bidi_class_code_point(c).into()
+}
+
+/// Explicit directional formatting characters (UAX #9 rules X1–X8)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Explicit {
+    /// Left-to-right embedding (U+202A)
+    Lre,
+    /// Right-to-left embedding (U+202B)
+    Rle,
+    /// Pop directional formatting (U+202C)
+    Pdf,
+    /// Left-to-right override (U+202D)
+    Lro,
+    /// Right-to-left override (U+202E)
+    Rlo,
+    /// Left-to-right isolate (U+2066)
+    Lri,
+    /// Right-to-left isolate (U+2067)
+    Rli,
+    /// First strong isolate (U+2068)
+    Fsi,
+    /// Pop directional isolate (U+2069)
+    Pdi,
+}
+impl Explicit {
+    /// Check if the explicit character is an isolate initiator (LRI, RLI or FSI)
+    pub fn is_isolate_initiator(&self) -> bool {
+        matches!(self, Explicit::Lri | Explicit::Rli | Explicit::Fsi)
     }
-    if is_weak(c) {
-        return Type::Weak;
+}
+
+/// Get the explicit formatting type of a character if it has one
+pub fn get_explicit(c: &u16) -> Option<Explicit> {
+    match c {
+        0x202A => Some(Explicit::Lre),
+        0x202B => Some(Explicit::Rle),
+        0x202C => Some(Explicit::Pdf),
+        0x202D => Some(Explicit::Lro),
+        0x202E => Some(Explicit::Rlo),
+        0x2066 => Some(Explicit::Lri),
+        0x2067 => Some(Explicit::Rli),
+        0x2068 => Some(Explicit::Fsi),
+        0x2069 => Some(Explicit::Pdi),
+        _ => None,
     }
-    Type::Ltr
 }
 
 /// Find the dominant type in the string. skip past CtrChar until RTL or LTR is found.
@@ -569,4 +1641,95 @@ mod tests {
         assert!(is_rtl(&0x05C3));
         assert!(!is_rtl(&0x01));
     }
+
+    #[test]
+    fn check_get_bidi_class() {
+        assert_eq!(get_bidi_class(&0x41), BidiClass::L);
+        assert_eq!(get_bidi_class(&0x05D0), BidiClass::R);
+        assert_eq!(get_bidi_class(&0x0628), BidiClass::AL);
+        assert_eq!(get_bidi_class(&0x31), BidiClass::EN);
+        assert_eq!(get_bidi_class(&0x2B), BidiClass::ES);
+        assert_eq!(get_bidi_class(&0x24), BidiClass::ET);
+        assert_eq!(get_bidi_class(&0x0661), BidiClass::AN);
+        assert_eq!(get_bidi_class(&0x2C), BidiClass::CS);
+        assert_eq!(get_bidi_class(&0x064B), BidiClass::NSM);
+        assert_eq!(get_bidi_class(&0x00AD), BidiClass::BN);
+        assert_eq!(get_bidi_class(&0x0A), BidiClass::B);
+        assert_eq!(get_bidi_class(&0x09), BidiClass::S);
+        assert_eq!(get_bidi_class(&0x20), BidiClass::WS);
+        assert_eq!(get_bidi_class(&0x21), BidiClass::ON);
+        assert_eq!(get_bidi_class(&0x202E), BidiClass::RLO);
+        assert_eq!(get_bidi_class(&0x2067), BidiClass::RLI);
+        // Arabic Extended-B and Extended-A
+        assert_eq!(get_bidi_class(&0x0870), BidiClass::AL);
+        assert_eq!(get_bidi_class(&0x0897), BidiClass::NSM);
+        assert_eq!(get_bidi_class(&0x08B5), BidiClass::AL);
+        // unassigned code points take their block default
+        assert_eq!(get_bidi_class(&0x05FF), BidiClass::R);
+        assert_eq!(get_bidi_class(&0x086F), BidiClass::AL);
+        assert_eq!(get_type(&0x0661), Type::Weak);
+    }
+
+    #[test]
+    fn check_supplementary_bidi_class() {
+        // Hanifi Rohingya, Mende Kikakui and Adlam
+        assert_eq!(get_bidi_class_code_point(&0x10D00), BidiClass::AL);
+        assert_eq!(get_bidi_class_code_point(&0x1E800), BidiClass::R);
+        assert_eq!(get_bidi_class_code_point(&'\u{1E900}'.into()), BidiClass::R);
+        assert!(is_rtl_code_point(&0x1E922));
+        // a surrogate code unit is not the code point it encodes
+        assert!(!is_rtl(&0xD83A));
+        // Kawi and Nag Mundari letters and their marks
+        assert_eq!(get_bidi_class_code_point(&0x11F04), BidiClass::L);
+        assert_eq!(get_bidi_class_code_point(&0x11F00), BidiClass::NSM);
+        assert_eq!(get_bidi_class_code_point(&0x1E4D0), BidiClass::L);
+        assert_eq!(get_bidi_class_code_point(&0x1E4EC), BidiClass::NSM);
+        // emoji, CJK Extension B and tags
+        assert_eq!(get_type_code_point(&0x1F600), Type::Neutral);
+        assert_eq!(get_bidi_class_code_point(&0x20000), BidiClass::L);
+        assert_eq!(get_bidi_class_code_point(&0xE0001), BidiClass::BN);
+        // unassigned code points take their block default
+        assert_eq!(get_bidi_class_code_point(&0x10FFF), BidiClass::R);
+        assert!(BIDI_CLASS_SUPPLEMENTARY.windows(2).all(|w| w[0].1 < w[1].0));
+    }
+
+    #[test]
+    fn check_get_bidi_classes() {
+        // 'a', Adlam alif as a surrogate pair and a lone high surrogate
+        let classes = get_bidi_classes(&[0x61, 0xD83A, 0xDD00, 0xD83A]);
+        assert_eq!(classes, [BidiClass::L, BidiClass::R, BidiClass::R, BidiClass::L]);
+    }
+
+    #[test]
+    fn check_utf16_conversion() {
+        let utf16 = chars_to_utf16(&['a', '\u{1E900}', 'ب']);
+        assert_eq!(utf16, [0x61, 0xD83A, 0xDD00, 0x0628]);
+        assert_eq!(utf16_to_chars(&utf16), ['a', '\u{1E900}', 'ب']);
+        assert_eq!(utf16_to_chars(&[0xD83A, 0x61]), ['\u{FFFD}', 'a']);
+    }
+
+    #[test]
+    fn check_get_mirror() {
+        assert_eq!(get_mirror(&0x28), 0x29);
+        assert_eq!(get_mirror(&0x29), 0x28);
+        assert_eq!(get_mirror(&0xAB), 0xBB);
+        // math operators, quotation marks and CJK brackets
+        assert_eq!(get_mirror(&0x2264), 0x2265);
+        assert_eq!(get_mirror(&0x2039), 0x203A);
+        assert_eq!(get_mirror(&0x300C), 0x300D);
+        assert_eq!(get_mirror(&0xFF63), 0xFF62);
+        assert_eq!(get_mirror(&0x61), 0x61);
+        assert!(MIRROR_CHAR.windows(2).all(|w| w[0][0] < w[1][0]));
+    }
+
+    #[test]
+    fn check_get_bracket() {
+        assert_eq!(get_bracket(&0x28), Some((BracketType::Open, 0x28)));
+        assert_eq!(get_bracket(&0x29), Some((BracketType::Close, 0x28)));
+        // canonical equivalents match each other
+        assert_eq!(get_bracket(&0x232A), Some((BracketType::Close, 0x3008)));
+        assert_eq!(get_bracket(&0x3009), Some((BracketType::Close, 0x3008)));
+        assert_eq!(get_bracket(&0x298E), Some((BracketType::Close, 0x298F)));
+        assert_eq!(get_bracket(&0x61), None);
+    }
 }
@@ -1,7 +1,13 @@
+/// Explicit embedding levels and isolating run sequences
+mod explicit;
+/// Implicit level resolution
+mod implicit;
 /// Module for bidi processing
 mod internal;
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+use explicit::*;
+use implicit::*;
 pub use internal::*;
 
 struct Line {
@@ -9,150 +15,412 @@ struct Line {
     pub end: usize,
 }
 
-#[derive(Copy, Clone)]
-struct Chunk {
+/// A run of characters that share the same embedding level
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    /// Start position of the run in the input (inclusive)
     pub start: usize,
+    /// End position of the run in the input (exclusive)
     pub end: usize,
+    /// Direction of the run, either `Type::Rtl` or `Type::Ltr`
     pub r#type: Type,
+    /// Embedding level of the run
+    pub level: u8,
 }
 impl Chunk {
-    pub fn new(start: usize, end: usize, r#type: Type) -> Chunk {
-        Chunk { start, end, r#type }
+    /// Create a new run, the direction is derived from the level
+    pub fn new(start: usize, end: usize, level: u8) -> Chunk {
+        let r#type = if level & 1 == 1 { Type::Rtl } else { Type::Ltr };
+        Chunk { start, end, r#type, level }
     }
 
+    /// Check if the run is of type `t`
     pub fn is_type(&self, t: Type) -> bool {
         self.r#type == t
     }
+}
+
+/// The resolved bidi levels of a paragraph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidiParagraph {
+    /// Start position of the paragraph in the input (inclusive)
+    pub start: usize,
+    /// End position of the paragraph in the input (exclusive), before its separator
+    pub end: usize,
+    /// Paragraph embedding level (0 for LTR, 1 for RTL)
+    pub level: u8,
+    /// Resolved embedding level of each code unit in `start..end`, with trailing whitespace
+    /// and separators reset to the paragraph level (L1)
+    pub levels: Vec<u8>,
+    /// Directional runs in visual order (left-to-right). Positions are relative to the input.
+    /// Characters of `Type::Rtl` runs are displayed in reverse.
+    pub runs: Vec<Chunk>,
+}
 
-    pub fn set_type(&mut self, t: Type) {
-        self.r#type = t;
+/// Check if a character ends a paragraph (Bidi_Class B): LF, CR, NEL or PS. CR followed by LF
+/// is a single paragraph separator.
+fn is_paragraph_separator(c: &u16) -> bool {
+    matches!(c, 0x000A | 0x000D | 0x0085 | 0x2029)
+}
+
+/// LINE SEPARATOR (U+2028) is whitespace (WS) to the bidi algorithm, it ends a line but not
+/// its paragraph
+const LINE_SEPARATOR: u16 = 0x2028;
+
+impl BidiParagraph {
+    /// Get the directional runs of the line `start..end` (input positions inside the
+    /// paragraph) in visual order. Trailing whitespace of the line is reset to the paragraph
+    /// level (L1) before the runs are reordered (L2).
+    pub fn line_runs(&self, input: &[u16], start: usize, end: usize) -> Vec<Chunk> {
+        let mut levels = self.levels[start - self.start..end - self.start].to_vec();
+        reset_whitespace_levels(&input[start..end], self.level, &mut levels);
+        visual_runs(&levels, start)
     }
+}
 
-    pub fn set_chunk_type(&mut self, c: &Chunk) {
-        self.r#type = c.r#type;
+/// Split a paragraph into lines at the caller's `line_breaks` (input positions where a new
+/// line starts) and at every line separator, which belongs to neither line. Empty lines are
+/// only kept between line separators.
+fn paragraph_lines(input: &[u16], paragraph: &BidiParagraph, line_breaks: &[usize]) -> Vec<Line> {
+    // [end of a line, start of the next line]
+    let mut breaks: Vec<(usize, usize)> = line_breaks
+        .iter()
+        .filter(|b| **b > paragraph.start && **b < paragraph.end && input[**b] != LINE_SEPARATOR)
+        .map(|b| (*b, *b))
+        .collect();
+    breaks.extend(
+        (paragraph.start..paragraph.end)
+            .filter(|idx| input[*idx] == LINE_SEPARATOR)
+            .map(|idx| (idx, idx + 1)),
+    );
+    breaks.sort_unstable();
+    breaks.push((paragraph.end, paragraph.end));
+
+    let mut lines = Vec::<Line>::new();
+    let mut start = paragraph.start;
+    for (end, next_start) in breaks {
+        let is_separator = next_start > end;
+        if end > start || (is_separator && end == start) {
+            lines.push(Line { start, end });
+        }
+        start = start.max(next_start);
     }
+
+    lines
 }
 
-/// Process a string of text and return a new string with the correct bidi ordering.
-/// Follows https://www.unicode.org/reports/tr9/#Basic_Display_Algorithm as closely as possible.
-/// Some things are not implemented, such as:
-/// - Explicit embedding levels
-/// - Explicit bracket control
-pub fn process_bidi_text(input: &[u16]) -> Vec<u16> {
-    // print input:
-    let mut result = Vec::<u16>::new();
+/// The base (paragraph) direction of the text
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum BaseDirection {
+    /// Use the direction of the first strong character, left-to-right if there is none (P2, P3)
+    #[default]
+    Auto,
+    /// Left-to-right, like `dir="ltr"` in HTML
+    Ltr,
+    /// Right-to-left, like `dir="rtl"` in HTML
+    Rtl,
+}
+
+/// Split the input by paragraph separators. Separators are not part of the paragraph, empty
+/// paragraphs are kept and a separator at the end of the input does not start a new paragraph.
+fn split_paragraphs(input: &[u16]) -> Vec<Line> {
     // lines are storing [start, end] positions
     let mut lines = Vec::<Line>::new();
-    // step 1: split by lines
     let mut start: usize = 0;
-    let mut end: usize = 0;
-    for (idx, c) in input.iter().enumerate() {
-        if *c == 0x000A || *c == 0x000D {
-            if start == end {
-                start = idx + 1;
-            } else {
-                lines.push(Line { start, end: idx });
-                start = idx + 1;
-            }
+    let mut idx: usize = 0;
+    while idx < input.len() {
+        if !is_paragraph_separator(&input[idx]) {
+            idx += 1;
+            continue;
         }
-        end = idx + 1;
+        lines.push(Line { start, end: idx });
+        if input[idx] == 0x000D && input.get(idx + 1) == Some(&0x000A) {
+            idx += 1;
+        }
+        idx += 1;
+        start = idx;
     }
     // store the last line
     if start < input.len() {
         lines.push(Line { start, end: input.len() });
     }
-    // step 2: iterate lines
-    for (line_idx, line) in lines.iter().enumerate() {
-        let line_str = &input[line.start..line.end];
-        let mut word_chunks = Vec::<Chunk>::new();
-        // s2.1: define dominant type
-        let mut cur_type: Type = get_type(&line_str[0]);
-        let dominant_rtl: bool = if cur_type != Type::Neutral && cur_type != Type::Weak {
-            cur_type == Type::Rtl
-        } else {
-            find_dominant_type(line_str) == Type::Rtl
-        };
-        if cur_type == Type::Neutral || cur_type == Type::Weak {
-            if dominant_rtl {
-                cur_type = Type::Rtl
-            } else {
-                cur_type = Type::Ltr;
-            }
+
+    lines
+}
+
+/// Resolve the embedding level of every character of a paragraph (X1–X10, W1–I2).
+/// Characters removed by X9 take the level of the preceding character.
+fn resolve_levels(input: &[u16], para_level: u8) -> Vec<u8> {
+    let matches = match_isolates(input);
+    let mut classes = get_bidi_classes(input);
+    let mut levels = Vec::<u8>::with_capacity(input.len());
+    levels.resize(input.len(), para_level);
+    let mut removed = Vec::<bool>::with_capacity(input.len());
+    removed.resize(input.len(), false);
+
+    resolve_explicit(input, para_level, &matches, &mut classes, &mut levels, &mut removed);
+    for seq in isolating_run_sequences(input, para_level, &matches, &levels, &removed) {
+        resolve_implicit(input, &seq, &classes, &mut levels);
+    }
+    for idx in 0..input.len() {
+        if removed[idx] {
+            levels[idx] = if idx == 0 { para_level } else { levels[idx - 1] };
         }
-        // s2.2: group by RTL and LTR chunks
-        start = 0;
-        for (idx, u_code) in line_str.iter().enumerate() {
-            let u_type = get_type(u_code);
-            if u_type != cur_type {
-                word_chunks.push(Chunk::new(start, idx, cur_type));
-                start = idx;
-                cur_type = u_type;
+    }
+
+    levels
+}
+
+/// L1: Reset segment separators, paragraph separators and any sequence of whitespace or
+/// isolate formatting characters preceding them or the end of the line to the paragraph level.
+/// Characters removed by X9 inside such a sequence are reset as well.
+fn reset_whitespace_levels(input: &[u16], para_level: u8, levels: &mut [u8]) {
+    // true while scanning backwards through a trailing whitespace sequence
+    let mut trailing = true;
+    let classes = get_bidi_classes(input);
+    for idx in (0..input.len()).rev() {
+        let c = &input[idx];
+        match classes[idx] {
+            BidiClass::S | BidiClass::B => {
+                levels[idx] = para_level;
+                trailing = true;
+            }
+            BidiClass::WS
+            | BidiClass::BN
+            | BidiClass::LRE
+            | BidiClass::RLE
+            | BidiClass::LRO
+            | BidiClass::RLO
+            | BidiClass::PDF
+            | BidiClass::LRI
+            | BidiClass::RLI
+            | BidiClass::FSI
+            | BidiClass::PDI => {
+                if trailing {
+                    levels[idx] = para_level;
+                }
+            }
+            _ if get_explicit(c).is_some() => {
+                if trailing {
+                    levels[idx] = para_level;
+                }
             }
+            _ => trailing = false,
         }
-        // store the last chunk
-        if start != line_str.len() {
-            word_chunks.push(Chunk::new(start, line_str.len(), cur_type));
+    }
+}
+
+/// L2: Group the levels of a line into runs and sort them into visual order.
+/// `offset` is added to every run position.
+fn visual_runs(levels: &[u8], offset: usize) -> Vec<Chunk> {
+    // group characters by level
+    let mut chunks = Vec::<Chunk>::new();
+    let mut start: usize = 0;
+    for idx in 1..=levels.len() {
+        if idx == levels.len() || levels[idx] != levels[start] {
+            chunks.push(Chunk::new(offset + start, offset + idx, levels[start]));
+            start = idx;
         }
-        // update neutral and weak chunks
-        let word_chunks_len = word_chunks.len();
-        for idx in 0..word_chunks_len {
-            let prev: Option<Chunk> =
-                if idx == 0 { None } else { word_chunks.get(idx - 1).cloned() };
-            let next: Option<Chunk> =
-                if idx == word_chunks_len - 1 { None } else { word_chunks.get(idx + 1).cloned() };
-            let chunk = word_chunks.get_mut(idx).unwrap();
-            if chunk.is_type(Type::Neutral) {
-                if idx == 0 {
-                    chunk.set_chunk_type(&next.unwrap());
-                } else if idx == word_chunks_len - 1 {
-                    continue;
-                } else if prev.unwrap().r#type == next.unwrap().r#type {
-                    chunk.set_chunk_type(&prev.unwrap());
-                } else {
-                    chunk.set_type(if dominant_rtl { Type::Rtl } else { Type::Ltr });
-                }
-            } else if chunk.is_type(Type::Weak)
-                && idx != 0
-                && prev.unwrap().is_type(Type::Rtl)
-                && !dominant_rtl
-            {
-                // swap chunks
-                word_chunks.swap(idx, idx - 1);
+    }
+    // From the highest level to the lowest odd level, reverse any sequence of chunks
+    // at that level or higher
+    let max_level = chunks.iter().map(|c| c.level).max().unwrap_or(0);
+    let min_odd_level = chunks.iter().map(|c| c.level | 1).min().unwrap_or(1);
+    let mut level = max_level;
+    while level >= min_odd_level {
+        let mut i: usize = 0;
+        while i < chunks.len() {
+            if chunks[i].level < level {
+                i += 1;
+                continue;
+            }
+            let seq_start = i;
+            while i < chunks.len() && chunks[i].level >= level {
+                i += 1;
             }
+            chunks[seq_start..i].reverse();
         }
-        // merge chunks that are the same
-        let mut i: isize = 0;
-        while i < (word_chunks.len() as isize - 1) {
-            let idx: usize = i.try_into().unwrap();
-            if word_chunks[idx].r#type == word_chunks[idx + 1].r#type {
-                word_chunks[idx].end = word_chunks[idx + 1].end;
-                word_chunks.remove(idx + 1);
-                i -= 1;
+        level -= 1;
+    }
+
+    chunks
+}
+
+/// Resolve the bidi embedding levels of a string of text without reordering it.
+/// Returns one entry per paragraph (line) with the paragraph level, the level of each
+/// code unit and the directional runs in visual order, so that callers can lay out
+/// the runs themselves. `direction` sets the paragraph level of every paragraph.
+pub fn resolve_bidi_levels(input: &[u16], direction: BaseDirection) -> Vec<BidiParagraph> {
+    split_paragraphs(input)
+        .into_iter()
+        .map(|line| {
+            let line_str = &input[line.start..line.end];
+            let level = match direction {
+                // define the paragraph level from the first strong character (P2, P3)
+                BaseDirection::Auto => first_strong_level(line_str).unwrap_or(0),
+                BaseDirection::Ltr => 0,
+                BaseDirection::Rtl => 1,
+            };
+            // resolve explicit and implicit levels
+            let mut levels = resolve_levels(line_str, level);
+            reset_whitespace_levels(line_str, level, &mut levels);
+            let runs = visual_runs(&levels, line.start);
+            BidiParagraph { start: line.start, end: line.end, level, levels, runs }
+        })
+        .collect()
+}
+
+/// Process a string of text and return a new string with the correct bidi ordering.
+/// Follows https://www.unicode.org/reports/tr9/#Basic_Display_Algorithm as closely as possible,
+/// including explicit embeddings, overrides, isolates and paired brackets.
+/// `direction` is the base direction of the text, `BaseDirection::Auto` detects it per paragraph.
+/// Paragraphs end at LF, CR, CRLF, NEL and PS, while a LINE SEPARATOR (U+2028) only ends a
+/// line: the paragraph keeps its level and each of its lines is reordered on its own.
+pub fn process_bidi_text(input: &[u16], direction: BaseDirection) -> Vec<u16> {
+    reorder_text(input, direction, None)
+}
+
+/// Same as `process_bidi_text` for UTF-8 text
+pub fn process_bidi_str(input: &str, direction: BaseDirection) -> String {
+    let utf16: Vec<u16> = input.encode_utf16().collect();
+    String::from_utf16_lossy(&process_bidi_text(&utf16, direction))
+}
+
+/// Same as `process_bidi_text` for a slice of code points
+pub fn process_bidi_chars(input: &[char], direction: BaseDirection) -> Vec<char> {
+    utf16_to_chars(&process_bidi_text(&chars_to_utf16(input), direction))
+}
+
+/// Same as `process_bidi_text` but also stores the input position of every output
+/// code unit in `visual_map`.
+pub fn process_bidi_text_with_map(
+    input: &[u16],
+    direction: BaseDirection,
+    visual_map: &mut Vec<usize>,
+) -> Vec<u16> {
+    visual_map.clear();
+    reorder_text(input, direction, Some(visual_map))
+}
+
+/// Reorder every line of the text, storing the input positions in `visual_map` if given
+fn reorder_text(
+    input: &[u16],
+    direction: BaseDirection,
+    mut visual_map: Option<&mut Vec<usize>>,
+) -> Vec<u16> {
+    let mut result = Vec::<u16>::with_capacity(input.len());
+    let paragraphs = resolve_bidi_levels(input, direction);
+    for (para_idx, paragraph) in paragraphs.iter().enumerate() {
+        let lines = paragraph_lines(input, paragraph, &[]);
+        for (line_idx, line) in lines.iter().enumerate() {
+            let line_runs;
+            // the paragraph runs also cover line separators, so only a line spanning the whole
+            // paragraph can reuse them
+            let runs = if line.start == paragraph.start && line.end == paragraph.end {
+                &paragraph.runs
+            } else {
+                line_runs = paragraph.line_runs(input, line.start, line.end);
+                &line_runs
+            };
+            push_visual_runs(input, runs, &mut result);
+            // keep the line separator
+            let next_start = lines.get(line_idx + 1).map_or(paragraph.end, |l| l.start);
+            result.extend_from_slice(&input[line.end..next_start]);
+            if let Some(visual_map) = visual_map.as_deref_mut() {
+                push_visual_positions(input, runs, visual_map);
+                visual_map.extend(line.end..next_start);
             }
-            i += 1
         }
-        // s2.3: If RTL dominant, then reverse the whole sentence (all chunks)
-        if dominant_rtl {
-            word_chunks.reverse();
+        // keep the original paragraph separator (LF, CRLF, CR, NEL or PS)
+        let next_start = paragraphs.get(para_idx + 1).map_or(input.len(), |p| p.start);
+        result.extend_from_slice(&input[paragraph.end..next_start]);
+        if let Some(visual_map) = visual_map.as_deref_mut() {
+            visual_map.extend(paragraph.end..next_start);
         }
-        // s2.4: Store each part, reversing each chunk as needed
-        for chunk in word_chunks {
-            let mut chunk_vec = line_str[chunk.start..chunk.end].to_vec();
-            let chunk_str = chunk_vec.as_mut_slice();
-            if chunk.is_type(Type::Rtl) {
-                chunk_str.reverse();
-                // run through the chunk_str and check for any mirrored characters (e.g. parentheses)
-                mirror_adjust_string(chunk_str);
+    }
+
+    result
+}
+
+/// Process a string of text that is wrapped at the caller's chosen line break points.
+/// `line_breaks` are the input positions where a new line starts, in ascending order; lines
+/// also end at every paragraph and line separator. Levels are resolved once per paragraph and
+/// each line is then reordered on its own (L1 & L2). Returns the visual order of every line,
+/// without separators.
+pub fn process_bidi_lines(
+    input: &[u16],
+    line_breaks: &[usize],
+    direction: BaseDirection,
+) -> Vec<Vec<u16>> {
+    let mut lines = Vec::<Vec<u16>>::new();
+    for paragraph in resolve_bidi_levels(input, direction) {
+        for line in paragraph_lines(input, &paragraph, line_breaks) {
+            let mut visual_line = Vec::<u16>::with_capacity(line.end - line.start);
+            let runs = paragraph.line_runs(input, line.start, line.end);
+            push_visual_runs(input, &runs, &mut visual_line);
+            lines.push(visual_line);
+        }
+        if paragraph.start == paragraph.end {
+            lines.push(Vec::<u16>::new());
+        }
+    }
+
+    lines
+}
+
+/// Check if a code unit is the low (trailing) half of a surrogate pair
+fn is_low_surrogate(c: &u16) -> bool {
+    (0xDC00..=0xDFFF).contains(c)
+}
+
+/// Check if a code unit is the high (leading) half of a surrogate pair
+fn is_high_surrogate(c: &u16) -> bool {
+    (0xD800..=0xDBFF).contains(c)
+}
+
+/// Store each run in visual order, RTL runs are reversed and mirrored. Surrogate pairs are
+/// kept in their logical order so reversed runs stay valid UTF-16.
+fn push_visual_runs(input: &[u16], runs: &[Chunk], result: &mut Vec<u16>) {
+    for chunk in runs {
+        let mut chunk_vec = input[chunk.start..chunk.end].to_vec();
+        let chunk_str = chunk_vec.as_mut_slice();
+        if chunk.is_type(Type::Rtl) {
+            chunk_str.reverse();
+            // run through the chunk_str and check for any mirrored characters (e.g. parentheses)
+            mirror_adjust_string(chunk_str);
+            let mut idx = 1;
+            while idx < chunk_str.len() {
+                if is_low_surrogate(&chunk_str[idx - 1]) && is_high_surrogate(&chunk_str[idx]) {
+                    chunk_str.swap(idx - 1, idx);
+                    idx += 1;
+                }
+                idx += 1;
             }
-            result.extend_from_slice(chunk_str)
         }
-        // TODO: use the original return (\n or \r) not just \n
-        if line_idx != lines.len() - 1 {
-            result.push(0x000A);
-        } // \n
+        result.extend_from_slice(chunk_str)
     }
+}
 
-    result.to_vec()
+/// Store the input position of every code unit `push_visual_runs` stores for the same runs
+fn push_visual_positions(input: &[u16], runs: &[Chunk], visual_map: &mut Vec<usize>) {
+    for chunk in runs {
+        if chunk.is_type(Type::Rtl) {
+            let map_start = visual_map.len();
+            visual_map.extend((chunk.start..chunk.end).rev());
+            let mut idx = map_start + 1;
+            while idx < visual_map.len() {
+                if is_low_surrogate(&input[visual_map[idx - 1]])
+                    && is_high_surrogate(&input[visual_map[idx]])
+                {
+                    visual_map.swap(idx - 1, idx);
+                    idx += 1;
+                }
+                idx += 1;
+            }
+        } else {
+            visual_map.extend(chunk.start..chunk.end);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -167,7 +435,7 @@ mod tests {
         let utf16_slice: Vec<u16> = my_string.encode_utf16().collect();
         // Create a reference to the slice
         let utf16_ref: &[u16] = &utf16_slice;
-        let result: &[u16] = &process_bidi_text(utf16_ref);
+        let result: &[u16] = &process_bidi_text(utf16_ref, BaseDirection::Auto);
         assert_eq!(result, utf16_ref);
     }
 
@@ -175,7 +443,7 @@ mod tests {
     fn arabic_string() {
         let input_utf16_ref: &[u16] = &[65203, 65276, 65249, 1779, 1785];
         let expected_utf16_ref: &[u16] = &[1779, 1785, 65249, 65276, 65203];
-        let result: &[u16] = &process_bidi_text(input_utf16_ref);
+        let result: &[u16] = &process_bidi_text(input_utf16_ref, BaseDirection::Auto);
         assert_ne!(result, input_utf16_ref);
         assert_eq!(result, expected_utf16_ref);
     }
@@ -184,8 +452,239 @@ mod tests {
     fn hebrew_string() {
         let input_utf16_ref: &[u16] = &[1468, 1489];
         let expected_utf16_ref: &[u16] = &[1489, 1468];
-        let result: &[u16] = &process_bidi_text(input_utf16_ref);
+        let result: &[u16] = &process_bidi_text(input_utf16_ref, BaseDirection::Auto);
         assert_ne!(result, input_utf16_ref);
         assert_eq!(result, expected_utf16_ref);
     }
+
+    #[test]
+    fn explicit_embedding() {
+        // "a RLE b alef PDF c" => "a alef b c"
+        let input: &[u16] = &[0x61, 0x202B, 0x62, 0x05D0, 0x202C, 0x63];
+        let result = process_bidi_text(input, BaseDirection::Auto);
+        let visible: Vec<u16> = result.into_iter().filter(|c| get_explicit(c).is_none()).collect();
+        assert_eq!(visible, [0x61, 0x05D0, 0x62, 0x63]);
+    }
+
+    #[test]
+    fn explicit_override() {
+        // "RLO a b c PDF" => "c b a"
+        let input: &[u16] = &[0x202E, 0x61, 0x62, 0x63, 0x202C];
+        let result = process_bidi_text(input, BaseDirection::Auto);
+        let visible: Vec<u16> = result.into_iter().filter(|c| get_explicit(c).is_none()).collect();
+        assert_eq!(visible, [0x63, 0x62, 0x61]);
+    }
+
+    #[test]
+    fn isolate_keeps_latin_order() {
+        // "alef bet LRI a b PDI" => "LRI a b PDI bet alef"
+        let input: &[u16] = &[0x05D0, 0x05D1, 0x20, 0x2066, 0x61, 0x62, 0x2069];
+        let result: &[u16] = &process_bidi_text(input, BaseDirection::Auto);
+        assert_eq!(result, [0x2069, 0x61, 0x62, 0x2066, 0x20, 0x05D1, 0x05D0]);
+    }
+
+    #[test]
+    fn bracket_pair_follows_context() {
+        // "ب (a) c" => "c (a) ب"
+        let input: Vec<u16> = "ب (a) c".encode_utf16().collect();
+        let expected: Vec<u16> = "c (a) ب".encode_utf16().collect();
+        assert_eq!(process_bidi_text(&input, BaseDirection::Auto), expected);
+    }
+
+    #[test]
+    fn bracket_pair_takes_content_direction() {
+        // brackets around RTL text in a LTR paragraph stay around their content
+        let input: Vec<u16> = "a (عربي) text".encode_utf16().collect();
+        let expected: Vec<u16> = "a (يبرع) text".encode_utf16().collect();
+        assert_eq!(process_bidi_text(&input, BaseDirection::Auto), expected);
+    }
+
+    #[test]
+    fn numbers_in_rtl_text() {
+        let cases = [
+            // EN after AL becomes AN, the CS between them joins the number
+            ("ب 12.50", "12.50 ب"),
+            ("ب 1/2", "1/2 ب"),
+            // ET attaches to the European number
+            ("א $12.50", "$12.50 א"),
+            // ES between European numbers joins them
+            ("א 555-1234", "555-1234 א"),
+        ];
+        for (input, expected) in cases {
+            let input: Vec<u16> = input.encode_utf16().collect();
+            let expected: Vec<u16> = expected.encode_utf16().collect();
+            assert_eq!(process_bidi_text(&input, BaseDirection::Auto), expected);
+        }
+    }
+
+    #[test]
+    fn nsm_follows_previous_type() {
+        // "a alef NSM b": the mark stays attached to the alef
+        let input: &[u16] = &[0x61, 0x20, 0x05D0, 0x05B0, 0x20, 0x62];
+        let paragraphs = resolve_bidi_levels(input, BaseDirection::Auto);
+        assert_eq!(paragraphs[0].levels, [0, 0, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn trailing_whitespace_keeps_paragraph_level() {
+        // "a אב  " => trailing spaces stay at the end of the LTR line
+        let input: Vec<u16> = "a אב  ".encode_utf16().collect();
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Auto);
+        assert_eq!(paragraphs[0].levels, [0, 0, 1, 1, 0, 0]);
+        assert_eq!(
+            process_bidi_text(&input, BaseDirection::Auto),
+            "a בא  ".encode_utf16().collect::<Vec<u16>>()
+        );
+        // "אב b  " => trailing spaces are displayed on the left of the RTL line
+        let input: Vec<u16> = "אב b  ".encode_utf16().collect();
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Auto);
+        assert_eq!(paragraphs[0].levels, [1, 1, 1, 2, 1, 1]);
+        assert_eq!(
+            process_bidi_text(&input, BaseDirection::Auto),
+            "  b בא".encode_utf16().collect::<Vec<u16>>()
+        );
+    }
+
+    #[test]
+    fn segment_separator_resets_level() {
+        // the tab and the whitespace before it take the paragraph level
+        let input: Vec<u16> = "a \tב".encode_utf16().collect();
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Auto);
+        assert_eq!(paragraphs[0].levels, [0, 0, 0, 1]);
+        let input: Vec<u16> = "א b \tc".encode_utf16().collect();
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Auto);
+        assert_eq!(paragraphs[0].levels, [1, 1, 2, 1, 1, 2]);
+    }
+
+    #[test]
+    fn line_terminators_are_preserved() {
+        let input: Vec<u16> = "אב\r\nג\rד\u{85}ה\u{2028}ו\u{2029}\n\nab\n".encode_utf16().collect();
+        let expected: Vec<u16> =
+            "בא\r\nג\rד\u{85}ה\u{2028}ו\u{2029}\n\nab\n".encode_utf16().collect();
+        assert_eq!(process_bidi_text(&input, BaseDirection::Auto), expected);
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Auto);
+        let lines: Vec<(usize, usize)> = paragraphs.iter().map(|p| (p.start, p.end)).collect();
+        assert_eq!(lines, [(0, 2), (4, 5), (6, 7), (8, 11), (12, 12), (13, 13), (14, 16)]);
+    }
+
+    #[test]
+    fn line_separator_ends_line_not_paragraph() {
+        // the paragraph is RTL, so the trailing "!" of the second line is displayed first
+        let input: Vec<u16> = "אב\u{2028}ab!".encode_utf16().collect();
+        let expected: Vec<u16> = "בא\u{2028}!ab".encode_utf16().collect();
+        let mut visual_map = Vec::<usize>::new();
+        let result = process_bidi_text_with_map(&input, BaseDirection::Auto, &mut visual_map);
+        assert_eq!(result, expected);
+        assert_eq!(visual_map, [1, 0, 2, 5, 3, 4]);
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Auto);
+        assert_eq!(paragraphs.len(), 1);
+        assert_eq!(paragraphs[0].level, 1);
+        // empty lines between line separators are kept
+        let input: Vec<u16> = "אב\u{2028}\u{2028}ab!\u{2028}".encode_utf16().collect();
+        let lines = process_bidi_lines(&input, &[2, 3], BaseDirection::Auto);
+        let expected: [Vec<u16>; 3] =
+            ["בא".encode_utf16().collect(), [].to_vec(), "!ab".encode_utf16().collect()];
+        assert_eq!(lines, expected);
+        let expected: Vec<u16> = "בא\u{2028}\u{2028}!ab\u{2028}".encode_utf16().collect();
+        assert_eq!(process_bidi_text(&input, BaseDirection::Auto), expected);
+    }
+
+    #[test]
+    fn trailing_line_separator_is_kept_once() {
+        for input in [[0x2028].as_slice(), &[0x05D0, 0x2028], &[0x61, 0x05D0, 0x2028]] {
+            let mut visual_map = Vec::<usize>::new();
+            let result = process_bidi_text_with_map(input, BaseDirection::Auto, &mut visual_map);
+            assert_eq!(result.len(), input.len());
+            assert_eq!(result.last(), Some(&0x2028));
+            // every input position appears exactly once
+            let mut positions = visual_map.clone();
+            positions.sort_unstable();
+            assert_eq!(positions, (0..input.len()).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn lines_are_reordered_separately() {
+        // "אב גד ef" wrapped after "אב ": each line is reordered on its own
+        let input: Vec<u16> = "אב גד ef\nab".encode_utf16().collect();
+        let lines = process_bidi_lines(&input, &[3], BaseDirection::Auto);
+        let expected: [Vec<u16>; 3] = [
+            " בא".encode_utf16().collect(),
+            "ef דג".encode_utf16().collect(),
+            "ab".encode_utf16().collect(),
+        ];
+        assert_eq!(lines, expected);
+        // without breaks every paragraph is a single line
+        let lines = process_bidi_lines(&input, &[], BaseDirection::Auto);
+        assert_eq!(lines[0], "ef דג בא".encode_utf16().collect::<Vec<u16>>());
+        // empty paragraphs are kept as empty lines
+        let lines = process_bidi_lines(&[0x61, 0x0A, 0x0A, 0x62], &[], BaseDirection::Auto);
+        assert_eq!(lines, [[0x61].to_vec(), [].to_vec(), [0x62].to_vec()]);
+    }
+
+    #[test]
+    fn base_direction() {
+        let input: Vec<u16> = "abc אבג".encode_utf16().collect();
+        let ltr: Vec<u16> = "abc גבא".encode_utf16().collect();
+        let rtl: Vec<u16> = "גבא abc".encode_utf16().collect();
+        assert_eq!(process_bidi_text(&input, BaseDirection::Auto), ltr);
+        assert_eq!(process_bidi_text(&input, BaseDirection::Ltr), ltr);
+        assert_eq!(process_bidi_text(&input, BaseDirection::Rtl), rtl);
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Rtl);
+        assert_eq!(paragraphs[0].level, 1);
+        // neutral only text follows the forced direction
+        let input: Vec<u16> = "(1)".encode_utf16().collect();
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Rtl);
+        assert_eq!(paragraphs[0].levels, [1, 2, 1]);
+    }
+
+    #[test]
+    fn resolved_levels() {
+        // "abc אבג 123\nאב"
+        let input: Vec<u16> = "abc אבג 123\nאב".encode_utf16().collect();
+        let paragraphs = resolve_bidi_levels(&input, BaseDirection::Auto);
+        assert_eq!(paragraphs.len(), 2);
+
+        let first = &paragraphs[0];
+        assert_eq!((first.start, first.end, first.level), (0, 11, 0));
+        assert_eq!(first.levels, [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2]);
+        // the number is displayed to the left of the hebrew text
+        assert_eq!(first.runs, [Chunk::new(0, 4, 0), Chunk::new(8, 11, 2), Chunk::new(4, 8, 1)]);
+
+        let second = &paragraphs[1];
+        assert_eq!((second.start, second.end, second.level), (12, 14, 1));
+        assert_eq!(second.levels, [1, 1]);
+        assert_eq!(second.runs, [Chunk::new(12, 14, 1)]);
+    }
+
+    #[test]
+    fn visual_map() {
+        // "ab אב\n\nג"
+        let input: Vec<u16> = "ab אב\n\nג".encode_utf16().collect();
+        let mut visual_map = Vec::<usize>::new();
+        let result = process_bidi_text_with_map(&input, BaseDirection::Auto, &mut visual_map);
+        assert_eq!(result, "ab בא\n\nג".encode_utf16().collect::<Vec<u16>>());
+        assert_eq!(visual_map, [0, 1, 2, 4, 3, 5, 6, 7]);
+    }
+
+    #[test]
+    fn surrogate_pairs_stay_intact() {
+        // alef, Adlam alif (U+1E900), an emoji (U+1F600) and bet
+        let input: &[u16] = &[0x05D0, 0xD83A, 0xDD00, 0xD83D, 0xDE00, 0x05D1];
+        let mut visual_map = Vec::<usize>::new();
+        let result = process_bidi_text_with_map(input, BaseDirection::Auto, &mut visual_map);
+        assert_eq!(result, [0x05D1, 0xD83D, 0xDE00, 0xD83A, 0xDD00, 0x05D0]);
+        assert_eq!(visual_map, [5, 3, 4, 1, 2, 0]);
+        // Adlam starts a right-to-left paragraph
+        let input: &[u16] = &[0xD83A, 0xDD00, 0x0020, 0x61];
+        assert_eq!(resolve_bidi_levels(input, BaseDirection::Auto)[0].level, 1);
+    }
+
+    #[test]
+    fn str_and_chars() {
+        assert_eq!(process_bidi_str("ab אב 😀", BaseDirection::Auto), "ab בא 😀");
+        let input: Vec<char> = "אב 😀 ג".chars().collect();
+        let expected: Vec<char> = "ג 😀 בא".chars().collect();
+        assert_eq!(process_bidi_chars(&input, BaseDirection::Auto), expected);
+    }
 }
@@ -207,6 +207,19 @@ export const U_SHAPE_TEXT_DIRECTION_VISUAL_LTR = 4;
 // ** Bit mask for direction output. Made uniquely for this library. - @CraigglesO
 export const U_SHAPE_DIRECTION_OUTPUT_BIDI = 1 << 20;
 
+// ** Base direction of the bidi output: detect it from the first strong character.
+// Made uniquely for this library.
+export const U_SHAPE_BASE_DIRECTION_AUTO = 0;
+
+// ** Base direction of the bidi output: force left-to-right. Made uniquely for this library.
+export const U_SHAPE_BASE_DIRECTION_LTR = 0x10000000;
+
+// ** Base direction of the bidi output: force right-to-left. Made uniquely for this library.
+export const U_SHAPE_BASE_DIRECTION_RTL = 0x20000000;
+
+// ** Bit mask for the base direction of the bidi output. Made uniquely for this library.
+export const U_SHAPE_BASE_DIRECTION_MASK = 0x30000000;
+
 // ** Bit mask for direction indicators. @stable ICU 2.
 export const U_SHAPE_TEXT_DIRECTION_MASK = 4;
 
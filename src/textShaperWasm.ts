@@ -5,6 +5,10 @@ import wasmBase64 from './uShaper.wasm.js';
 type WasmFree = (ptr: number, len: number) => void;
 /** WASM Process Text Command */
 type WasmProcessText = (ptr: number, len: number, options: number) => void;
+/** WASM Try Process Text Command */
+type WasmTryProcessText = (ptr: number, len: number, options: number) => number;
+/** WASM Joining Forms Command */
+type WasmJoiningForms = (ptr: number, len: number) => void;
 /** WASM Alloc Sentinel Command */
 type WasmAllocSentinel = (size: number) => number;
 /** WASM Is Right-to-Left Command */
@@ -12,6 +16,12 @@ type WasmIsRTL = (unicode: number) => number;
 /** WASM Is Chinese-Japanese-Korean Command */
 type WasmIsCJK = (unicode: number) => number;
 
+/** The OpenType feature selecting the glyph of a joining character */
+export type JoiningForm = 'isol' | 'fina' | 'init' | 'medi';
+
+/** The joining forms in the order of the codes returned by the WASM joiningForms */
+const JOINING_FORMS: (JoiningForm | undefined)[] = [undefined, 'isol', 'fina', 'init', 'medi'];
+
 /**
  * WASM based text shaper
  */
@@ -59,6 +69,44 @@ export class WasmTextShaper {
     return this.tmpString;
   }
 
+  /**
+   * Converts a string into a shaped string, rejecting invalid options and malformed input
+   * @param str - input string
+   * @param options - shaping options
+   * @returns - shaped string, or undefined if the options or input are invalid
+   */
+  tryShapeString(str: string, options = DEFAULT_OPTIONS): string | undefined {
+    const tryProcessText = this.instance.exports.tryProcessText as WasmTryProcessText;
+    const free = this.instance.exports.free as WasmFree;
+
+    if (str.length === 0) return str;
+
+    const len = str.length;
+    const ptr = this.#putString(str);
+    const ok = tryProcessText(ptr, len, options) === 1;
+    free(ptr, len);
+    return ok ? this.tmpString : undefined;
+  }
+
+  /**
+   * Get the joining form of every code unit of a logical string without shaping it, for fonts
+   * with their own `isol`, `fina`, `init` and `medi` glyphs
+   * @param str - input string
+   * @returns - the joining form of every code unit, undefined for non joining characters
+   */
+  joiningForms(str: string): (JoiningForm | undefined)[] {
+    const joiningForms = this.instance.exports.joiningForms as WasmJoiningForms;
+    const free = this.instance.exports.free as WasmFree;
+
+    if (str.length === 0) return [];
+
+    const len = str.length;
+    const ptr = this.#putString(str);
+    joiningForms(ptr, len);
+    free(ptr, len);
+    return Array.from(this.tmpString, (form) => JOINING_FORMS[form.charCodeAt(0)]);
+  }
+
   /**
    * Check if a character is a "right-to-left" unicode character
    * @param unicode - input unicode character
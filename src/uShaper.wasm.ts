@@ -1 +1 @@
-export default 'AGFzbQEAAAABMghgAX8AYAF/AX9gAn9/AGACf38Bf2ADf39/AGADf39/AX9gBH9/f38AYAZ/f39/f38AAhcBA2Vudg9zZXRVbmljb2RlQXJyYXkAAgM1NAQEBAUBBwEDAQECAQQEBgYBAgECBgEEAgQGBgICBAQCBgQCAQYGBAYFBQUCAgQCAgIAAAEFAwEAEQYZA38BQYCAwAALfwBB6ZXAAAt/AEHwlcAACwdeCAZtZW1vcnkCAAtwcm9jZXNzVGV4dAABEWFsbG9jVW5pY29kZUFycmF5ACQEZnJlZQAjBWlzUlRMADQFaXNDSksAFgpfX2RhdGFfZW5kAwELX19oZWFwX2Jhc2UDAgrshQE0wUYCE38BfiMAQYADayIDJAAgA0HgAGogACABEBkCQAJAIAJBGHEiCEUNACADKAJkIQ0gAygCaCEEIANBADYClAEgA0KAgICAIDcCjAFBAiELAkAgAkGAgAFxRQRAIA0hBwwBBQsgBEEBdCIGBEAgA0GMAWogBhAYIAZBAWshASADKAKQASILIAMoApQBIgVBAXRqIQADfyAAQQA7AQAgAQR/IAFBAWshASAAQQJqIQAMAQUgBSAGagsLIQ4FCyADIA42ApQBQX9BASACQQRxIglBAnYiBxshD0EAIQUgBkEAIAcbIQAgBEEAIAcbIQEgBCAHayEQIAJBmIABcUGYgAFGIRFBACEHQQEhDANAAn8gASAQRgRAIAUgAEEAIAkbIgBJIAUgDktyDQUgBSAAayEEIAsgAEEBdGohBwwDBQsgASAETw0EIBFFIAwgByANIAFBAXRqLwEAIgYQESIHckHAAXFBwAFGcUVyRQRAIAsgDiAAECsgCkH//wNxIgcgBiAGIAdLG0HuE2s7AQAgACAOTw0FQQAhDCALIABBAXRqLwEAEBEhByAPDAEFCyALIA4gABArIAY7AQAgBUEBaiEFIAkEQCAAQQFrIQBBASEMIAYhCkF/DAEFCyAAQQFqIQAgBiEKQQEhDEEBCyABaiEBDAALAAsCQAJAAkAgCEEYRwRAIAQhASAIQQhHDQIgAkGDgARxRSACQYCAOHFBgIAgRnINAQwCBQsgBCEBIAJBg4AEcQ0CCyAEQQFrIQUgAkEEcQRAQQAhACAHIQYDQCAAIARGDQICQAJAAkAgBi8BACIJQaIMayIKQQVLQQEgCnRBK3FFciAAIAVPckUEQCAGQQJqLwEAQcQMRg0BBQsgAUUgCUHw/wNxQfD8A0dyDQIMAQsgAQ0AQQAhAQwBCyABQQFrIQELIAZBAmohBiAAQQFqIQAMAAsABQtBACEAIAchBgNAIAAgBEYNAQJAAkACQAJAIAYvAQAiCUHEDEcgACAFT3INACAGQQJqLwEAQaIMayIKQQVLDQBBASAKdEErcQ0BCyAJQfD/A3FB8PwDRw0CIAENAQwCCyABDQBBACEBDAELIAFBAWshAQsgBkECaiEGIABBAWohAAwACwALIAJBm4AEcUEQRw0AIAQhACAHIQYDQCAARQ0BIAEgAUEARyAGLwEAQYsCakH//wNxQQhJcWohASAAQQFrIQAgBkECaiEGDAALAAsgA0GYAWoiACABIAQgASAESxsQICAAIAcgBBAiQQAhACADQQA2AnAgA0EANgJ0IAJBBHEiB0UEQCADKAKcASIEIAMoAqABIgYgA0HwAGogA0H0AGoQDyAEIAYgAygCcCADKAJ0ECEFCwJAAkAgCEEIRwRAIAhBGEYNASADKAKgASEFIAMoApwBIQYMAgULIAJBgIA4cSIEQYCAMEcEQEECIQAgBA0BBQsgAygCnAEiBiADKAKgASIFQQEQAyAEQYCAMEcNASAFIQQgBiEJA0AgBEUNAgJAAkACfwJAAkACQCAJLwEAIgBB8P8DcUHw/ANGBEAgAEHz/ANrIgoOAwMCAwEFCyAAQfL5A0kNAkH9/AMhCCAAQf38A0YgAEH1+QNJcg0EDAILIAAiCEH9/ANGDQMLQf38AyEIAkACQCAAQZADakH//wNxQYCAQGstAAAiDEEBaw4CAAQBC0HADCEIDAMLAkAgCg4DAQABAAtBASAMa0H/AXEMAQsgAEGiB2pB//8DcUEGSQtFIABB/PwDRnINAUEgIQgLIAkgCDsBAAsgBEEBayEEIAlBAmohCQwACwALIAMoApwBIgYgAygCoAEiBSAAEAMLIAdFBEAgBiAFIANB8ABqIANB9ABqEA8gBiAFIAMoAnAgAygCdBAhBQsgA0GoAWogARAgIAVBAXQhAEEAIQEgAygCmAEhBANAIAAgAUcEQCABIAZqLwEAIgVB/f8DTQRAIANBqAFqIAUQFAULIAFBAmohAQwBBQsLIAQgBhAvIANBiAFqIgAgA0GwAWooAgA2AgAgAyADKQKoATcDgAEgAygCjAEgCxAvIAMoAmAgDRAvIANB6ABqIAAoAgAiCjYCACADIAMpA4ABNwNgIAMoAmQhDCADQYABaiAKECBBACEHIANBADYCsAEgA0KAgICAwAA3AqgBIApBAWshBiAMQQJqIQhBBCELQQAhBQJAA0ACQCAFIApJBEAgCCAFQQF0IgBqIQEgACAMaiIJLwEAEAxB/wFxIQQgBSEAA0AgACAGRgRAIAohAAwDBQsgAEEBaiEAIAEvAQAhDSABQQJqIQEgBCANEAxB/wFxIg1GIA1BAkZyDQALDAEFCyADKAKoASEQIAMoAqwBIQtBACEEIANBADYClAEgA0KAgICAwAA3AowBIAtBCGohCEEAIQACQANAIAggBEEMbCIJaiEGA0AgBiEBIAcgACIFRgRAIAQgB0kEQCADQagBaiIAIAsgBEEMbGogByAEaxANIANBADYCtAEgA0GMAWogABASBQsgAygCkAEiBSADKAKUASINQQR0aiERIAMoAowBIRQgBSEAA0AgACARRwRAIAAoAggiB0EBayEPQQghCSAHIgFBDGwiBkEMayISIQgDQAJAIAFFBEAgA0GoAWogBxAgIAAoAgQhAQNAIAZFDQIgA0GoAWogASgCACABKAIEECIgBkEMayEGIAFBDGohAQwACwAFCyAJIAAoAgQiBGoiDi0AAEEIRgRAIANBoAFqIhUgDigCADYCACADIA5BCGsiEykCADcDmAEgEyAOQQRqIAgQAiAAIA82AgggACgCACAPRgRAIAAQMiAAKAIEIQQFCyAPBEAgBEEMaiAEIBIQAgULIAQgAykDmAE3AgAgACAHNgIIIARBCGogFSgCADYCAAULIAFBAWshASAIQQxrIQggCUEMaiEJDAELCyADQYABaiADQagBahAsIAMoAqgBIAMoAqwBEC8gACgCDCIBBEAgA0GAAWogAS8BABAUBQsgAEEQaiEADAEFCwsgA0HYAGogAygChAEiACADKAKIASAKECggDCAKIAMoAlggAygCXBAmIAUhAQNAIA0EQCABKAIAIAFBBGooAgAQMCANQQFrIQ0gAUEQaiEBDAEFCwsgFCAFQRAQLiAQIAsQMCADKAKAASAAEC8gAygCZCEMIANBgAFqIAMoAmgiChAgQQAhByADQQA2ArABIANCgICAgMAANwKoASAMQQJqIQYgCkEBayEIQQQhC0EAIQUCQANAAkAgBSAKSQRAIAYgBUEBdCIAaiEBIAAgDGoiCS8BABAHQf8BcSEEIAUhAANAIAAgCEYEQCAKIQAMAwULIABBAWohACABLwEAIQ0gAUECaiEBIAQgDRAHQf8BcSINRiANQQRGcg0ACwwBBQsgAygCqAEhESADKAKsASELQQAhBCADQQA2ApQBIANCgICAgMAANwKMASALQQhqIQhBACEGAkADQCAIIARBDGwiCWohAANAIAAhASAHIAYiBUYEQCAEIAdJBEAgA0GoAWoiACALIARBDGxqIAcgBGsQDSADQQA2ArQBIANBjAFqIAAQEgULIAMoApABIgUgAygClAEiDUEEdGohFCADKAKMASESIANBoAFqIRAgBSEAA0AgACAURwRAIAAoAggiB0EBayEPQQghCSAHIgFBDGwiBkEMayIVIQgDQAJAIAFFBEAgA0GoAWogBxAgIAAoAgQhAQNAIAZFDQIgA0GoAWogASgCACABKAIEECIgBkEMayEGIAFBDGohAQwACwAFCyAJIAAoAgQiBGoiDi0AAEEPRgRAIBAgDigCADYCACADIA5BCGsiEykCADcDmAEgEyAOQQRqIAgQAiAAIA82AgggACgCACAPRgRAIAAQMiAAKAIEIQQFCyAPBEAgBEEMaiAEIBUQAgULIAQgAykDmAE3AgAgACAHNgIIIARBCGogECgCADYCAAULIAFBAWshASAIQQxrIQggCUEMaiEJDAELCyADQYABaiADQagBahAsIAMoAqgBIAMoAqwBEC8gACgCDCIBBEAgA0GAAWogAS8BABAUBQsgAEEQaiEADAEFCwsgA0HQAGogAygChAEiACADKAKIASAKECggDCAKIAMoAlAgAygCVBAmIAUhAQNAIA0EQCABKAIAIAFBBGooAgAQMCANQQFrIQ0gAUEQaiEBDAEFCwsgEiAFQRAQLiARIAsQMCADKAKAASAAEC8gAygCZCEHIANBjAFqIAMoAmgiChAgIANBADYCsAEgA0KAgICAwAA3AqgBQQAhAQJAA0AgASAKSQRAIAFBAWohACAHIAFBAXRqIQQCQCAKIAFBAmoiBk0NACAAIApPDQMgByAAQQF0ai8BAEG6IEcNACADQagBaiAEIAQvAQAgByAGQQF0ai8BAEG5IEYiBBAIQf8BcRAfIAFBA2ogACAEGyEBDAILIANBqAFqIAQgBC8BAEEAEAhB/wFxEB8gACEBDAEFCwsgAygCsAEhBSADKAKoASEPIAMoAqwBIQ1BACEIIANBADYCoAEgA0KAgICAwAA3ApgBIA1BBGohCUEAIQADQCAJIAhBA3QiC2ohBgNAIAYhASAFIAAiBEYEQCAFIAhLBEAgA0GoAWoiACANIAhBA3RqIAUgCGsQDiADQQA2ArQBIANBmAFqIAAQEgULIAMoApwBIgYgAygCoAEiDEEEdGohDiADKAKYASEQIAYhBQNAIAUgDkcEQEEAIQQgBUEIaiERA0AgBCARKAIAIglPBEAgA0GoAWogCRAgIAlBA3QhACAFQQRqKAIAIQEDQCAABEACQCABLQAEQQJGBEAgA0G6oOSBATYBmgEgAyABKAIALwEAOwGYASADQagBaiADQZgBakEDECIMAQULIANBqAFqIAEoAgAvAQAQFAsgAUEIaiEBIABBCGshAAwBBQsLIANBjAFqIANBqAFqECwgAygCqAEgAygCrAEQLyAFKAIMIgAEQCADQYwBaiAALwEAEBQFCyAFQRBqIQUMAwUCQAJAAkACQAJAIAVBBGooAgAiCyAJIAQQKi0ABA4HAgQAAwQEAQQLIAkgBEEBaiIATQ0DIAsgCSAEIAAQGiAAIQQMAwsgA0HIAGogBSAEEBcgBUEAIAMoAkggAy0ATBAVDAILIARBAWshASAEAn8DQEEBIAFFDQEaIAsgCSABECohACABQQFrIQEgAC0ABEEFRg0ACyABQQJqCyIARg0BIAsgCSAAIAQQGgwBCyAJQQFrIQhBACEBAkADQCABIAhGDQEgCyAJIAEQKiEAIAFBAWohASAALQAEQQFHDQALIAFBAWshCAsgBCAIRg0AIANBQGsgBSAEEBcgBSAIIAMoAkAgAy0ARBAVCyAEQQFqIQQMAQsACwAFCwsgA0E4aiADKAKQASIAIAMoApQBIAoQKCAHIAogAygCOCADKAI8ECYgBiEBA0AgDARAIAEoAgAgAUEEaigCABAxIAxBAWshDCABQRBqIQEMAQULCyAQIAZBEBAuIA8gDRAxIAMoAowBIAAQL0EAIQQDQCAEIgAgCkkEQEEBIQQgAEUNASAAQQFqIQRBaiEBIAcgAEEBdGovAQAhBgNAIAFFDQIgAUH2hsAAaiEFIAFBAmohASAFLwEAIAZHDQALIAcgCiAAQQFrIAAQGwwBBQsLIANBjAFqIAoQICADQoCAgIDAADcCqAFBACEBQQQhBiAHIQBBBCEIA0AgAyABNgKwAQJAAkAgASAKRwRAQQAhCSAALwEAIgRBwB5rQf//A3FBLUkgBEGIH2tB//8DcUEFSXINAkEBIQkCQAJAAkAgBEHxHmsOBAQFAQQACwJAIARBgB9rDgIFAQALIARB//8DRg0BIARB+h5rQf//A3FBBEkNBEEEIQkgBEH1HmtB//8DcUEFTw0EC0EDIQkMAwtBBSEJDAIFCyADKAKoASEQIAMoAqwBIQtBACEEIANBADYCoAEgA0KAgICAwAA3ApgBIAtBBGohCEEAIQADQCAIIARBA3QiCWohBgNAIAYhASAKIAAiBUYEQCAEIApJBEAgA0GoAWoiACALIARBA3RqIAogBGsQDiADQQA2ArQBIANBmAFqIAAQEgULIAMoApwBIgQgAygCoAEiDUEEdGohESADKAKYASEUIAQhBQNAIAUgEUcEQCAFQRBqIQYgBUEIaiIOKAIAIgBBAWshCEEAIQkDQAJAAkAgACAJRgRAIANBqAFqIAAQICAAQQN0IQEgBUEEaigCACEAA0AgAUUNAiADQagBaiAAKAIALwEAEBQgAUEIayEBIABBCGohAAwACwAFCyAFQQRqIhIoAgAiDCAAIAkiARAqLQAEQQFrQf8BcUECSw0BA0ACQAJAIAFFBEBBACEBDAEFCyAMIAAgARAqLQAEDQELIAwgCUEDdGoiDy0ABCEVIA8oAgAhEyAPIA9BCGogACAJQX9zakEDdBACIA4gCDYCACABIAhLDSAgBSgCACAIRgR/IAUQMyASKAIABSAMCyABQQN0aiEMIAEgCEkEQCAMQQhqIAwgCCABa0EDdBACBQsgDCAVOgAEIAwgEzYCACAOIAA2AgAMAwsgAUEBayEBDAALAAsgA0GMAWogA0GoAWoQLCADKAKoASADKAKsARAvIAUoAgwiAARAIANBjAFqIAAvAQAQFAULIAYhBQwDCyAJQQFqIQkMAAsABQsLIANBMGogAygCkAEiACADKAKUASAKECggByAKIAMoAjAgAygCNBAmIAQhAQNAIA0EQCABKAIAIAFBBGooAgAQMSANQQFrIQ0gAUEQaiEBDAEFCwsgFCAEQRAQLiAQIAsQMSADKAKMASAAEC8gAygCZCEMIANBgAFqIAMoAmgiChAgQQAhByADQQA2ArABIANCgICAgMAANwKoASAMQQJqIQYgCkEBayEIQQQhC0EAIQUCQANAAkAgBSAKSQRAIAYgBUEBdCIAaiEBIAAgDGoiCS8BABAFQf8BcSEEIAUhAANAIAAgCEYEQCAKIQAMAwULIABBAWohACABLwEAIQ0gAUECaiEBIAQgDRAFQf8BcSINRiANQRJGcg0ACwwBBQsgAygCqAEhESADKAKsASELQQAhBCADQQA2ApQBIANCgICAgMAANwKMASALQQhqIQhBACEAAkADQCAIIARBDGwiCWohBgNAIAYhASAHIAAiBUYEQCAEIAdJBEAgA0GoAWoiACALIARBDGxqIAcgBGsQDSADQQA2ArQBIANBjAFqIAAQEgULIAMoApABIgUgAygClAEiDUEEdGohFCADKAKMASESIANBoAFqIRAgBSEAA0AgACAURwRAIAAoAggiB0EBayEPQQghCSAHIgFBDGwiBkEMayIVIQgDQAJAIAFFBEAgA0GoAWogBxAgIAAoAgQhAQNAIAZFDQIgA0GoAWogASgCACABKAIEECIgBkEMayEGIAFBDGohAQwACwAFCyAJIAAoAgQiBGoiDi0AAEEGRgRAIBAgDigCADYCACADIA5BCGsiEykCADcDmAEgEyAOQQRqIAgQAiAAIA82AgggACgCACAPRgRAIAAQMiAAKAIEIQQFCyAPBEAgBEEMaiAEIBUQAgULIAQgAykDmAE3AgAgACAHNgIIIARBCGogECgCADYCAAULIAFBAWshASAIQQxrIQggCUEMaiEJDAELCyADQYABaiADQagBahAsIAMoAqgBIAMoAqwBEC8gACgCDCIBBEAgA0GAAWogAS8BABAUBQsgAEEQaiEADAEFCwsgA0EoaiADKAKEASIAIAMoAogBIAoQKCAMIAogAygCKCADKAIsECYgBSEBA0AgDQRAIAEoAgAgAUEEaigCABAwIA1BAWshDSABQRBqIQEMAQULCyASIAVBEBAuIBEgCxAwIAMoAoABIAAQLwwfBQsgAUEMaiEGIAVBAWohACABLQAAQRVrQf8BcUEBSw0ACyAEIAVLDQEgA0GYAWogCSALaiAFIARrEA0gAUEIayIBKAIEBEAgA0GwAWogA0GgAWooAgA2AgAgAyADKQKYATcDqAEgAyABKAIANgK0ASADQYwBaiADQagBahASIAAhBAwBBQsLDB0LDBwLIAAgBU8EQCAAIApLDQIgAygCqAEgB0YEQCADQagBahAyIAMoAqwBIQsFCyALIAdBDGxqIgEgBDoACCABIAAgBWs2AgQgASAJNgIAIAEgAy8AmAE7AAkgAUELaiADQZoBai0AADoAACADIAdBAWoiBzYCsAEgACEFDAEFCwsMGgsMGQULIAFBCGohBiAFQQFqIQAgAS0AAEEFRw0ACyAEIAVNBEAgA0GoAWoiBiAJIAtqIAUgBGsQDiADIAFBBGsoAgA2ArQBIANBmAFqIAYQEiAAIQQMAQULCwwWC0ECIQkLIAMoAqgBIAFGBEAgA0GoAWoQMyADKAKsASEIBQsgBiAIaiIEIAk6AAAgBEEEayAANgIAIAZBCGohBiAAQQJqIQAgAUEBaiEBDAALAAULIAFBCGohBiAEQQFqIQAgAS0AAEEHRw0ACyAEIAhPBEAgA0GoAWoiBiALIA1qIAQgCGsQDiADIAFBBGsoAgA2ArQBIANBmAFqIAYQEiAAIQgMAQULCwwQCwwPBQsgAUEMaiEAIAVBAWohBiABLQAAQRNrQf8BcUEBSw0ACyAEIAVLDQEgA0GYAWogCSALaiAFIARrEA0gAUEIayIAKAIEBEAgA0GwAWogA0GgAWooAgA2AgAgAyADKQKYATcDqAEgAyAAKAIANgK0ASADQYwBaiADQagBahASIAYhBAwBBQsLDAwLDAsLIAAgBU8EQCAAIApLDQIgAygCqAEgB0YEQCADQagBahAyIAMoAqwBIQsFCyALIAdBDGxqIgEgBDoACCABIAAgBWs2AgQgASAJNgIAIAEgAy8AmAE7AAkgAUELaiADQZoBai0AADoAACADIAdBAWoiBzYCsAEgACEFDAEFCwsMCQsMCAULIAFBDGohBiAFQQFqIQAgAS0AAEEOcUEMRw0ACyAEIAVLDQEgA0GYAWogCSALaiAFIARrEA0gAUEIayIBKAIEBEAgA0GwAWogA0GgAWooAgA2AgAgAyADKQKYATcDqAEgAyABKAIANgK0ASADQYwBaiADQagBahASIAAhBAwBBQsLDAULDAQLIAAgBU8EQCAAIApLDQIgAygCqAEgB0YEQCADQagBahAyIAMoAqwBIQsFCyALIAdBDGxqIgEgBDoACCABIAAgBWs2AgQgASAJNgIAIAEgAy8AmAE7AAkgAUELaiADQZoBai0AADoAACADIAdBAWoiBzYCsAEgACEFDAEFCwsMAgsMAQsCQCACQYCAwABxRQRAIANBsAFqIANB6ABqKAIANgIAIAMgAykDYDcDqAEMAQULIAMoAmQhDyADKAJoIRFBACEAIANBADYCfCADQoCAgIAgNwJ0IANBADYCiAEgA0KAgICAwAA3AoABIA8gEUEBdGohBiAPIQEDQCAAIQIDQCAAIQQgASAGRgRAIAIgEUkEQCADQYABaiACIBEQHgULIAMoAoQBIhAgAygCiAFBA3RqIRVBACECA0ACQAJAIBAgFUcEQCAQKAIEIgUgECgCACIETwRAIAUgEU0EQCADQQA2ApQBIANCgICAgMAANwKMAQJAAkACQCAEIAVHBEBBACEGQQAhCiAPIARBAXQiAGoiDS8BABAKQf8BcSIHIQwgB0EBaw4DAQEDAgULDA0LIAVBAXQgAGshASANIQADQAJAIAFFBEBBAyEMDAEFCyAALwEAEApB/wFxIghFDQIgAUECayEBIABBAmohAEEDIQwgCEEDRw0BCwsMAQtBACEMQQEhCgsgAkEBaiELIBBBCGohECAFIARrIRQgDCAHIAdBAWtBAkkbIQQgDyAFQQF0aiEIIA0hAUEAIQUDQCABIAhHBEAgAS8BACEHIAYiAEEBaiEGIAFBAmohASAHEApB/wFxIgcgBEH/AXFGDQEgAyAEOgCwASADIAA2AqwBIAMgBTYCqAEgA0GMAWogA0GoAWoQHCAHIQQgACEFDAEFCwsgBSAURwRAIAMgBDoAsAEgAyAUNgKsASADIAU2AqgBIANBjAFqIANBqAFqEBwFCyADKAKUASIBQQFrIQ5BACEGIAMoApABIQdBACEEA0AgASAEIgBGBEBBACEGAkADQCAGIA4gBiAOShshBSABQQFrIQQCQANAIAYiACAFRwRAIAcgASAAECktAAggByABIABBAWoiBhApIggtAAhHDQEMAgULCyADIAE2ApQBIApFDQkgA0EgaiABQQF2IgAgByAAECVBACEFIAMoAiQhBiADKAIgIQQgA0EYaiAAIAcgAUEMbGogAEF0bGogABAlIAMoAhggAEEMbGpBDGshCCADKAIcIQcDQCAAIAVGDQkCQCAFIAZHBEAgByAAIAVBf3NqSwRAQQAhAQNAIAFBDEYNAyABIARqIgkoAgAhCiAJIAEgCGoiCSgCADYCACAJIAo2AgAgAUEEaiEBDAALAAULDBIFCwwRCyAIQQxrIQggBEEMaiEEIAVBAWohBQwACwALIAAgAUkEQCAHIABBDGxqIAgoAgQ2AgQgASAGTQ0CIAcgBkEMbGoiBiAGQQxqIAEgAGtBDGxBGGsQAiAOQQFrIQ4gBCEBIAAhBgwBBQsLDA0LDAwFC0EEIQhBBCEJIAAEQCAGIAdqQQRrLQAAIQkFCyABIABBAWoiBEsEQCAGIAdqQRRqLQAAIQgFCwJAAkAgBiAHaiISQQhqIgUtAAAiE0ECRwRAIABFIBNBAUdyDQECQCAJQf8BcQ4FAAICAgMCCyAKDQEgEkEMayIAKQIAIRYgACASKQIANwIAIBIgFjcCACAAQQhqIgAoAgAhCCAAIAUoAgA2AgAgBSAINgIADAEFCyAARQRAIAhB/wFxQQRGDQIgBSAIOgAADAEFCyAAIA5GDQAgCUH/AXEiAEEERg0BIAhB/wFxIghBBEYNASAAIAhHBEAgBSAMOgAADAEFCyAFIAk6AAALIAZBDGohBgwBCwsDQAwACwAFCwwJBQsMCAULIANBqAFqIAMoAngiACADKAJ8EBkgAygCgAEgAygChAFBCBAuIAMoAnQgABAvIAMoAmAgAygCZBAvDAYLIAMoApQBIQEgAygCkAEhBwsgByABQQxsaiEKIAMoAowBIQ4gByEMA0ACQCAKIAxHBEAgDC0ACCIEQQRHDQEFCyAOIAdBDBAuIAMoAogBQQFrIAJHBEAgA0H0AGpBChAUBQsgCyECDAILAkAgDCkCACIWQiCIpyIAIBanIgFPBEAgACAUTQRAIANBmAFqIA0gAUEBdGogACABaxAZIAMoAqABIQggAygCnAEiBSEBIAQNAiADQRBqIAhBAXYiASAFIAEQJSADKAIQIQAgA0EIaiABIAUgCEEBdGoiEiAIQX5xIgRrIAEQJSABQQFrIQEgAygCCCAEakECayEGIAMoAgwhBCADKAIUIQkCQAJAA0AgAUF/Rg0BIAlFDQIgASAESQRAIAAvAQAhEyAAIAYvAQA7AQAgBiATOwEAIAlBAWshCSAAQQJqIQAgBkECayEGIAFBAWshAQwBBQsLDAsLIAUhBANAAkAgBCASRwRAIANBqAFqQfiGwABBwAEQBBogA0KAgICAgAY3AugCIARBAmohAEEAIQEgBC8BACEJA0AgAUEEaiIGQcQBRg0CIANBqAFqIAFqIRMgBiEBIAkgEygCACIGQf//A3FHDQALIAQgBkEQdjsBAAwBBQsgAygCnAEhAQwFCyAAIQQMAAsACwwJBQsMCAULDAcLIAxBDGohDCADQfQAaiAFIAgQIiADKAKYASABEC8MAAsACwAFCyABLwEAIQUgBEEBaiEAIAFBAmohAQJAIAVBCmsOBAABAQABCwsgAiAERg0AIANBgAFqIAIgBBAeDAALAAsgAygCrAEiACADKAKwARAAIAMoAqgBIAAQLyADQYADaiQADwsDQAwACwALwQkBB38CQAJAIAIiBSAAIgMgAWtLBEAgASAFaiEAIAMgBWohAyAFQRBJDQFBACADQQNxIgZrIQgCQCADIANBfHEiBE0NACAGQQFrIQkCQCAGRQRAIAAhAgwBBQsgBiEHIAAhAgNAIANBAWsiAyACQQFrIgItAAA6AAAgB0EBayIHDQALCyAJQQNJDQAgAkEEayECA0AgA0EBayACQQNqLQAAOgAAIANBAmsgAkECai0AADoAACADQQNrIAJBAWotAAA6AAAgA0EEayIDIAItAAA6AAAgAkEEayECIAMgBEsNAAsLIAQgBSAGayIFQXxxIgJrIQNBACACayEGAkAgACAIaiIAQQNxBEAgAyAETw0BIABBA3QiAkEYcSEHIABBfHEiCEEEayEBQQAgAmtBGHEhCSAIKAIAIQIDQCAEQQRrIgQgAiAJdCABKAIAIgIgB3ZyNgIAIAFBBGshASADIARJDQALDAEFCyADIARPDQAgASAFakEEayEBA0AgBEEEayIEIAEoAgA2AgAgAUEEayEBIAMgBEkNAAsLIAVBA3EhBSAAIAZqIQAMAQULIAVBEE8EQAJAIAMgA0EAIANrQQNxIgZqIgJPDQAgASEEIAYEQCAGIQADQCADIAQtAAA6AAAgBEEBaiEEIANBAWohAyAAQQFrIgANAAsFCyAGQQFrQQdJDQADQCADIAQtAAA6AAAgA0EBaiAEQQFqLQAAOgAAIANBAmogBEECai0AADoAACADQQNqIARBA2otAAA6AAAgA0EEaiAEQQRqLQAAOgAAIANBBWogBEEFai0AADoAACADQQZqIARBBmotAAA6AAAgA0EHaiAEQQdqLQAAOgAAIARBCGohBCACIANBCGoiA0cNAAsLIAIgBSAGayIFQXxxIgdqIQMCQCABIAZqIgBBA3EEQCACIANPDQEgAEEDdCIEQRhxIQYgAEF8cSIIQQRqIQFBACAEa0EYcSEJIAgoAgAhBANAIAIgBCAGdiABKAIAIgQgCXRyNgIAIAFBBGohASADIAJBBGoiAksNAAsMAQULIAIgA08NACAAIQEDQCACIAEoAgA2AgAgAUEEaiEBIAMgAkEEaiICSw0ACwsgBUEDcSEFIAAgB2ohAQULIAMgAyAFaiIATw0BIAVBB3EiBARAA0AgAyABLQAAOgAAIAFBAWohASADQQFqIQMgBEEBayIEDQALBQsgBUEBa0EHSQ0BA0AgAyABLQAAOgAAIANBAWogAUEBai0AADoAACADQQJqIAFBAmotAAA6AAAgA0EDaiABQQNqLQAAOgAAIANBBGogAUEEai0AADoAACADQQVqIAFBBWotAAA6AAAgA0EGaiABQQZqLQAAOgAAIANBB2ogAUEHai0AADoAACABQQhqIQEgACADQQhqIgNHDQALDAELIAMgAyAFayICTQ0AIAVBA3EiAQRAA0AgA0EBayIDIABBAWsiAC0AADoAACABQQFrIgENAAsFCyAFQQFrQQNJDQAgAEEEayEBA0AgA0EBayABQQNqLQAAOgAAIANBAmsgAUECai0AADoAACADQQNrIAFBAWotAAA6AAAgA0EEayIDIAEtAAA6AAAgAUEEayEBIAIgA0kNAAsLC9UGAQt/IAFBAWshCiABBEBBfiEEIAAgCkEBdGovAQAQESEHIAohAwJAA0ACQAJAIAdB//8DcUH/AUsNACABIANNDQMgACADQQF0ai8BABARQQRxDQAgByEGIAQhCCADIQUMAQsgA0EBayEGAn8CQAJAA0AgBCIIQQBOBEAgB0EgcUUgCUEQcUVyDQMgASADTQ0HQQAhBCAAIANBAXRqIgYvAQBBogxrIgdB//8DcSIJQQZPBEAgAyEFDAMFCyADIQVBKyAHdkEBcUUNAiAGQf//AzsBACABIApLBEAgACAKQQF0aiAJQQF0QdiVwABqLwEAIgQ7AQAgCiEFDAMFCwNADAALAAULIAYhBUF/IQZBuBchBEEAIQsgBUF/Rg0AIAEgBUsEQCAIIAUgACAFQQF0ai8BABARIgtBBHFBAnYiCBshBCAFIAhrIQYMAQULCwNADAALAAsgDSEJIAQQEQwBCyADIQUgBwsiBkEDcSIDIAtBA3FBBHQgCUEDcUECdHJyQZ+GwABqLQAAIQQCfwJAAkACQCADQQFGBEAgBEEBcSEEDAEFCyABIAVNDQEgACAFQQF0ai8BACIDQcsMa0H//wNxQQhPDQACQCAJQQJxQQAgC0EBcRtFBEBBACEEIAJB/wFxQQJHDQIMAQULQQAhBAJAIAJB/wFxQQFrDgIAAQILIANB3gxxQcwMRg0BIAtBIHFFIAlBEHFFciEEDAELIANB0QxGIQQLIAEgBU0NACAAIAVBAXRqIgcvAQAiA0GADHNBgAJPDQMgA0HLDGtB//8DcSIMQQhPBEAgBkEIcQ0CIAZBBHEgBkH//wNxQYACSXINBCAGQYD+A3FBCHYgBGpBkANrDAMFCyACQf8BcUECRgRAQf7/AyADQdEMRw0DGgULIAQgDEGXhsAAai0AAGpBkANrDAILA0AMAAsACyAGQYD+A3FBCHYgBGpBsAlrCyEDIAcgAzsBAAsgBSAFQQFrIgwgDEEASBshA0F+IQQgCyEHAkAgCCAMRwRAIAVFDQEgASADTQ0DIAAgA0EBdGovAQAQESEHIAghBAULIAogBSAGQQRxIggbIQogDSAJIAgbIQ0gCSAGIAgbIQkgBQ0BCwsPCwNADAALAAULA0AMAAsAC48FAQh/AkAgAkEQSQRAIAAhAwwBBQsCQCAAIABBACAAa0EDcSIFaiIGTw0AIAAhAyABIQQgBQRAIAUhBwNAIAMgBC0AADoAACAEQQFqIQQgA0EBaiEDIAdBAWsiBw0ACwULIAVBAWtBB0kNAANAIAMgBC0AADoAACADQQFqIARBAWotAAA6AAAgA0ECaiAEQQJqLQAAOgAAIANBA2ogBEEDai0AADoAACADQQRqIARBBGotAAA6AAAgA0EFaiAEQQVqLQAAOgAAIANBBmogBEEGai0AADoAACADQQdqIARBB2otAAA6AAAgBEEIaiEEIAYgA0EIaiIDRw0ACwsgBiACIAVrIgJBfHEiB2ohAwJAIAEgBWoiBUEDcQRAIAMgBk0NASAFQQN0IgRBGHEhCCAFQXxxIglBBGohAUEAIARrQRhxIQogCSgCACEEA0AgBiAEIAh2IAEoAgAiBCAKdHI2AgAgAUEEaiEBIAMgBkEEaiIGSw0ACwwBBQsgAyAGTQ0AIAUhAQNAIAYgASgCADYCACABQQRqIQEgAyAGQQRqIgZLDQALCyACQQNxIQIgBSAHaiEBCwJAIAMgAiADaiIFTw0AIAJBB3EiBARAA0AgAyABLQAAOgAAIAFBAWohASADQQFqIQMgBEEBayIEDQALBQsgAkEBa0EHSQ0AA0AgAyABLQAAOgAAIANBAWogAUEBai0AADoAACADQQJqIAFBAmotAAA6AAAgA0EDaiABQQNqLQAAOgAAIANBBGogAUEEai0AADoAACADQQVqIAFBBWotAAA6AAAgA0EGaiABQQZqLQAAOgAAIANBB2ogAUEHai0AADoAACABQQhqIQEgBSADQQhqIgNHDQALCyAAC74EAQJ/AkACQAJAAkACQCAAQfz/A3FBhC9GDQACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCAAQf//A3EiAUGAL2sOXg0NDQIBAQEBAg0NDQ0CDQ0NDQ0NAg0NDQ0CAg0NDQEBDQINAQEBAQEBAQEBAQEBAQEBAQEBAQMBAQEBAQEBEQEBAQEBAQEQAQEBARAFAQEBAQEEBgEBAQEBAQEHDxAACwJAIAFBjMAAaw4CCggACyABQc8GRg0HIAFB4MAARg0IIAFB//8DRg0KCyAAQf7/A3EiAUG0L0YEQEECDwULIAFBni9HDQoLQQEPC0EHDwtBCA8LQQoPC0ENDwtBDw8LQRIPC0EUDwtBFQ8LQRYPCyAAQaMva0H//wNxQRFJBEBBAw8FCyAAQbcva0H//wNxQQRJDQQgAEG7L2tB//8DcUEDSQRAQQUPBQsgAEHBL2tB//8DcUEDSQRAQQYPBQtBByECAkAgAEG/L2siAUH//wNxQQdPBEAgAEHJL2tB//8DcUECSQRAQQkPBQsgAEHNL2tB//8DcUEFSQ0FIABBxy9rQf//A3FBAk8NAUEMDwULQeMAIAF2QQFxDQELIABB4P8DcUHgM0YgAEHUL2tB//8DcUEHSXINAiAAQe7/A3FB4C9rQe7/A3FBCkkEQEEQDwULIABB3i9rIgFB//8DcUEiT0KD4I+APyABrYinQQFxRXINAUERIQILIAIPC0ETQRcgAEHw/wNxQYD8A0YbDwtBDg8LQQsPC0EEC5QDAgZ/AX4jAEEgayIGJAACQCACIAIgA2oiA0sEQEEAIQIMAQULQQAhAiAEIAVqQQFrQQAgBGtxrUEEIAEoAgAiCkEBdCIIIAMgAyAISRsiAyADQQRNGyILrX4iDEIgiKcNACAMpyIIQYCAgIB4IARrSw0AIAYgCgR/IAYgBSAKbDYCHCAGIAEoAgQ2AhQgBAVBAAs2AhggBkEIaiEKIwBBEGsiCSQAAn8gBkEUaiICKAIEBEAgAigCCCIFRQRAIAlBCGogBCAIECcgCSgCCCEHIAkoAgwMAgULIAIoAgAhAgJAIAgQCSIHRQRAQQAhBwwBBQsgByACIAUQBBogAiAFEAsLIAgMAQULIAkgBCAIECcgCSgCACEHIAkoAgQLIQIgCiAHIAQgBxs2AgQgCiAHRTYCACAKIAIgCCAHGzYCCCAJQRBqJAAgBigCCEUEQCAGKAIMIQIgASALNgIAIAEgAjYCBEGBgICAeCECDAEFCyAGKAIQIQMgBigCDCECCyAAIAM2AgQgACACNgIAIAZBIGokAAurAwECfwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAEH//wNxIgFBs9MCaw4tBgsLCAgJCQoKCAsFBAIBAQEBAQEBAQEBAQEBEgcBAQEBAQEBAQEBAQEBAQcHAAsCQCABQYzAAGsOAg0DAAsgAUGgAUYgAUHXAUZyDRIgAUHPBkYNAiABQaLAAEYNEiABQeDAAEYNCyABQczLAEYNEiABQYTTAkYNDiABQf//A0YNDQsgAEH3rAFqQf//A3FBA0kgAEHxrAFqQf//A3FBJElyDQ0gAEH//wNxIgFBhdMCayICQQlNDQ4MDwtBAg8LQQQPC0EGDwtBBw8LQQgPC0EMDwtBDQ8LQQ4PC0EPDwtBEA8LQRIPC0ETDwtBFCECCyACDwtBASACdEGPB3FFDQBBAw8LIAFBksAAa0EESSABQfvLAGtBBElyDQEgAEH8/wNxQYDTAkYEQEEFDwULIABBv6wBakH//wNxQQ1JBEBBCg8FCyAAQaasAWpB//8DcUEESQ0AQRFBCSAAQfD/A3FBgPwDRhsPC0ELDwtBAQuNAwEDfwJAAkACQAJAAkACQAJAAkACQAJAIAFFBEAgAEH//wNxIgFBziBrIgJBGEsNAUEBIAJ0IgJBjYCgDHENBiACQYAYcUUNAQwCBQtBAiECIABB//8DcSIBQc4gayIDQRhNBEBBASADdCIEQY2AoAxxDQYgBEGAGHENAiADQQxGDQoFCwJAIAFBryBrDhECAggJBQUFCQUFBQUFBwUFBgALIAFBhCBGIAFBmyBGcg0JIAFBhCFGDQcgAUGOIUYgAUH61AJGcg0FIAFB//8DRg0DDAQLIAFBryBrDhEAAAYHAwMDBwMDAwMDBQMDBAELQQUPCyABQYQhRg0EIAFBjiFGIAFB+tQCRnINAiABQf//A0cNAQtBBw8LIABB8P8DcUHg1AJGIABBj6sBakH//wNxQQZJciAAQfUga0H//wNxQQ1JIABB7iBrQf//A3FBA0lyciAAQYAga0H//wNxQSFJcg0AQQQhAiAAQdoga0H//wNxQQNLDQQLQQEPC0EDDwtBBg8LQQAhAgsgAgvjAQEGf0EIIAAgAEEITRtBB2pBeHEiAUH//wNqIgBBgIB8cSEFIABBEHYhBkHklcAAIQADQAJAAn8CQCAAIgQoAgAiAEF/RwRAIAEgACgCBCAAaiICTw0EIAAgAiABa0F4cSIDSw0EIAIgASADaiIBSw0BIAQhASAADAIFCyAGQAAiAEF/Rw0CQQAPCyABIAA2AgAgASACIAFrNgIEIAQgATYCACABKAIACyECIAAgA0cEQCACIAMgAGs2AgQgAw8FCyABIAIoAgA2AgAgAw8LIABBEHQgBRALQeSVwAAhAAwACwAL5QEBBH8jAEGgB2siBCQAAn9BACAAEBMNABpBCCEBIARBEGpB7InAAEGkBBAEGgJAA0AgAUEEaiICQbAERg0BIARBCGogAWohAyACIQEgAEH//wNxIgIgAygCACIDQf//A3FJIANBEHYgAklyDQALQQIMAQsgBEEIakGQjsAAQZAHEAQaIARCgICAgMAcNwKYB0EAIQEDQEEDIAFBBGoiAkGUB0YNARogBEEIaiABaiEDIAIhASAAQf//A3EiAiADKAIAIgNB//8DcUkgA0EQdiACSXINAAtBAQshASAEQaAHaiQAIAEL3gEBA38gAEEIIAEgAUEITRtBB2pBeHEiAmohA0HklcAAIQECQAJAAkADQCABIgQoAgAiAUF/Rg0CIAEgA0YNASAAIAFNDQALIAAgASABKAIEIgNqRwRAIAAgAjYCBCAAIAE2AgAgBCAANgIADwULIAEgAiADajYCBA8LIAEoAgQgAmohAiABKAIAIgFBf0YNASABIAEoAgQiA2ogAEcNASABIAIgA2o2AgQgBCABNgIADwsgACACNgIEIABBfzYCACAEIAA2AgAPCyAEIAA2AgAgACABNgIAIAAgAjYCBAvxAQECfwJAAkAgAEGANGtB//8DcUEXSQ0AAkACQAJAAkACQAJAAkACQAJAAkAgAEH//wNxIgFBlzRrDgkDBAUGAwEBAgIAC0EBIQICQAJAIAFBjMAAaw4KCQEMDAwMCwsLCwALIAFBoAFGIAFB+8sAa0EESXIgAUHXAUZyDQogAUH//wNGDQkgAUGiwABGDQogAUHgwABGDQcgAUHMywBGDQogAUHP0wJGDQIgAUHPBkcNCwtBAg8LQQQPC0EFDwtBBg8LQQcPC0EIDwtBCQ8LQQsPC0EMDwtBDSECCyACDwtBCkEDIABB8P8DcUGA/ANGGwvOAQEKfyMAQRBrIgMkACADIAJBBEEMEBAgAygCACIHIAJB/////wNxIgQgBCAHSxshBkEAIQQgAygCBCEIIANBD2ohCgNAIAYEQCABIARqIglBBGooAgAhCyAJKAIAIQwgBCAIaiIFQQhqIAlBCGotAAA6AAAgBUEEaiALNgIAIAUgDDYCACAFQQlqIAMvAA07AAAgBUELaiAKLQAAOgAAIAZBAWshBiAEQQxqIQQMAQULCyAAIAI2AgggACAINgIEIAAgBzYCACADQRBqJAALnAEBCH8jAEEQayIEJAAgBEEIaiACQQRBCBAQIAQoAggiBiACQf////8BcSIDIAMgBksbIQVBACEDIAQoAgwhBwNAIAUEQCABIANqIggoAgAhCSADIAdqIgpBBGogCEEEai0AADoAACAKIAk2AgAgBUEBayEFIANBCGohAwwBBQsLIAAgAjYCCCAAIAc2AgQgACAGNgIAIARBEGokAAuVAQEDfyAAIQQDQAJAAkAgASAFRwRAIAQvAQBBIEYNASABQQFrIQQgAUEBdCAAakECayEADAIFCwNADAALAAsgBEECaiEEIAVBAWohBQwBCwsCQANAIAEgBE0NASAALwEAQSBGBEAgAEECayEAIARBAWshBCAGQQFqIQYMAQULCyADIAY2AgAgAiAFNgIADwsDQAwACwALjQECAn8BfiMAQRBrIgQkAAJAAkAgAiADakEBa0EAIAJrca0gAa1+IgZCIIinDQAgBqciBUGAgICAeCACa0sNAAJAIAVFBEBBACEBIAIhAwwBBQsgBEEIaiACIAUQJyAEKAIIIgNFDQILIAAgAzYCBCAAIAE2AgAgBEEQaiQADwsDQAwACwALIAIgBRAtAAuaAQEBfyAAQaIMa0H//wNxIgFBsgFPBEAgAEH//wNxQY3AAEYEQEEDDwULIABB7cAAa0H//wNxQQNJBEBBBA8FCyAAQbAJaiIBQf//A3FBkwJPBEAgAEGQA2pB//8DcSIAQY0BTwRAQQAPBQsgAEGHhcAAai0AAA8FCyABQf//A3FB9ILAAGotAAAPBQsgAUEBdEGQgMAAai8BAAuEAQEDfyAAKAIIIgMgACgCAEYEQCMAQRBrIgIkACACQQhqIAAgACgCAEEBQQRBEBAGIAIoAggiBEGBgICAeEcEQCAEIAIoAgwQLQAFCyACQRBqJAAFCyAAIANBAWo2AgggACgCBCADQQR0aiIAIAEpAgA3AgAgAEEIaiABQQhqKQIANwIAC3EBBX8jAEHAAWsiASQAQQghAiABQQxqQbiIwABBtAEQBBoDQCACQQRqIgRBwAFHBEAgAUEEaiACaiEDIAQhAiAAQf//A3EiBSADKAIAIgNB//8DcUkgA0EQdiAFSXINAQULCyABQcABaiQAIARBwAFHC28BA38gACgCCCIDIAAoAgBGBEAjAEEQayICJAAgAkEIaiAAIAAoAgBBAUECQQIQBiACKAIIIgRBgYCAgHhHBEAgBCACKAIMEC0ABQsgAkEQaiQABQsgACADQQFqNgIIIAAoAgQgA0EBdGogATsBAAtoAQJ/IAEgACgCCCIETQRAIAAoAgAgBEYEQCAAEDMFCyAAKAIEIAFBA3RqIQUgASAESQRAIAVBCGogBSAEIAFrQQN0EAIFCyAFIAM6AAQgBSACNgIAIAAgBEEBajYCCA8FCwNADAALAAtqAQR/IwBBQGoiASQAIAFBoJXAAEE4EAQiA0KAgICA4AE3AjhBACEBA0AgAUEEaiIEQTxHBEAgASADaiECIAQhASACKAIAIgJB//8DcSAASyACQRB2IABJcg0BBQsLIANBQGskACAEQTxHC14BBH8gAiABKAIIIgRJBEAgASgCBCACQQN0aiIDKAIAIQUgAy0ABCEGIAMgA0EIaiAEIAJBf3NqQQN0EAIgASAEQQFrNgIIIAAgBjoABCAAIAU2AgAPBQsDQAwACwALVAECfyMAQRBrIgIkAAJAIAAoAgAgACgCCCIDayABTw0AIAJBCGogACADIAFBAkECEAYgAigCCCIAQYGAgIB4Rg0AIAAgAigCDBAtAAsgAkEQaiQAC00BAn8jAEEQayIDJAAgA0EIaiACQQJBAhAQIAMoAgghBCADKAIMIAEgAkEBdBAEIQEgACACNgIIIAAgATYCBCAAIAQ2AgAgA0EQaiQAC0sBAX4CQCABIAJLBEAgASADSw0BA0AMAAsABQsDQAwACwALIAAgAkEDdGoiASkCACEEIAEgACADQQN0aiIAKQIANwIAIAAgBDcCAAtJAAJAIAEgAksEQCABIANLDQEDQAwACwAFCwNADAALAAsgACACQQF0aiIBLwEAIQIgASAAIANBAXRqIgAvAQA7AQAgACACOwEAC0gBAX8gACgCCCICIAAoAgBGBEAgABAyBQsgACACQQFqNgIIIAAoAgQgAkEMbGoiACABKQIANwIAIABBCGogAUEIaigCADYCAAtEAQF/IwBBEGsiAiQAIAJBCGogACAAKAIAQQFBBCABEAYgAigCCCIAQYGAgIB4RwRAIAAgAigCDBAtAAULIAJBEGokAAs8AQF/IAAoAggiAyAAKAIARgRAIAAQMwULIAAgA0EBajYCCCAAKAIEIANBA3RqIgAgAjYCBCAAIAE2AgALPAEBfyAAKAIIIgMgACgCAEYEQCAAEDMFCyAAIANBAWo2AgggACgCBCADQQN0aiIAIAI6AAQgACABNgIACzgCAX8BfiMAQRBrIgIkACACQQhqIAFBAkECEBAgAikDCCEDIABBADYCCCAAIAM3AgAgAkEQaiQACzIAIANBf3MgAWohAwNAIAIgA0kEQCAAIAEgAiADEBsgA0EBayEDIAJBAWohAgwBBQsLCy4BAX8gACACEBggACgCBCAAKAIIIgNBAXRqIAEgAkEBdBAEGiAAIAIgA2o2AggLMAEBfyMAQRBrIgIkACABQYCAgIAETwRAA0AMAAsABQsgACABQQF0EAsgAkEQaiQACyYBAX8jAEEQayIBJAAgAUEEaiAAECAgASgCCCEAIAFBEGokACAACyAAIAEgA00EQCAAIAE2AgQgACACNgIADwULA0AMAAsACx0AIAEgA0cEQANADAALAAULIAAgAiABQQF0EAQaCyUAIAIEQEHolcAALQAAGiACEAkhAQULIAAgAjYCBCAAIAE2AgALHwAgAiADSQRAA0AMAAsABQsgACADNgIEIAAgATYCAAsZACABIAJNBEADQAwACwAFCyAAIAJBDGxqCxkAIAEgAk0EQANADAALAAULIAAgAkEDdGoLGQAgASACTQRAA0AMAAsABQsgACACQQF0agsXACAAIAEoAgQgASgCCBAiIAFBADYCCAsVACAARQRAA0AMAAsABQsDQAwACwALEQAgAARAIAEgACACbBALBQsLCgAgACABQQIQLgsKACAAIAFBDBAuCwoAIAAgAUEIEC4LCAAgAEEMEB0LCAAgAEEIEB0LBgAgABATCwuKFAsAQYGAwAALQQEAAAAAAAEAAQABAAEAASERIRMBFSEXAxkhHQMfASMDJQMpAy0DMQM1ATkBOwE9AT8DQQNFA0kDTQNRA1UDWQNdAEHMgMAACy4DAANhA2UDaRNtA3EDdQN5AX0BfwOBBAGEAYQBhAGEAYQBRAMEAQQHBAgECAQBAEGEgcAACwgBhQGHAYkBiwBBrIHAAAvSAQQGCQAhACEAAAAhAAEAAQADAAsWCw4LAgMAAwALBgMAAwADAAMAAwADAAMACyoDAAk4AQABAAEACTQJMgk2AQABAAk8AQABAAEAAQABAAEACToBAAMAAwADAAMAAwADAAMAAwADAAMAAwADAAMAAwADAAs+AwADAAMAAwADAAtCAwADAAMAAwADAAMAAwADAAMAAwAJTgtQAwADAAtaAwAJVAtWAQABAAEACZAJiQmHCYsJkgEACY4LrAEAAwADAAuUAwAJXglgAAEAAAAAAAECAwBBn4PAAAsDAQIDAEGvg8AACwsBAAAAAQIDAAECAwBBoYTAAAsDAQIDAEGChcAAC50BBAQEBAQDAwMAAwADAwMDAwMDAwMDAAABAAEAAQABAAECAwABAAECAwABAAECAwABAgMAAQIDAAECAwABAgMAAQABAAEAAQABAgMAAQIDAAECAwABAgMAAQIDAAECAwABAgMAAQIDAAECAwABAgMAAQIDAAECAwABAgMAAQIDAAECAwABAAEAAQIDAAEAAQABAAEAAAAAAgQGCAoMDgBBqIbAAAsXAQADAAEAAQAAAgIAAAECAAEBAgABAQMAQciGwAALnA8BAAMAAQADAAABAgAAAQIAAQECAAEBAwC+C78LwAvBC8ILwwvEC8ULxgvHC8gLAAAoACkAKQAoADwAPgA+ADwAWwBdAF0AWwB7AH0AfQB7AKsAuwC7AKsAFSL1KR8i/isgIqMpISKbKSIioCkkIu4qQyLNIkUiTCJMIkUimCK4KaYi3iqoIuQqqSLjKqsi5Sq4ItwnzSJDIvIi+iLzIvsi9CL8IvYi/SL3Iv4i+iLyIvsi8yL8IvQi/SL2Iv4i9yLcJ7gimykhIqApIiKjKSAiuCmYIvUpFSLeKqYi4yqpIuQqqCLlKqsi7iokIv4rHyK+Bb4FwAXABcMFwwXGBcYF0AXqBfAF9AUIBggGCwYLBg0GDQYbBhsGHgZKBm0GbwZxBtUG5QbmBu4G7wb6Bg0HEAcQBxIHLwdNB6UHsQexB8AH6gf0B/UH+gf6BwAIFQgaCBoIJAgkCCgIKAgwCD4IQAhYCF4IXggPIA8gHfsd+x/7KPsq+zb7OPs8+z77PvtA+0H7Q/tE+0b7wfvT+z39UP2P/ZL9x/3w/fz9cP50/nb+/P4JAA0AHAAiACYAKgA7AEAAWwBgAHsAfgCFAIUAoQChAKYAqQCrAKwArgCvALQAtAC2ALgAuwC/ANcA1wD3APcAuQK6AsICzwLSAt8C5QLtAu8C/wJ0A3UDfgN+A4QDhQOHA4cD9gP2A4oFigUGBgcGDgYPBt4G3gbpBukG9gf5B/ML+Av6C/oLeAx+DDoPPQ+QE5kTABQAFIAWgBabFpwW8Bf5FwAYChgOGA4YQBlAGUQZRRneGf8ZvR+9H78fwR/NH88f3R/fH+0f7x/9H/4fACAKIBAgLiA1IEMgRSBfIHwgfiCMII4gACEBIQMhBiEIIQkhFCEUIRYhGCEeISMhJSElISchJyEpISkhOiE7IUAhRCFKIU0hUCFfIYkhiSGQIREiFCI1I3sjlCOWI/MjACQmJEAkSiRgJIck6iSrJq0m/yYBJ8onzCfMJ84n/ycAKUwrUCtZK+Us6iz5LP8sAC4xLoAumS6bLvMuAC/VL/Av+y8AMAQwCDAgMDAwMDA2MDcwPTA/MJswnDCgMKAw+zD7MMAx4zEdMh4yUDJfMnwyfjKxMr8yzDLPMnczejPeM98z/zP/M8BN/02QpMakDaYPpnOmc6Z+pn+mAKchp4iniKcoqCuodKh3qD79P/39/f39EP4Z/jD+T/5R/lH+VP5U/lb+Xv5g/mH+ZP5m/mj+aP5r/mv+Af8C/wb/Cv8b/yD/O/9A/1v/Zf/i/+T/6P/u/wAACAAOABsAIwAlACsAOgB/AIQAhgCgAKIApQCtAK0AsACzALkAuQAAA28DgwSJBJEFvQW/Bb8FwQXCBcQFxQXHBccFAAYDBgkGCgYMBgwGEAYaBksGbAZwBnAG1gbdBt8G5AbnBugG6gbtBvAG+QYPBw8HEQcRBzAHSgemB7AH6wfzBxYIGQgbCCMIJQgnCCkILQhZCFsIAAkCCToJOgk8CTwJQQlICU0JTQlRCVcJYgljCYEJgQm8CbwJwQnECc0JzQniCeMJ8gnzCfsJ+wkBCgIKPAo8CkEKQgpHCkgKSwpNClEKUQpwCnEKdQp1CoEKggq8CrwKwQrFCscKyArNCs0K4grjCvEK8QoBCwELPAs8Cz8LPwtBC0QLTQtNC1YLVgtiC2MLgguCC8ALwAvNC80L+Qv5Cz4MQAxGDEgMSgxNDFUMVgxiDGMMvAy8DMwMzQziDOMMQQ1EDU0NTQ1iDWMNyg3KDdIN1A3WDdYNMQ4xDjQOOg4/Dj8ORw5ODrEOsQ60DrkOuw68DsgOzQ4YDxkPNQ81DzcPNw85DzkPcQ9+D4APhA+GD4cPjQ+XD5kPvA/GD8YPLRAwEDIQNxA5EDoQPRA+EFgQWRBeEGAQcRB0EIIQghCFEIYQjRCNEJ0QnRBdE18TEhcUFzIXNBdSF1MXchdzF7cXvRfGF8YXyRfTF9sX2xfdF90XCxgNGKkYqRggGSIZJxkoGTIZMhk5GTsZFxoYGlYaVhpYGl4aYBpgGmIaYhplGmwacxp8Gn8afxoAGwMbNBs0GzYbOhs8GzwbQhtCG2sbcxuAG4EbohulG6gbqRvmG+Yb6BvpG+0b7RvvG/EbLBwzHDYcNxzQHNIc1BzgHOIc6BztHO0cwB3mHfwd/x0LIA0gLyA0IEQgRCBgIGQgaiBwIHQgeyCAIIsgoCC5INAg8CAuIS4hEiITIogkmyTvLPEsfy1/LeAt/y0qMC8wmTCaMG+mcqZ8pn2m8KbxpgKoAqgGqAaoC6gLqCWoJqg4qDmoxKjEqOCo8agmqS2pR6lRqYCpgqmzqbOptqm5qbypvKm8qbypKaouqjGqMqo1qjaqQ6pDqkyqTKqwqrCqsqq0qrequKq+qr+qwarBquWr5avoq+ir7avtqx77Hvsp+yn7AP4P/iD+Jv5Q/lD+Uv5S/lX+Vf5f/l/+Yv5j/mn+av7//v/+A/8F/wv/Gv/g/+H/5f/m/wBO/58ANL9NAPn/+gAv3y+ALv8uwDHvMfAv/y8AMD8wMP5P/gD/7/9Q/m/+EP4f/gAy/zIAM/8zXAZdBlwGXgZcBl8GAEHklcAACwT/////';
+export default 'AGFzbQEAAAABTAtgAX8AYAF/AX9gAn9/AGACf38Bf2ADf39/AGADf39/AX9gBH9/f38AYAR/f39/AX9gBX9/f39/AGAGf39/f39/AGAHf39/f39/fwACFwEDZW52D3NldFVuaWNvZGVBcnJheQACA3RzBAoGCQgEAgQGBQUJAQQBBAkEBAIECAMCAQcCBAQBBgQBBAYCAgQHAQYEAQQCAgIGBAYEBAEEAgQEAgYCAgYGBAgCAgQEBAMEBAYCAgIFCAIGAQQGCAYEBgYFBQUCAgUBAgIAAgICAgICAgICAgICAAAAAgUDAQARBgkBfwFBgIDAAAsHYwgGbWVtb3J5AgARYWxsb2NVbmljb2RlQXJyYXkAUgRmcmVlAF0FaXNDSksAKwVpc1JUTABgDGpvaW5pbmdGb3JtcwAHC3Byb2Nlc3NUZXh0AB0OdHJ5UHJvY2Vzc1RleHQACgqHuQJz/20CL38EfiMAQbACayIEJAACQAJAAkACQCACQQhxRQ0AIAEoAgQhDyAEQaQCaiABKAIIIg4QbyAEQoCAgIDAADcCmAEgDkEBayEKIA9BAmohCUEEIQsDQCAEIAg2AqABAkAgByAOSQRAIAkgB0EBdCIFaiEDIAUgD2oiDC8BABAZQf8BcSEGIAchBQNAIAUgCkYEQCAOIQUMAwULIAVBAWohBSADLwEAIQ0gA0ECaiEDIAYgDRAZQf8BcSINRiANQQJGcg0ACwwBBQsgBCgCmAEhEEEAIQcgBEEANgKIAiAEQoCAgIDAADcCgAIgC0EIaiEJQQAhBQNAIAkgB0EMbCIMaiEGA0AgBiEDIAggBSIKRgRAIAcgCEkEQCAEQZgBaiIDIAsgB0EMbGogCCAHaxAgIARBADYCpAEgBEGAAmogAxA6BQsgBCgChAIiBSAEKAKIAiINQRRsaiETIAQoAoACIREgBSEDA0AgAyIJIBNHBEAgAygCCCIDQQFrIQxBACEKIAMiBkEMbEEMayIUIQcDQAJAIAZFBEAgBEGYAWogAxBvIAkoAghBDGwhBiAJKAIEIQMDQCAGRQ0CIARBmAFqIAMoAgAgAygCBBBJIAZBDGshBiADQQxqIQMMAAsABQsgCSgCBCAKaiIIQQhqLQAAQQhGBEAgBCAIQQlqLwAAOwGYASAEIAhBC2otAAA6AJoBIAgpAgAhMiAIIAhBDGogBxAGIAkgDDYCCCAJKAIAIAxGBEAgCRBxBQsgCSgCBCEIIAwEQCAIQQxqIAggFBAGBQsgCEEIOgAIIAggMjcCACAIIAQvAZgBOwAJIAggBC0AmgE6AAsgCSADNgIIBQsgBkEBayEGIAdBDGshByAKQQxqIQoMAQsLIAQgBCgCoAE2AogCIAQgBCkCmAE3A4ACIARBpAJqIgcgBEGAAmoQXiAEKAKAAiAEKAKEAhBrIAlBFGohAyAJKAIMIgZFDQEgByAGIAkoAhAQSQwBBQsLIARB+ABqIAQoAqgCIgcgBCgCrAIgDhBZIA8gDiAEKAJ4IAQoAnwQViAFIQMDQCANBEAgAygCACADQQRqKAIAEGggDUEBayENIANBFGohAwwBBQsLIBEgBUEEQRQQMiAQIAsQaCAEKAKkAiAHEGsgBEGkAmogDhBvIARCgICAgMAANwKYASAPQQJqIQogDkEBayEJQQAhB0EEIQtBACEIA0AgBCAINgKgAQJAIAcgDkkEQCAKIAdBAXQiBWohAyAFIA9qIgwvAQAQD0H/AXEhBiAHIQUDQCAFIAlGBEAgDiEFDAMFCyAFQQFqIQUgAy8BACENIANBAmohAyAGIA0QD0H/AXEiDUYgDUEERnINAAsMAQULIAQoApgBIRBBACEHIARBADYCiAIgBEKAgICAwAA3AoACIAtBCGohCUEAIQUDQCAJIAdBDGwiDGohBgNAIAYhAyAIIAUiCkYEQCAHIAhJBEAgBEGYAWoiAyALIAdBDGxqIAggB2sQICAEQQA2AqQBIARBgAJqIAMQOgULIAQoAoQCIgUgBCgCiAIiDUEUbGohEyAEKAKAAiERIAUhAwNAIAMiCSATRwRAIAMoAggiA0EBayEMQQAhCiADIgZBDGxBDGsiFCEHA0ACQCAGRQRAIARBmAFqIAMQbyAJKAIIQQxsIQYgCSgCBCEDA0AgBkUNAiAEQZgBaiADKAIAIAMoAgQQSSAGQQxrIQYgA0EMaiEDDAALAAULIAkoAgQgCmoiCEEIai0AAEEPRgRAIAQgCEEJai8AADsBmAEgBCAIQQtqLQAAOgCaASAIKQIAITIgCCAIQQxqIAcQBiAJIAw2AgggCSgCACAMRgRAIAkQcQULIAkoAgQhCCAMBEAgCEEMaiAIIBQQBgULIAhBDzoACCAIIDI3AgAgCCAELwGYATsACSAIIAQtAJoBOgALIAkgAzYCCAULIAZBAWshBiAHQQxrIQcgCkEMaiEKDAELCyAEIAQoAqABNgKIAiAEIAQpApgBNwOAAiAEQaQCaiIHIARBgAJqEF4gBCgCgAIgBCgChAIQayAJQRRqIQMgCSgCDCIGRQ0BIAcgBiAJKAIQEEkMAQULCyAEQfAAaiAEKAKoAiIHIAQoAqwCIA4QWSAPIA4gBCgCcCAEKAJ0EFYgBSEDA0AgDQRAIAMoAgAgA0EEaigCABBoIA1BAWshDSADQRRqIQMMAQULC0EEIQwgESAFQQRBFBAyIBAgCxBoIAQoAqQCIAcQayAEQaQCaiAOEG8gBEKAgICAwAA3ApgBQQAhA0EEIQpBACEFA0AgBCADNgKgAQJAAkACQAJAAkACQAJAAkACQAJAAkAgBSAOSQRAIA8gBUEBdGohCwJAAkACQCAOIAVBAmoiBksEQCALLwECQbogRg0BBQsgCy8BACEHDAELIAsvAQAhByAPIAZBAXRqLwEAQbkgRg0BCyAHQf//A3EiBkHOIGsiCUEYSw0DQQEgCXQiCUGNgKAMcQ0IIAlBgBhxRQ0DDAQLQQMhCUECIQggB0HOIGsiBkEYSw0BQQEgBnQiDUGNgKAMcQ0HIA1BgBhxDQMgBkEMRw0BDAsFCyAEKAKYASETQQAhCSAEQQA2AogCIARCgICAgMAANwKAAiAMQQRqIQhBACEGA0AgCCAJQQN0IgtqIQoDQCAKIQUgAyAGIgdGBEAgAyAJSwRAIARBmAFqIgUgDCAJQQN0aiADIAlrECYgBEEANgKkASAEQYACaiAFEEMFCyAEKAKEAiIGIAQoAogCIg1BBHRqIREgBCgCgAIhFCAGIQgDQCAIIBFHBEBBACEHIAhBCGohEANAIAcgECgCACIFTwRAIARBmAFqIAUQbyAQKAIAQQN0IQUgCEEEaigCACEDA0AgBQRAAkAgA0EEai0AAEECRgRAIARBuqDkgQE2AYICIAQgAygCAC8BADsBgAIgBEGYAWogBEGAAmpBAxBJDAEFCyAEQZgBaiADKAIALwEAEEwLIANBCGohAyAFQQhrIQUMAQULCyAEIAQoAqABNgKIAiAEIAQpApgBNwOAAiAEQaQCaiIFIARBgAJqEF4gBCgCgAIgBCgChAIQayAIKAIMIQMgCEEQaiEIIANFDQMgBSADLwEAEEwMAwUCQAJAAkACQAJAIAhBBGooAgAiCyAFIAcQWi0ABA4HAgQAAwQEAQQLIAUgB0EBaiIDTQ0DIAsgBSAHIAMQPyADIQcMAwsgBEHoAGogCCAHEDQgCEEAIAQoAmggBC0AbBAwDAILIAdBAWohAwNAAkAgA0EBRgRAQQAhCQwBBQsgA0ECayEKIANBAWsiCSEDIAsgBSAKEFotAARBBUYNAQsLIAcgCUYNASALIAUgCSAHED8MAQsgBUEBayEJQQAhCgNAIAoiAyAJRwRAIANBAWohCiALIAUgAxBaLQAEQQFHDQEFCwsgAyAHRg0AIARB4ABqIAggBxA0IAggAyAEKAJgIAQtAGQQMAsgB0EBaiEHDAELAAsABQsLIARB2ABqIAQoAqgCIgUgBCgCrAIgDhBZIA8gDiAEKAJYIAQoAlwQViAGIQMDQCANBEAgAygCACADQQRqKAIAEGcgDUEBayENIANBEGohAwwBBQsLIBQgBkEEQRAQMiATIAwQZyAEKAKkAiAFEGtBACEDA0ACQCAOIAMiB0sEQEEBIQMgB0EBaiEFIAdFDQJBaiEDIA8gB0EBdGovAQAhBgNAIANFDQIgA0GehcAAaiEKIANBAmohAyAKLwEAIAZHDQALIA8gDiAHQQFrIAcQPgwBBQsgBEGkAmogDhBvIARCgICAgMAANwKYAUEAIQNBBCEGIA8hBUEEIQkDQCAEIAM2AqABAkACQCADIA5HBEBBACEKIAUvAQAiB0HAHmtB//8DcUEtSSAHQYgfa0H//wNxQQVJcg0CQQEhCgJAAkACQCAHQfEeaw4EBAUBBAALAkAgB0GAH2sOAgUBAAsgB0H//wNGDQEgB0H6HmtB//8DcUEESQ0EQQQhCiAHQfUea0H//wNxQQVPDQQLQQMhCgwDC0EFIQoMAgULIAQoApgBIRMgBCgCnAEhDUEAIQcgBEEANgKIAiAEQoCAgIDAADcCgAIgDUEEaiEJQQAhBQNAIAkgB0EDdCIIaiEGA0AgBiEDIA4gBSIKRgRAIAcgDkkEQCAEQZgBaiIDIA0gB0EDdGogDiAHaxAmIARBADYCpAEgBEGAAmogAxBDBQsgBCgChAIiByAEKAKIAiIMQQR0aiERIAQoAoACIRQgByEJA0AgCSARRwRAIAlBEGohBiAJQQhqIhAoAgAiBUEBayEIQQAhCgNAAkACQCAFIApGBEAgBEGYAWogBRBvIBAoAgBBA3QhAyAJQQRqKAIAIQUDQCADRQ0CIARBmAFqIAUoAgAvAQAQTCADQQhrIQMgBUEIaiEFDAALAAULIAlBBGoiFSgCACILIAUgCiIDEFotAARBAWtB/wFxQQJLDQEDQAJAAkAgA0UEQEEAIQMMAQULIAsgBSADEFotAAQNAQsgCyAKQQN0aiILLQAEIRYgCygCACEdIAsgC0EIaiAFIApBf3NqQQN0EAYgECAINgIAIAMgCEsNKSAJKAIAIAhGBEAgCRBwBQsgFSgCACADQQN0aiELIAMgCEkEQCALQQhqIAsgCCADa0EDdBAGBQsgCyAWOgAEIAsgHTYCACAQIAU2AgAMAwsgA0EBayEDDAALAAsgBCAEKAKgATYCiAIgBCAEKQKYATcDgAIgBEGkAmoiBSAEQYACahBeIAQoAoACIAQoAoQCEGsgCSgCDCEDIAYhCSADRQ0DIAUgAy8BABBMDAMLIApBAWohCgwACwAFCwsgBEHQAGogBCgCqAIiBSAEKAKsAiAOEFkgDyAOIAQoAlAgBCgCVBBWIAchAwNAIAwEQCADKAIAIANBBGooAgAQZyAMQQFrIQwgA0EQaiEDDAEFCwtBBCELIBQgB0EEQRAQMiATIA0QZyAEKAKkAiAFEGsgBEGkAmogDhBvIARCgICAgMAANwKYASAPQQJqIQogDkEBayEJQQAhB0EAIQgDQCAEIAg2AqABAkAgByAOSQRAIAogB0EBdCIFaiEDIAUgD2oiDC8BABANQf8BcSEGIAchBQNAIAUgCUYEQCAOIQUMAwULIAVBAWohBSADLwEAIQ0gA0ECaiEDIAYgDRANQf8BcSINRiANQRJGcg0ACwwBBQsgBCgCmAEhEEEAIQcgBEEANgKIAiAEQoCAgIDAADcCgAIgC0EIaiEJQQAhBQNAIAkgB0EMbCIMaiEGA0AgBiEDIAggBSIKRgRAIAcgCEkEQCAEQZgBaiIDIAsgB0EMbGogCCAHaxAgIARBADYCpAEgBEGAAmogAxA6BQsgBCgChAIiBSAEKAKIAiINQRRsaiETIAQoAoACIREgBSEDA0AgAyIJIBNHBEAgAygCCCIDQQFrIQxBACEKIAMiBkEMbEEMayIUIQcDQAJAIAZFBEAgBEGYAWogAxBvIAkoAghBDGwhBiAJKAIEIQMDQCAGRQ0CIARBmAFqIAMoAgAgAygCBBBJIAZBDGshBiADQQxqIQMMAAsABQsgCSgCBCAKaiIIQQhqLQAAQQZGBEAgBCAIQQlqLwAAOwGYASAEIAhBC2otAAA6AJoBIAgpAgAhMiAIIAhBDGogBxAGIAkgDDYCCCAJKAIAIAxGBEAgCRBxBQsgCSgCBCEIIAwEQCAIQQxqIAggFBAGBQsgCEEGOgAIIAggMjcCACAIIAQvAZgBOwAJIAggBC0AmgE6AAsgCSADNgIIBQsgBkEBayEGIAdBDGshByAKQQxqIQoMAQsLIAQgBCgCoAE2AogCIAQgBCkCmAE3A4ACIARBpAJqIgcgBEGAAmoQXiAEKAKAAiAEKAKEAhBrIAlBFGohAyAJKAIMIgZFDQEgByAGIAkoAhAQSQwBBQsLIARByABqIAQoAqgCIgcgBCgCrAIgDhBZIA8gDiAEKAJIIAQoAkwQViAFIQMDQCANBEAgAygCACADQQRqKAIAEGggDUEBayENIANBFGohAwwBBQsLIBEgBUEEQRQQMiAQIAsQaCAEKAKkAiAHEGsMJgULIANBDGohBiAKQQFqIQUgAy0AAEEVa0H/AXFBAUsNAAsgByAKTQRAIARBmAFqIgYgCyAMaiAKIAdrECAgBCADQQhrKQIANwKkASAEQYACaiAGEDogBSEHDAEFCwsMJQsgBSAHTwRAIAQoApgBIAhGBEAgBEGYAWoQcSAEKAKcASELBQsgCyAIQQxsaiIDIAY6AAggAyAFIAdrNgIEIAMgDDYCACAIQQFqIQggBSEHDAEFCwsMIwULIANBCGohBiAKQQFqIQUgAy0AAEEFRw0ACyAHIApNBEAgBEGYAWoiBiAIIA1qIAogB2sQJiAEIANBBGsoAgA2AqQBIARBgAJqIAYQQyAFIQcMAQULCwwgC0ECIQoLIAQoApgBIANGBEAgBEGYAWoQcCAEKAKcASEJBQsgBiAJaiIHIAo6AAAgB0EEayAFNgIAIAZBCGohBiAFQQJqIQUgA0EBaiEDDAALAAsgBSEDDAALAAULIAVBCGohCiAHQQFqIQYgBS0AAEEHRw0ACyAHIAlPBEAgBEGYAWoiCiALIAxqIAcgCWsQJiAEIAVBBGsoAgA2AqQBIARBgAJqIAoQQyAGIQkMAQULCwwYCwJAIAdBryBrDhECAggJBQUFCQUFBQUFBwUFBgALIAdBhCBGIAdBmyBGcg0JIAdBhCFGDQcgB0GOIUYgB0H61AJGcg0FIAdB//8DRg0DDAQLIAZBryBrDhEAAAYHAwMDBwMDAwMDBQMDBAELQQEhCUEFIQgMBwsgBkGEIUYNBCAGQY4hRiAGQfrUAkZyDQIgBkH//wNHDQELQQEhCUEHIQgMBQsgB0Hw/wNxQeDUAkYgB0GPqwFqQf//A3FBBklyIAdB9SBrQf//A3FBDUkgB0HuIGtB//8DcUEDSXJyIAdBgCBrQf//A3FBIUkgB0HaIGtB//8DcUEDTXJyDQBBASEJQQQhCAwEC0EBIQhBASEJDAMLQQEhCUEDIQgMAgtBASEJQQYhCAwBC0EBIQlBACEICyAFIAlqIQUgBCgCmAEgA0YEQCAEQZgBahBwIAQoApwBIQwFCyAKIAxqIgcgCDoAACAHQQRrIAs2AgAgCkEIaiEKIANBAWohAwwACwAFCyADQQxqIQYgCkEBaiEFIAMtAABBE0kNAAsgByAKTQRAIARBmAFqIgYgCyAMaiAKIAdrECAgBCADQQhrKQIANwKkASAEQYACaiAGEDogBSEHDAEFCwsMCQsgBSAHTwRAIAQoApgBIAhGBEAgBEGYAWoQcSAEKAKcASELBQsgCyAIQQxsaiIDIAY6AAggAyAFIAdrNgIEIAMgDDYCACAIQQFqIQggBSEHDAEFCwsMBwULIANBDGohBiAKQQFqIQUgAy0AAEEOcUEMRw0ACyAHIApNBEAgBEGYAWoiBiALIAxqIAogB2sQICAEIANBCGspAgA3AqQBIARBgAJqIAYQOiAFIQcMAQULCwwECyAFIAdPBEAgBCgCmAEgCEYEQCAEQZgBahBxIAQoApwBIQsFCyALIAhBDGxqIgMgBjoACCADIAUgB2s2AgQgAyAMNgIAIAhBAWohCCAFIQcMAQULCwwCCwJAIAJBgIDAAHFFBEAgACABKAIINgIIIAAgASkCADcCAAwBBQsgASgCBCEaIARBgAFqIAEoAggiExBvQQAhByAEQQA2AqABIARCgICAgMAANwKYAQNAIAcgEyAHIBNLGyEKIBogB0EBdGohBSAHIQMCQANAIAMgCkYEQEEBQQJBACACQYCAgIADcSICQYCAgIACRhsgAkGAgICAAUYbISggByATSQRAIARBmAFqIAcgExBFBQsgBCgCmAEhKSAEKAKcASEdIARBQGsgBCgCoAEiAkEEQSQQKSAEQQA2ApQBIAQgBCkDQDcCjAEgBEGMAWpBACACQQRBJBBVIB0gAkEDdGohKiAEQaQBaiErIAQoApQBIRwgBCgCkAEhLCAdIRQMAgULAkACQAJAIAUvAQAiBkEKaw4EAgEBAgALIAZBhQFGIAZBqcAARnINAQsgBUECaiEFIANBAWohAwwBCwsgBEGYAWogByADEEUCQCAGQQ1HDQAgEyADQQFqIgVNDQAgBSADIBogBUEBdGovAQBBCkYbIQMLIANBAWohBwwBCwsDQAJAIBQgKkcEQCAUKAIEIiEgFCgCACIeSSATICFJckUEQCAhIB5rIRIgGiAeQQF0aiEPQQAhBkEAIRECQAJAAkAgKEEBaw4CAgEACyAEQThqIA8gEhATIAQtADlBACAELQA4GyERDAELQQEhEQsgFEEIaiEUIARBpAJqIgIgEhBuIAIgEhAtIARBADYCiAIgBEKAgICAwAA3AoACIA8gEkEBdCIDaiEfIA8hBQNAAkACQCADBEAgBS8BABA1IgJB/wFxIgdB/wFGDQIgAkEFa0H/AXFBA0kNASAHQQhHDQIgBCgCiAIiAkUNAiAEIAJBAWsiAjYCiAIgBCgCqAIgBCgCrAIgBCgChAIgAkECdGooAgAQWiICIAY2AgQgAkEBNgIADAIFCyAEKAKsAiEXIAQoAqgCISAgBCgCpAIhLSAEKAKAAiAEKAKEAhBqIARBwAFqIA8gEhASIARBzAFqIBIQbSAEAn8gEiAEKALUASICTQRAIAQoAtABIQwgEgwBBQsgEiACIgZrIgcgBCgCzAEgBmtLBEAgBEHMAWogBiAHQQFBARBBIAQoAtQBIQYFCyAGIAQoAtABIgxqIQMgAkF/cyASaiEFA0AgAyAROgAAIAUEQCAFQQFrIQUgA0EBaiEDDAEFCwsgBiAHagsiDjYC1AEgBEHYAWoiAiASEG0gAiASEC8gBCgC4AEhFSAEKALcASEWIAQoAsgBIRsgBCgCxAEhGCAEQTBqQf8AQQFBAxApQQAhAyAEQQA2AogCIAQgBCkDMDcCgAIgBEGAAmogEUEQdEGA/gNyED0gDyEKQQAhEANAQQAhCANAQQAhBwNAAkACQAJAAkACQAJ/AkACQAJAAkACfwJAAkAgCiAfRwRAIAQoAogCIgZFDQQgBCgChAIiGSAGQQNsaiILQQNrIgVFDQQgA0EBaiECIApBAmohCSALQQJrLQAAIQ0gC0EBay0AACELIAUtAAAhIgJAAkACQAJAAkAgCi8BABA1Qf8BcSIKDgkCAgECAgMDAwQACyADIBtPDR0gAyAYaiIFLQAAIgZBCkcEQCADIA5PDR4gAyAMaiALOgAAIAZBCUcEQCAJIQogAiEDIA1B/wFGDRQgBSANOgAADBMFCyADIBVJDREMHgULIAMgDkkNDwwdCyADIA5PDRwgAyAMaiALOgAAIAMgFU8NHCADIBZqQQE6AAAgCSEKIAIhAyAHDREgCEUEQEEAIQhBACEHICJBAXEgBkECSXINEiAEIAZBAWs2AogCDA4FCyAIQQFrIQgMDAsgAyAOTw0bIAMgDGogCzoAACADIBVPDRsgAyAWakEBOgAAAn8CQAJAIApBAWsOBAEAAAEACyALQX5xQQJqDAELIAtBAWpBAXILIgNB/wFxQf0ASyAHciAIcgRAIAggB0VqIQgMEAULQQAhCCAEQYACaiADQRB0QQFBfyAKQQRGG0EAIApBA0cbQf8BcUEIdHIQPQwLCyADIA5PDRogAyAMaiALOgAAIAMgG0kNAgwaCyAHRQ0CIAdBAWsMAwULIAQoAoACIAQoAoQCQQFBAxAyQQAhBSAEQQA2AvgBIARCgICAgMAANwLwAUEEIQNBACEGA0AgBCgC+AEiB0UgAyAHQQxsIgpqQQxrIglFciEIIAZB/wFxIQYDQCAFIBIgBSASSxshCyAFIBUgBSAVSxshAwJAAkADQCALIAUiAkYNASADIAVGDR0gBUEBaiEFIAIgFmotAAANAAsgCEUEQCACIA5PDR8gAiAMai0AACAGRg0CBQsgAiAOTw0eIAIgDGotAAAhBiAEIAI2AqQCIARBgAJqIARBpAJqQQEQOCAEKALwASAHRgRAIARB8AFqEHEFCyAKIAQoAvQBIgNqIgIgBCkCgAI3AgAgAiAEKAKIAjYCCCAEIAdBAWo2AvgBDAMLIARBmAJqIgIgEhBuIAIgEhAtIAQoAvgBQQxsIQVBACEGIAQoAvQBIQMDQCAFBEAgA0EEaigCACADQQhqKAIAQQAQWyECIAQoApwCIAQoAqACIAIoAgAQWiICIAY2AgQgAkEBNgIAIAVBDGshBSAGQQFqIQYgA0EMaiEDDAEFCwsgBEGkAmoiAiASEG0gAiASEC8gF0EDdCEFQQAhAyAEKAKoAiELIAQoAqwCIRADQAJAIAMgBUcEQCADICBqIgIoAgBFDQEgECACQQRqKAIAIgJNDSAgAiALakEBOgAADAEFC0EAIRsgBEEANgKIAiAEQoCAgIDAADcCgAIgFkEBayEYIAQoAvQBIgcgBCgC+AEiH0EMbGohGUEEIQ0gBCgCoAIhIiAEKAKcAiEkQQAhCiAHIQMCQANAIAMgGUYNASADQQhqIQIgA0EEaiEFIANBDGohAyALIBAgBSgCACIFIAIoAgAiAkEAEFsoAgAQXy0AAA0AIARB5AFqIAUgAhA4A0AgBCgC7AEiAkUNCyAEKALoASIFIAJBAnRqQQRrIgZFDQsCQAJAAkACQAJAIBcgBigCACIGSwRAICAgBkEDdGoiCSgCAARAICQgIiAJKAIEEFoiCSgCAA0GBQsgDiAFIAJBABBbKAIAIglNDQIgCSAMai0AACEJIAUgAkEAEFsoAgAiBUEBayAVSSEIAn8DQCARIAVFDQEaIAVBAWshAiAIRQ0qIAUgGGohJSACIQUgJS0AAA0ACyAFIA5PDQIgBSAMai0AAAshBSAGIBJPDQMCfyARIA8gBkEBdGovAQAQNUEFa0H/AXFBA0kNABogBkEBaiEIA0AgESASIAgiAkYNARogCCAVTw0GIAhBAWohCCACIBZqLQAADQALIAIgDk8NKSACIAxqLQAAC0H/AXEiAiAJIAIgCUsbQQFxIQYgBUH/AXEiAiAJIAIgCUsbQQFxIQUgBCgCgAIgCkYEQCAEQYACahByIAQoAoQCIQ0FCyAEKQLkASEyIA0gCkEEdGoiAiAEKALsATYCCCACIDI3AgAgAiAJOgAOIAIgBjoADSACIAU6AAwgBCAKQQFqIgo2AogCIBtBEGohGwwHBQsACwALAAsACwALIB8gCSgCBCICSwRAIARB5AFqIAcgAkEMbGoiAigCBCACKAIIEEgMAQULCwsMIAsgBCgCgAIhIiAEKAKEAiEWIAQoAqQCIAQoAqgCEGkgBCgCmAIgBCgCnAIQZyAEKAL0ASICIAQoAvgBEFAgBCgC8AEgAkEEQQwQMiAWIApBBHRqIRAgBCgC1AEhJCAEKALQASElIAQoAsgBIRggBCgCxAEhHyAWIQMDQAJAAkACQCADIBBHBEAgA0EQaiEMIAMoAgAiLkF/Rw0BIAwhEAULIBYgEGsgG2pBBHYhAwNAIANFDQIgECgCACAQQQRqKAIAEGogA0EBayEDIBBBEGohEAwACwALIAMtAA0hLyADLQAMIQ4gAygCBCELIAMtAA4hByAEQYACaiICIAMoAggiFRBtIAIgFRBhIAQoAogCIQggBCgChAIhCiAVBEAgCyEDIBUhBQNAIBggAygCACICTQ0kIAggCmogAiAfai0AADoAACADQQRqIQMgCEEBaiEIIAVBAWsiBQ0ACwULIAsgFUECdCIwaiEmIAdBAXEhFyAIIApqIQcgBCgCgAIhMSAKIQMgDiEFA0AgAyAHRwRAIAMtAAAiAkEIRwRAIANBAWohAyACIQUMAgUgAyAFOgAAIANBAWohAwwCCwAFCwsgDiEJIAohBQwBCyAiIBZBBEEQEDJBACEDIAQoAtABIQIgBCgC1AEhByAEKALgASEKIAQoAtwBIQYDQAJAIAMgEkYNHiAGIAogAxBfLQAAQQFGBEAgESEFIAMEQCAHIANBAWtNDQIgAiADakEBay0AACEFBQsgAyAHTw0iIAIgA2ogBToAAAULIANBAWohAwwBCwsACwNAAkACQAJAIAcgBSIDRwRAIANBAWohBQJAIAMtAAAiAg4EAgIAAwULIANBAToAAAwDBQtBACEDQQEgCCAIQQBHayICIAJBAU0bQQFrIQlBAiAIIAhBAk0bQQJrIQ0DQCADIAlGBEBBACEDA0ACQAJAIAggAyICTQRAQQAhAwNAAkAgAyAIRgRAIA4hBiAKIQUMAQULIAMgCmoiAi0AACIFQQdLQQEgBXRBsAFxRXJFBEAgAkENOgAABQsgA0EBaiEDDAELCwNAIAZB/wFxIQMDQCAHIAUiAkYNAyAFQQFqIQUCQCACLQAAIgYOBAICAQABCyADDQALQQAhBiACQQA6AAAMAAsABQsgAiAKaiIFLQAAQQVHBEAgA0EBaiEDDAMFCwNAIAMgCEYEQCAIIQMMAwULIAMgCmotAABBBUcNAiADQQFqIQMMAAsACyAEQaQCakE/EG5BACEGIARBADYCiAIgBEKAgICAwAA3AoACIAitITRCACEzQQQhGUF4IQ0gCyECA0ACQCAmIAIiA0YNAAJAIDMiMiA0UgRAIDJCAXwhMyADQQRqIQIgCiAypyIHai0AAEENRw0DIBIgAygCACIDTQ0qIA8gA0EBdGovAQAhCUGAASEDQQAhBQNAIANBAkkNAiAFIAUgA0EBdiIjaiIFIAVBBmwvAdDqQCAJSxshBSADICNrIQMMAAsABQsACyAFQQZsIgMvAdDqQCAJRw0BAkACQCADQdDqwABqIgMvAQRFBEACQCAJQanGAGsOAgIAAwtBieAAIQkMAgULAkACQAJAIAMvAQIiB0GpxgBrDgIBAAILQYngACEHDAELQYjgACEHCyAEKAKsAiIFQQN0IQMgBCgCqAIiCUEIayEjA0AgA0UNBCAFQQFrIQUgAyAjaiEnIANBCGshAyAnLwEAIAdHDQALIAkgBUEDdGo1AgQhNSAEKAKAAiAGRgRAIARBgAJqEHAgBCgChAIhGQULIBkgBkEDdGogMkIghiA1hDcCACAEIAZBAWoiBjYCiAIgBCAFIAQoAqwCIgMgAyAFSxs2AqwCIA1BCGohDQwDC0GI4AAhCQsgBCgCrAJBP0YNACAEQaQCaiAJIAcQRgwBCwsgBCgChAIhBQJAIAZBAkkNACAGQRVPBEAjAEGQIGsiAiQAAkBBwIQ9IAYgBkHAhD1PGyIDIAYgBkEBdmsiByADIAdLGyIDQYEETwRAIAJBCGogA0EEQQgQKSAFIAYgAigCDCIDIAIoAggiByAGQcEASRAFIAcgAxBnDAEFCyAFIAYgAkEQakGABCAGQcEASRAFCyACQZAgaiQADAEFCyAFQQhqIQMDQCANRQ0BIAUgAxA3IA1BCGshDSADQQhqIQMMAAsACyAEKAKAAiEJIAQoAqQCIAQoAqgCEGcgCkEBayEZIAhBAWshJiAFIAZBA3RqISMgBSENAkACQANAIA0gI0YNASANKQIAIjKnIgdBAWoiAyAyQiCIpyICSyACIAhLcg0CIA1BCGohDSACIApqIScgAyAKaiEDQQAhAgJ/AkADQCADICdGDQEgAy0AACEGIANBAWohA0HPACAGdkEBcUUgBkEGS3INAEEBIQIgF0KAgoSIgIDAACAGQQN0rYinQQFxRw0ACyAXDAELIAJBAXFFDQEgByAISw0rA0AgDiAHRQ0BGiAHIBlqIQIgB0EBayEHIAItAAAiAkEGS0HPACACdkEBcUVyDQALQoCChIiAgMAAIAJBA3StiKcLIQcgBCAyNwKIAiAEQoCAgIAgNwKAAgNAIARBIGogBEGAAmoQQiAEKAIgQQFHDQEgCCAEKAIkIgNNDSoDQCADIApqIAc6AAAgAyAmRg0BIBggCyAVIANBAWoiAxBbKAIAIgJLBEAgAiAfai0AAEEIRw0CDAEFCwsLCwwqCyAJIAUQZ0EAIQYDQCAGIAhPBEBBACEDQQAhBQNAIAMgMEcgBSAIR3EEQAJAAkAgJCADIAtqKAIAIgdLBEAgBSAKai0AACECIAcgJWoiBy0AACIGQQFxRQ0BQQEgAnRByQBxRSACQQZLcg0CIAZBAWohBgwCBQsACwJAAkAgAkEBaw4GAQIAAgIAAgsgBkECaiEGDAELIAZBAXIhBgsgByAGOgAAIANBBGohAyAFQQFqIQUMAQUgMSAKEGkgLiALEGogDCEDDA0LAAsABQsCQCAKIAggBhBfLQAAIgJBFk0EQCAGIQNBASACdEGA/OADcQ0BBQsgBkEBaiEGDAELA0ACQCADIAhGBEAgCCEDDAEFCyAKIAggAxBfLQAAIgJBFktBASACdEGA/OADcUVyDQAgA0EBaiEDDAELCwJ/IA4gBkUNABpB/wEgCiAIIAZBAWsQXy0AACICQQZLDQAaQoCChIjw//8AIAJBA3StiKcLIQICfyAvIAMgCEYNABpB/wEgCiAIIAMQXy0AACIFQQZLDQAaQoCChIjw//8AIAVBA3StiKcLIQUgBEEYaiAGIAMgCiAIEE8gFyACIAJB/wFxIgJB/wFGGyAXIAVB/wFxIAJGGyECIAQoAhwhBSAEKAIYIQYDQCAFBEAgBiACOgAAIAVBAWshBSAGQQFqIQYMAQUgAyEGDAILAAsACwALDCcLAkAgAgRAIAVBAWstAABBA0YNAQULIAMgCE8NASADIApqLQAAQQNHDQELIARBEGogAiADIAogCBBPIAQoAhQhBSAEKAIQIQYDQCAFRQ0BIAZBAzoAACAFQQFrIQUgBkEBaiEGDAALAAsABQJAAkACQCADIAhHBEACQAJAIAMgDUcEQCADIApqIgJBAWoiGS0AACEFIAJBAmotAAAhBiACLQAAIgJBA2sOBAIGBgEGBQsACyAFQQdHIAZBBkdyDQQMAwsgBkEDRg0BDAMFCwwnCyAFQQRrDgQAAQEAAQsgGSACOgAACyADQQFqIQMMAQsACwALIAIhCQwCCyAJQf8BcUECRw0BIANBBjoAAAtBAiEJDAALAAsACyADQQhqIQMMAAsACyAJIAIQSwwACwALAAsgAyAYakENIA0gDUH/AUYbOgAAIApBBmsOAgQDBQsgEAR/A0AgBkUgBUVyDQMgBkEBayEGIAUtAAAhByAFQQNrIQUgB0EBRw0ACyAEIAY2AogCQQAhCCAQQQFrBUEACyEQQQALIQcgBkUNACAZIAZBA2xqIgVBA0YNACADIA5PDRUgAyAMaiAFQQFrLQAAOgAAIAMgG08NFSADIBhqQQ0gBUECay0AACIDIANB/wFGGzoAAAwJCwALIAMgF08NEyADICAgA0EDdGoiBSgCBCASIAUoAgAbIgVPIAUgEktyDRQgBEEoaiAPIAJBAXRqIAUgAmsQEyAELQAoRQ0BIAQtAClBAUcNAQsgC0EBakEBcgwBCyALQX5xQQJqCyEDIANB/wFxQf0ASyAHciAIcgRAIAdBAWohBwwFBQsgBEGAAmogA0EQdEGB/gNyED0gEEEBaiEQDAELIAkhCiACIQMMBQsgCSEKIAIhAwwFCyADIAxqIBE6AAAMAQsgAyAWakEBOgAACyAJIQogAiEDDAALAAsACwALIARBgAJqIAYQSwsgBUECaiEFIAZBAWohBiADQQJrIQMMAAsABQsMBQULICkgHRBnIAQoApABIgcgHEEkbGohFiAEKAKMASEdIAchAkEAIRACQANAIAIgFkYNASAEIBM2ApACIAQgGjYCjAIgBEKEgICAwAA3AoACIAQgAjYCiAIgBEGYAWogBEGAAmoQJAJAIAQoApgBQQFGBEAgBCkCnAEhMiAEQQhqQQRBBEEIECkgBCgCCCEFIAQoAgwiCSAyNwIAQQEhAyAEQQE2AqACIAQgCTYCnAIgBCAFNgKYAiAEIAQoApACNgKoASAEIAQpAogCNwOgASAEIAQpAoACNwOYAUEMIQUDQCAEQaQCaiAEQZgBahAkIAQoAqQCQQFGBEAgBCgCrAIhBiAEKAKoAiEKIAQoApgCIANGBEAgBEGYAmoQYyAEKAKcAiEJBQsgBSAJaiIIIAY2AgAgCEEEayAKNgIAIAQgA0EBaiIDNgKgAiAFQQhqIQUMAQULCyAEIAQpApgCNwPwASAEIAQoAqACNgL4AQwBBQsgBEEANgL4ASAEQoCAgIDAADcC8AELIBBBAWohECACQSRqIQ4gAkEcaiEVIAIoAhwhCCACKAIYIgMhCgJAA0AgCiAIIAggCkkbIQkgCiATIAogE0sbIQwgGiAKQQF0aiEGAkADQCAJIAoiBUYNASAFIAxGDQMgBUEBaiEKIAYvAQAhDyAGQQJqIQYgD0GowABHDQALIAQoAvgBIgYgBCgC8AFGBEAgBEHwAWoQYwULIAQoAvQBIAZBA3RqIgkgCjYCBCAJIAU2AgAgBCAGQQFqNgL4AQwBCwsCQCAEKAL4ASIGQQJJDQAgBCgC9AEhBSAGQRVPBEACQAJAAkAgBSgCCCIKIAUoAgAiCUcEQCAJIApNDQEMAgULIAUoAgwgBSgCBEkNAQsgBUEUaiEKQQIhCQNAIAYgCUYNBAJAIApBBGsoAgAiDCAKQQxrKAIAIg9HBEAgDCAPTw0BDAQFCyAKKAIAIApBCGsoAgBJDQMLIApBCGohCiAJQQFqIQkMAAsACyAFQRRqIQpBAiEJA0AgBiAJRgRAIAZBAXYhCSAGQQN0IAVqQQhrIQoDQCAJRQ0FIAUpAgAhMiAFIAopAgA3AgAgCiAyNwIAIAlBAWshCSAFQQhqIQUgCkEIayEKDAALAAULAkAgCkEEaygCACIMIApBDGsoAgAiD0cEQCAMIA9PDQMMAQULIAooAgAgCkEIaygCAE8NAgsgCkEIaiEKIAlBAWohCQwACwALIAUgBkEAIAZBAXJnQQF0QT5zEAMMAQULIAUgBkEBEBULIARB8AFqIAggCBBFIARBADYCoAEgBEKAgICAwAA3ApgBIAQoAvgBQQN0IQogBCgC8AEhCCAEKAL0ASIMIQUDQAJAIAoEQCADIAUoAgAiBk8gAyAGRyAGIAUoAgQiCU9ycQ0BIARBmAFqIAMgBhBFDAEFCyAMIAgQbCACQQxqIQsgBCgCnAEiDyAEKAKgASISQQN0aiEeIAQoApgBISBBACENIA8hDAJAA0ACQAJ/AkAgDCAeRwRAIAwoAgQhAyAMKAIAIgUgAigCGCIKRgRAIAshBkEAIAMgFSgCAEYNAxoFCyADIAprIgYgBSAKayIITyAGIAIoAghNcQ0BDA8FCyATIQMgECAcSQRAIAcgEEEkbGooAhghAwULIBUoAgAiAiADSyADIBNLcg0EIARBgAFqIBogAkEBdGogAyACaxBJICAgDxBnIA4hAgwICyACKAIEIREgBEGYAWogBiAIayIKEG0gBCgCnAEhCQJAIAMgBUYEQCAEKAKgASEKDAEFCyAJIAggEWogChALGgsgAyAFSSADIBNLcg0BIAQoApgBIQggGiAFQQF0aiADIAVrIAItACAgCSAKEBYgBEGAAmoiBiAJIAogBRAJIAggCRBpQQELIRcgDUEBaiENIAxBCGohCiAGKAIEIgggBigCCEEMbGohIQNAAkACQAJAAkAgCCAhRwRAIAgoAgQiAyAIKAIAIgVJIAMgE0tyDRIgBEGYAWogGiAFQQF0aiADIAVrEDkgBCgCoAEhESAEKAKcASEGIAgtAAkNBCAGIBEQLiAGIBFBAXRqIRsgBiEJA0AgCSAbRgRAQQEhAwNAIAMgEU8NByARIANBAWsiBU0NBQJAQbyDwAAgBiAFQQF0ai8BABBHRQ0AQcKDwAAgBiADQQF0ai8BABBHRQ0AIAYgESAFIAMQPiADQQFqIQMLIANBAWohAwwACwAFC0GsAyEDQQAhBSAJLwEAIRQDQCADQQJPBEAgBSAFIANBAXYiGGoiBSAFQQJ0LwGg3UAgFEsbIQUgAyAYayEDDAEFCwsgCSAUIAVBAnQvAaDdQCIDRgR/IAUgAyAUSWoiA0GrA0sNAyADQQJ0LwGi3UAFIBQLOwEAIAlBAmohCQwACwAFCyATIA8gDUEDdGogFSANIBJJGygCACIDSSADIAwoAgQiBUlyDREgBEGAAWogGiAFQQF0aiADIAVrEEkgF0UNAiAEKAKAAiAEKAKEAhBoDAILAAsACyAKIQwMAwsgCEEMaiEIIARBgAFqIAYgERBJIAQoApgBIAYQawwACwALCwwKCwwJCyAFQQhqIQUgCSADIAMgCUkbIQMgCkEIayEKDAALAAsLAAsgACAEKAKIATYCCCAAIAQpAoABNwIAIAchAwNAIBwEQCADKAIAIANBBGooAgAQaSADQQxqKAIAIANBEGooAgAQaCAcQQFrIRwgA0EkaiEDDAEFCwsgHSAHQQRBJBAyIAEoAgAgASgCBBBrDAILIAQoAswBIQMgBCgC2AEgBhBpIAQoAsABIAQoAsQBEGkgLSAgEGcgDyASIBEgAiAHEBYgKyACIAcgHhAJIAQgEToAuAEgBCAhNgK0ASAEIB42ArABIAQgBzYCoAEgBCACNgKcASAEIAM2ApgBICwgHEEkbGogBEGYAWpBJBALGiAcQQFqIRwMAAsACyAEQbACaiQADwsACwALAAvGLAIUfwJ+IwBBgAJrIgckACAHQQA2AiQgB0KAgICAIDcCHCAHQQA2AjAgB0KAgICAwAA3AiggB0EANgI8IAdCgICAgMAANwI0QQQhDwJAIANBgIABcUUEQCACIQoMAQULIANBmIABcSEKAkAgA0EEcSIQBEAgB0G4AWoiCCACEGUgCCACEGIgAkEBayEIIAIgBygCwAEiCWohDSAHKAK8ASAJQQJ0aiEJA0AgCEF/RwRAIAkgCDYCACAIQQFrIQggCUEEaiEJDAEFCwsgByAHKQK4ATcDQCAHIA02AkgMAQULIAdBQGtBACACECILIAcoAkQiDSAHKAJIQQJ0aiESIAcoAkAhEyAKQZiAAUYhFCANIQkDQCAHKAIkIgpFIAcoAiAgCkEBdGpBAmsiFkVyIAcoAjAiCkUgBygCLCAKQQJ0akEEayIRRXJyIRUgC0H//wNxIQ5BASEKA0ACQAJAAkAgCSASRwRAIAIgCSgCACIPTQ0BIAEgD0EBdGovAQAiCxAoIQggFA0CDAMFCyATIA0QaiAHKAIkIQogBygCICEBIBBFBEAgBygCMCERIAcoAiwhDwwGBQsgASAKEC5BACELQQAgBygCMCIRQQF2IgxrIQ0gBygCLCIPIBFBAnRqQQRrIQggDyEJA0AgCyANRg0GIAtBAWshCyAMBEAgCSgCACEOIAkgCCgCADYCACAIIA42AgAgCUEEaiEJIAhBBGshCAwBBQsLAAsACyAKQX9zIAggDHJBwAFxQcABR3JBAXENACALQf//A3EiCiAOIAogDkkbQe4TayEIIBVFBEAgFiAIOwEAIAdBNGogDyARKAIAIgogCiAPSRsgDyAKIAogD0sbEEUgESAPIBEoAgAiCiAKIA9LGzYCAAULIAlBBGohCUEAIQogCBAoIQwMAQsLIAlBBGohCSAHQRxqIAsQTCAHQShqIA8QSyAIIQwMAAsACwJAAkACQCADQRhxIg5BGEcEQCAKIQ0gDkEIRw0CIANBg4AEcSIIRSELIANBgIA4cUGAgCBGIgwNASAIDQMMAQULIAohDSADQYOABHENAkEAIQxBASELCyAKQQFrIRAgA0EEcQRAQQAhCCABIQkgCiENA0AgCCAKRg0CAkACQAJAIAtFDQAgCS8BAEGiDGsiEkEFS0EBIBJ0QStxRXIgCCAQT3INACAJQQJqLwEAQcQMRw0AIA0NAUEAIQ0MAgsgDEUNASANRSAJLwEAIhJB0QxGIBJBywxrQf//A3FBB0tycg0BCyANQQFrIQ0LIAlBAmohCSAIQQFqIQgMAAsABQtBACEIIAEhCSAKIQ0DQCAIIApGDQECQAJAAkAgC0UNACAJLwEAQcQMRyAIIBBPcg0AIAlBAmovAQBBogxrIhJBBUtBASASdEErcUVyDQAgDQ0BQQAhDQwCCyAMRQ0BIA1FIAkvAQAiEkHRDEYgEkHLDGtB//8DcUEHS3JyDQELIA1BAWshDQsgCUECaiEJIAhBAWohCAwACwALIANBm4AEcUEQRw0AIAohCCABIQkDQCAIRQ0BIA0gDUEARyAJLwEAQYsCakH//wNxQQhJcWohDSAIQQFrIQggCUECaiEJDAALAAsgB0HQAGoiCCAKIA0gCiANSxsQbyAIIAEgChBJAkAgEQRAIAdB4ABqIA8gERA4DAEFCyAHQeAAakEAIAcoAlgQIgsgB0EANgJwIAdBADYCdCADQQRxIhJFBEAgBygCVCIBIAcoAlgiCiAHQfAAaiAHQfQAahAjIAEgCiAHKAJwIgEgBygCdCIKEDsgBygCZCAHKAJoIAEgChAfBQsCfyADQYSAgCBxIhVBhICAIEcEQEEDIRNBAiERQYCAECEQQYCAGAwBBQtBAiETQQMhEUGAgBghEEGAgBALIQ8CQAJAIA5BCEcEQCAOQRhHDQJBACEIDAEFCyADQYCAOHEiAUGAgDBGIAFFckUEQEECIQgMAQULIAcoAlQiCSAHKAJYIgtBARAIIAFBgIAwRw0BA0AgC0UNAgJAAkACfwJAAkACQCAJLwEAIgFB8P8DcUHw/ANGBEBB/fwDIQoCQAJAIAFB8/wDaw4DAwEDAAsgAUH9/ANGDQYLIAHBQbuGwABqLQAAQQFrDgICBQMFCyABQfL5A0kNAEH9/AMhCiABQf38A0YgAUH1+QNJcg0ECyABQZwHakH//wNxQfr/A0kMAgtBwAwhCgwCCyABQY0DakH9/wNxRQshCCABQfz8A0YNAUEgIQogCA0BCyAJIAo7AQALIAtBAWshCyAJQQJqIQkMAAsACyAHKAJUIAcoAlggCBAICyASRQRAIAcoAlQiASAHKAJYIgogB0HwAGogB0H0AGoQIyABIAogBygCcCIBIAcoAnQiChA7IAcoAmQgBygCaCABIAoQHwULAkACQAJAAkACQAJAIA5BA3ZBAWsOAwABAAULIAcoAmghDCAHKAJkIQsgBygCWCEKIAcoAlQhASADQYOABHEiCEEBRw0BIApBAXQhCSABIQgDQCAJRQ0DIAgvAQBB//8DRgRAIAhBIDsBAAULIAhBAmohCCAJQQJrIQkMAAsACyAHKAJYIQEgBygCVCEIAkAgA0GAgIAccUGAgIAIRiIMRSADQYCAgANxIgtBgICAAUdxDQBBAUF/IBIbIg9BAXQhCkEAIQkgEkUhESALQYCAgAFGIQ4gCCELA0AgASAJRg0BAkAgCUUgEXEgCSAPaiABT3INAAJAAkAgDEUNACALLwEAIhBBgP0DRyAQQaEMR3ENACAKIAtqIhAvAQAiFEHv/QNrQQJPIBRByQxHcQ0AIBBBpgw7AQAMAQsgDkUNASALLwEAIhBB8/wDRyAQQYvAAEdxDQEgCiALai8BAEHPAmpB//8DcSIQQQ1LQbPmACAQdkEBcUVyDQELIAtBIDsBAAsgCUEBaiEJIAtBAmohCwwACwALIAFBAXQhCQNAAkACQAJAAn8CQAJAAkAgCQRAQZ6FwAAgCC8BACIBEEdFBEBBpIXAACABEEdFIAFB8/wDRnINCCABQZADakH//wNxIgFBjAFLDQIgAUEBdC8BlLlAIQsMBwULIAFBsAlqQf//A3EiAUGwAUkNBQAFCyAHIBJFOgB7IAdBADYChAEgB0KAgICAwAA3AnwgB0GIAWogBygCWCIBQQFqIgoQbyAHQZQBaiAKEGUgBygCaCIKIAEgASAKSxshCCAHKAJUIQkgBygCZCIKIQsDQAJAAkAgCARAIAkvAQAiAUGLAmpB//8DcSIMQQhJDQEgB0GIAWogARBMIAdBlAFqIAsoAgAQSwwCBQsCQCAHKAKEASIOBEAgBygCUCAHKAJUEGsgByAHKAKQATYCWCAHIAcpAogBNwNQIAcoAmAgChBqIAcgBygCnAE2AmggByAHKQKUATcDYCADQYOABHEiDA0BDAcFCyAHKAKUASAHKAKYARBqIAcoAogBIAcoAowBEGsMBgsgBygCWCIKQQF0IQhBACELIAcoAlQiASEJA0AgCEUEQCAKIQsMBgULIAkvAQBBIEcNBSAIQQJrIQggC0EBaiELIAlBAmohCQwACwALIAxBAXQvAa67QCEBAkAgEgRAIAdB/ABqIAcoApABEEsgB0HEDDsBugEgByABOwG4ASAHQYgBaiAHQbgBakECEEkMAQULIAcgATsBugEgB0HEDDsBuAEgB0GIAWogB0G4AWpBAhBJIAdB/ABqIAcoApABQQFrEEsLIAcgCygCACIBNgK8ASAHIAE2ArgBIAdBlAFqIAdBuAFqQQIQSAsgCEEBayEIIAtBBGohCyAJQQJqIQkMAAsACwALIA4gCyALIA5LGyELIApBAXQhCCABQQJrIQFBACEJAn8DQAJAAkAgCARAIAEgCGovAQBBIEYNASAJIQoFC0EAIRQgB0GgAWpBACALECIgB0GsAWogBygCWCIBIA4gCiAKIA5LG2sgARAiIAcgBygCgAEiASAOQQJ0ajYC3AEgByABNgLYASAHIAdB0ABqNgLkASAHIAdB+wBqNgLgASAHQRBqIAdB2AFqECVBASEWQQQhD0EAIRFBACAHKAIQQQFHDQMaIAcoAhQhASAHQbgBakEEEGUgBygCvAEgATYCACAHQQE2AvgBIAcgBykCuAE3A/ABIAcgBykC4AE3A8ABIAcgBykC2AE3A7gBA0AgB0EIaiAHQbgBahAlIAcoAghBAUcNAiAHKAIMIQogBygC+AEiASAHKALwAUYEQCAHQfABakEBEGIFCyAHKAL0ASABQQJ0aiAKNgIAIAcgAUEBajYC+AEMAAsACyAIQQJrIQggCUEBaiEJDAELCyAHKAL0ASEPIAcoAvABIREgBygC+AELIQECfyAMQQFGBEAgESEMIA8hCkEBDAEFCyAMQYCABEYEQCAHIAdBoAFqIgogB0GsAWoiCCAVQYSAgCBGIgkbIgwoAgg2AuABIAcgDCkCACIbNwPYASAHIAggCiAJGyIKKAIINgLsASAHIAopAgA3AuQBIAdBAjYCvAEgByAbNwLAASAHIAcpA+ABNwLIASAHIAcpA+gBNwLQASAHQcgBaiEIQQAhC0EBIQkCQAJAA0AgCUEDRg0BIAhBBGsoAgAhCiAIQQhrKAIAIQwCQAJAIA4gCCgCAEcEQCAMIAoQagwBBQsgDEF/Rw0BCyALQQFqIQsgCEEMaiEIIAlBAWohCQwBCwsgByAJNgK4ASARIA8QaiAOIQEMAQsgByALNgK4ASARIQwgDyEKIAshCQsgCUECRwRAIAdBzAFqQQEQUAULQQAhFkEADAEFCyAMIBNHBEBBACEWQQEhFCAHKAK0ASEBIAcoArABIQogBygCrAEhDEEBDAEFC0EBIRQgBygCqAEhASAHKAKkASEKIAcoAqABIQxBAAshFSAHKAJUIRcgBygCZCETIAcoAlghCCAHKAJoIQkgB0IENwLIASAHQgA3AsABIAdCgICAgCA3ArgBIAkgCCAIIAlLGyEYIAdBxAFqIRlBACEQA0AgECILIBhHBEAgC0EBaiEQIAEEQEEAIQkgASEIA0AgCEECTwRAIAkgCSAIQQF2IhpqIgkgCiAJQQJ0aigCACALSxshCSAIIBprIQgMAQULCyAKIAlBAnRqKAIAIAtGDQIFCyATIAtBAnRqKAIAIQggB0G4AWogFyALQQF0ai8BABBMIBkgCBBLDAEFCwsgByAHKQLIATcD6AEgByAHKQLAASIbNwPgASAHIAcpArgBIhw3A9gBIAcgGz4C+AEgByAcNwPwASAHIAcoAuwBNgLAASAHIAcpAuQBNwO4ASAHKAJQIAcoAlQQayAHIAcoAvgBNgJYIAcgBykD8AE3A1AgBygCYCATEGogByAHKALAATYCaCAHIAcpA7gBNwNgIAwgChBqIBQEQCARIA8QagULIBYEQCAHKAKsASAHKAKwARBqBQsgFQRAIAcoAqABIAcoAqQBEGoFCyABIA5GDAELQQELIRMgBygCfCAHKAKAARBqDAYLIAFBAXQvAbS2QCILRQ0BCyAIIAs7AQALIAhBAmohCCAJQQJrIQkMAAsACyAIIBNGIg4gFUGEgIAgRnIhCSAIQYCABEYgDnJFBEBBACEJIAggEUcNAQULIAEgCiALIAxB//8DIAkQDAsgDyADQYCAOHEiCEcgCCAQR3FFBEAgASAKIAsgDEH+/wMgCCAQRhAMBQtB83xBi8AAIANBgICAwABxGyEPQX9BASASGyEQQQAhCCAHKAJYIREgBygCVCEOIANBgICAA3FBgICAAUchFCADQYCAgBxxIhZBgICACEYhFUEBIRMDQCAIIBEgCCARSxshASAOIAhBAXRqIQkCfwNAIAEgCEYNAwJAAkACfyAURQRAIAkvAQAiCkHPAmpB//8DcSIMQQ5JQbPmACAMdnEiDCAVDQEaIAxFDQJBASELIAhBAWoMBQULIBZBgICACEcNASAJLwEAIQpBAAshCyALIApB9wJqQf//A3FBAklyDQELIAhBAWohCCAJQQJqIQkMAQsLIAhBAWoLIQECQEEAIBIgCBsNACAOIAggEGoiDEEBdGohCSAMIBFPDQAgCS8BAEEgRw0AIA8hDAJAIAsNACAKQfcCakH//wNxIgpBAkkEQCAOIAhBAXRqIApBAXQvAb67QDsBAEGA/QMhDAwBBQsACyAJIAw7AQAgASEIDAELIAEhCEEAIRMMAAsACyAGIBMgBi0AAHE6AAALIAdB2AFqIA0QbyAFQQA2AgggBEEANgIIIAUgAhAtIAdBADYCwAEgB0KAgICAwAA3ArgBIAcoAmQiBiAHKAJoQQJ0aiELIAcoAlQiCiAHKAJYQQF0aiEPIAcoAmAhESAHKAJQIQ4gBiEJIAohCAJAA0ACQAJAAkACQCAIIA9GIAkgC0ZyRQRAIAgvAQAhDSAJKAIAIQwgCUEEaiIBIQkgCEECaiICIQggDUH+/wNrDgIFAgEFCyAOIAoQayARIAYQaiAHKAI8QQN0IQkgBygCNCEBIAcoAjgiAiEIA0AgCQRAIAgoAgAhBCAFKAIEIgYgBSgCCCIKIAgoAgQQWikCACEbIAYgCiAEEFogGzcCACAJQQhrIQkgCEEIaiEIDAEFCwsgAiABEGxB8A1B4AwgA0GABnFBgAJGIgQbIQJBACEJIAcoAuABIQEgBygC3AEhCAJAAkACQCADQeABcUEga0EFdg4EAgYBAAgLQQEhCQsgByAJOgCsASAHIAI7AfABIBIEQCABQQF0IQUgCEECayEBA0AgBUUNCCAHQawBaiAHQfABaiABIAVqEDYgBUECayEFDAALAAULIAFBAXQhBQNAIAVFDQcgBUECayEFIAdBrAFqIAdB8AFqIAgQNiAIQQJqIQgMAAsACyABQQF0IQUgAkEwayEBA0AgBUUNBiAILwEAIgJBMGtB//8DcUEKSQRAIAggASACajsBAAULIAhBAmohCCAFQQJrIQUMAAsACwJAIA1BiwJqQf//A3FBB0sNACAHKALAASEIIAdBADYCwAEgCEECdCEIIAcoArwBIQkDQCAIRQ0BIAcoAuABIRAgBSgCBCAFKAIIIAkoAgAQWiITIBA2AgQgE0EBNgIAIAhBBGshCCAJQQRqIQkMAAsACyAFKAIEIAUoAgggDBBaIggoAgBFBEAgCCAHKALgATYCBCAIQQE2AgAFCyAHQdgBaiANEEwgBCAMEEsMAQsgEgRAIAdBuAFqIAwQSwwBBQsgBygC4AEiCUEBdCEIIAcoAtwBQQJrIQ0CfwNAQQAgCEUNARogCUEBayEJIAggDWohECAIQQJrIQggEC8BAEGLAmpB//8DcUEHSw0AC0EBCyEIIAUoAgQgBSgCCCAMEFoiDCAJNgIEIAwgCDYCAAsgASEJIAIhCAwBCwtBwHJB0HMgBBshAyABQQF0IQUDQCAFRQ0BIAgvAQAiASACa0H//wNxQQpJBEAgCCABIANqOwEABQsgCEECaiEIIAVBAmshBQwACwALIAAgBygC4AE2AgggACAHKQLYATcCACAHKAK4ASAHKAK8ARBqIAcoAiggBygCLBBqIAcoAhwgBygCIBBrIAdBgAJqJAALkRACCH8BfiMAQZACayIGJAADQAJAAkAgAUEhTwRAIAMNASAAIQIgASIDIAFBAXZqIQQDQCAEBEACfyADIARBAWsiBEsEQCACKQIAIQwgAiACIARBA3RqIgApAgA3AgAgACAMNwIAQQAMAQULIAQgA2sLIQEgAyAEIAMgBEkbIQUDQCAFIAFBAXQiB0EBciIATQ0CIAUgB0ECaiIHSwRAAn8gAiAAQQN0aiIIKAIAIgogAiAHQQN0aiIHKAIAIglHBEAgCSAKSwwBBQsgCCgCBCAHKAIESQsgAGohAAULAkAgAiABQQN0aiIBKAIAIgggAiAAQQN0aiIHKAIAIgpHBEAgCCAKTw0EDAEFCyABKAIEIAcoAgRPDQMLIAEgB0EIEBwgACEBDAALAAULCwwCBQsgAUECSQ0BIAEgAUEBdiICIAFBEkkiChshAyABIAJrIQcgACACQQN0aiEFIAAhBANAAkACfyADQQxNBEBBASEIIANBCE0NAiAEQQBBAxAxIARBAUEHEDEgBEECQQUQMSAEQQRBCBAxIARBAEEHEDEgBEECQQQQMSAEQQNBCBAxIARBBUEGEDEgBEEAQQIQMSAEQQFBAxAxIARBBEEFEDEgBEEHQQgQMSAEQQFBBBAxIARBA0EGEDEgBEEFQQcQMSAEQQBBARAxIARBAkEEEDEgBEEDQQUQMSAEQQZBCBAxIARBAkEDEDEgBEEEQQUQMSAEQQZBBxAxIARBAUECEDFBCQwBBQsgBEEAQQwQMSAEQQFBChAxIARBAkEJEDEgBEEDQQcQMSAEQQVBCxAxIARBBkEIEDEgBEEBQQYQMSAEQQJBAxAxIARBBEELEDEgBEEHQQkQMSAEQQhBChAxIARBAEEEEDEgBEEBQQIQMSAEQQNBBhAxIARBB0EIEDEgBEEJQQoQMSAEQQtBDBAxIARBBEEGEDEgBEEFQQkQMSAEQQhBCxAxIARBCkEMEDEgBEEAQQUQMSAEQQNBCBAxIARBBEEHEDEgBEEGQQsQMSAEQQlBChAxIARBAEEBEDEgBEECQQUQMSAEQQZBCRAxIARBB0EIEDEgBEEKQQsQMSAEQQFBAxAxIARBAkEEEDEgBEEFQQYQMSAEQQlBChAxIARBAUECEDEgBEEDQQQQMSAEQQVBBxAxIARBBkEIEDEgBEECQQMQMSAEQQRBBRAxIARBBkEHEDEgBEEIQQkQMUENCyEIIARBA0EEEDEgBEEFQQYQMQsgBCADIAgQFSAKDQIgACAERiEIIAUhBCAHIQMgCA0ACyAEQQhrIQMgACABQQN0QQhrIgRqIQggBkEIaiIHIARqIQogACEEA0AgAgRAIAcgBSAEAn8gBSgCACIJIAQoAgAiC0cEQCAJIAtJDAEFCyAFKAIEIAQoAgRJCyIJGykCADcCACAHQQhqIQcgCUEDdCAFaiEFIAlBAXNBA3QgBGohBCAKIAMgCAJ/IAgoAgAiCSADKAIAIgtHBEAgCSALSQwBBQsgCCgCBCADKAIESQsiCRspAgA3AgAgAkEBayECIApBCGshCiADIAlBA3QiCWshAyAIIAlqQQhrIQgMAQULCyADQQhqIQIgAUEBcQRAIAcgBCAFIAIgBEsiAxspAgA3AgAgBSACIARNQQN0aiEFIAQgA0EDdGohBAULIAhBCGogBUcgAiAER3JFBEAgACAGQQhqIAFBA3QQCxoMAgULAAsgACABQQN2IgdBOGxqIQQgACAHQQV0aiEFIANBAWshAwJ/IAFBwABPBEAgACAFIAQgBxAaDAEFCyAAAn8gACgCACIHIAUoAgAiCEcEQCAHIAhJDAEFCyAAKAIEIAUoAgRJCyIJAn8gByAEKAIAIgpHBEAgByAKSQwBBQsgACgCBCAEKAIESQtHDQAaIAQgBSAIIApHBH8gCCAKSQUgBSgCBCAEKAIESQsgCXMbCyAAayEFAkACQAJAIAJFDQACQCACKAIAIgcgACAFaiIEKAIAIghHBEAgByAISQ0CDAEFCyACKAIEIAQoAgRJDQELIAApAgAhDCAAIAQpAgA3AgAgBCAMNwIAIAYgACkCCDcCiAIgBkEANgIUIAYgAEEQaiIENgIQIAYgAEEIaiICNgIIIAAgAUEDdGoiBUEIayEHIAYgBkGIAmo2AgwDQCAEIAdPBEADQCAEIAVHBEAgACACIAZBCGoQKiAGKAIQIQQMAQULCwUgACACIAZBCGoiBBAqIAAgAiAEECogBigCECEEDAELCyAGIAYoAgw2AhAgACACIAZBCGoQKiABIAYoAhQiAk0NASAAKQIAIQwgACAAIAJBA3RqIgQpAgA3AgAgBCAMNwIAIAEgAkEBaiICayEBIAAgAkEDdGohAEEAIQIMBAsgACkCACEMIAAgACAFaiIEKQIANwIAIAQgDDcCACAGIAApAgg3AogCIAZBADYCFCAGIABBEGoiBDYCECAGIABBCGoiBTYCCCAAIAFBA3RqIgdBCGshCCAGIAZBiAJqNgIMA0AgBCAITwRAA0AgBCAHRwRAIAAgBSAGQQhqECwgBigCECEEDAEFCwsFIAAgBSAGQQhqIgQQLCAAIAUgBBAsIAYoAhAhBAwBCwsgBiAGKAIMNgIQIAAgBSAGQQhqECwgASAGKAIUIgVLDQELAAsgACkCACEMIAAgACAFQQN0aiIEKQIANwIAIAQgDDcCACAAIAUgAiADEAMgASAFQX9zaiEBIARBCGohACAEIQIMAQsLIAZBkAJqJAAL9gsCDX8BfiMAQSBrIgskACACQQhrIRICQAJAA0ACQAJAAkACQCABQSFPBEAgBA0BIAAgASACIANBARAFDAcFCyABQQJJDQYgAyABQRBqSQ0DIAFBAXYhAyABQQ9LDQEgAUEHTQRAIAIgACkCADcCACACIANBA3QiBGogACAEaikCADcCAEEBIQQMBgULIAAgAhAbIAAgA0EDdCIEaiACIARqEBtBBCEEDAULIAAgAUEDdiIJQThsaiEGIAAgCUEFdGohByAEQQFrIQQgCwJ/IAFBwABPBEAgACAHIAYgCRAnDAEFCyAAIAYgByAAKAIAIgkgBygCACIHSSIMIAcgBigCACIGSXMbIAwgBiAJS3MbCyIOKQIAIhM3AxAgDiAAa0EDdiEMIAUEQCAFKAIAIBOnTw0CBQsgASADSw0CIAIgAUEDdCINaiEIQQAhByAAIQYgDCEJA0AgACAJQQNrIgpBACAJIApPG0EDdGohDwNAIAYgD0kEQCAHQQN0IAIgCEEIayAOKAIAIgogBigCAEsiEBtqIAYpAgA3AgAgByAQaiIHQQN0IAIgCEEQayAGQQhqIhAoAgAgCkkiERtqIBApAgA3AgAgByARaiIHQQN0IAIgCEEYayAGQRBqIhAoAgAgCkkiERtqIBApAgA3AgAgByARaiIHQQN0IAIgCEEgayIIIAogBkEYaiIQKAIASyIKG2ogECkCADcCACAHIApqIQcgBkEgaiEGDAEFCwsgACAJQQN0aiEKA0AgBiAKTwRAAkAgASAJRwRAIAhBCGsiCCAHQQN0aiAGKQIANwIAIAZBCGohBiABIQkMBAULIA0gEmohCCAAIAIgByIGQQN0IgkQCyAJaiIKIQkDQCABIAZHBEAgCSAIKQIANwIAIAZBAWohBiAJQQhqIQkgCEEIayEIDAEFCwsgB0UNBSABIAdJDQAgCiABIAdrIAIgAyAEIAtBEGoQBCAHIQEMBwsFIAdBA3QgAiAIQQhrIgggBigCACAOKAIASSIPG2ogBikCADcCACAGQQhqIQYgByAPaiEHDAELCwsACyAAIAIgAiABQQN0aiIEEFMgACADQQN0IgVqIAIgBWogBEFAaxBTQQghBAwDCyABIANLDQAgAiABQQN0IglqIQhBACEHIAAhBgNAIAAgDEEDayIFQQAgBSAMTRtBA3RqIQoDQCAGIApJBEAgB0EDdCACIAhBCGsgDigCACIFIAYoAgBPIg0baiAGKQIANwIAIAcgDWoiB0EDdCACIAhBEGsgBSAGQQhqIg0oAgBPIg8baiANKQIANwIAIAcgD2oiB0EDdCACIAhBGGsgBSAGQRBqIg0oAgBPIg8baiANKQIANwIAIAcgD2oiB0EDdCACIAhBIGsiCCAFIAZBGGoiDSgCAE8iBRtqIA0pAgA3AgAgBSAHaiEHIAZBIGohBgwBBQsLIAAgDEEDdGohBQNAIAUgBk0EQCABIAxHBEAgAiAHQQN0aiAGKQIANwIAIAZBCGohBiAHQQFqIQcgCEEIayEIIAEhDAwDBQsgASAHayEGIAkgEmohCCAAIAIgB0EDdCIAEAsiBSAAaiEJA0AgBgRAIAkgCCkCADcCACAGQQFrIQYgCUEIaiEJIAhBCGshCAwBBQsLIAtBCGogByAFIAEQUSALKAIMIQEgCygCCCEAQQAhBQwEBSAHQQN0IAIgCEEIayIIIA4oAgAgBigCAE8iChtqIAYpAgA3AgAgBkEIaiEGIAcgCmohBwwBCwALAAsACwsACyALQoCAgIAgNwIQIAsgA61CIIY3AhhBACAEayEJIAEgA2shDCACIARBA3QiBWohDiAAIAVqIQoDQAJAIAsgC0EQahBCIAsoAgBBAUcNACAJIAwgAyALKAIEIgUbIgYgBCAEIAZJG2ohCCAOIAVBA3QiBWohBiAFIApqIQcgAiAFaiEFA0AgCEUNAiAGIAcpAgA3AgAgBSAGEDcgCEEBayEIIAZBCGohBiAHQQhqIQcMAAsACwsgAiABIAAQEAsgC0EgaiQAC4MJAhJ/An4jAEGAA2siCCQAQoCAgICAgICAwAAgAa0iF4AiGCAXfkKAgICAgICAgMAAUq0hFwJ/IAFBgSBPBEBBASABQQFyZ0EfcyIFIAVBAXZrIgV0IAEgBXZqQQF2DAEFC0HAACABIAFBAXZrIgUgBUHAAE8bCyEQIBcgGHwhFyAAQQhrIRVBASEJA0BBASEMQQAhESABIA1LBEAgCEEoaiANIAAgARBRIAgoAighCiAXIA1BAXQCfwJAIBAgCCgCLCIGSw0AIAZBAk8EfwJAAkAgCigCCCAKKAIASSISRQRAIApBCGohC0ECIQUDQCAFIAZGDQIgC0EIaiIMKAIAIAsoAgBJDQMgBUEBaiEFIAwhCwwACwAFCyAKQQhqIQtBAiEFA0AgBSAGRg0BIAtBCGoiDCgCACALKAIATw0CIAVBAWohBSAMIQsMAAsACyAGIQULIAUgEEkNAQJAIBJFDQAgCEEgaiAFIAogBhBYIAgoAiQiDEEBdiEGIAgoAiAiCyAMQQN0akEIayEMA0AgBkUNASALIAxBCBAcIAZBAWshBiALQQhqIQsgDEEIayEMDAALAAsgBQUgBgtBAXRBAXIMAQsgBiAQIAYgEEkbQQF0IARFDQAaIAhBGGpBICAGIAZBIE8bIgUgCiAGEFggCCgCGCAIKAIcIAIgA0EAQQAQBCAFQQF0QQFyCyIMQQF2aq1+IA0gCUEBdmutIA2tfCAXfoV5pyERBQsgFSANQQN0IgVqIRIgACAFaiEWA0ACQAJAAkACQAJAAkAgB0ECTwRAIAdBAWsiCyAIQb4CamotAAAgEU8NAQULIAhBvgJqIAdqIBE6AAAgCEE0aiAHQQJ0aiAJNgIAIAEgDU0NASAHQQFqIQcgDEEBdiANaiENIAwhCQwHCyAIQTRqIAtBAnRqKAIAIgVBAXYiDiAJQQF2IgpqIQ8gBUEBcSIFIAMgD0lyIAlBAXEiB3JFDQEgACANIA9rQQN0aiEJIAVFBEAgCEEQaiAOIAkgDxBYIAgoAhAgCCgCFCACIAMQVAULIAdFBEAgCEEIaiAOIAkgDxBRIAgoAgggCCgCDCACIAMQVAULIApFIA5FciADIAogDiAKIA5JIgUbSXINBCAJIA5BA3QiBmohByAFBEAgAiAHIApBA3QiBRALIgogBWohBiASIQUDQCAFIAdBCGsiFCAGQQhrIgYgBigCACITIBQoAgAiDkkiBxspAgA3AgAgBiAHQQN0aiEGIAkgFCAOIBNNQQN0aiIHRg0EIAVBCGshBSAGIApHDQALDAMFCyAGIAIgCSAGEAsiBWohBgNAIAUgBkYgByAWRnINBCAJIAcgBSAHKAIAIhMgBSgCACIOSSIKGykCADcCACAJQQhqIQkgByAKQQN0aiEHIAUgDiATTUEDdGohBQwACwALIAlBAXFFBEAgACABIAIgAxBUBQsgCEGAA2okAA8LIA9BAXQhCSALIQcMAwsgByEJIAohBQsgCSAFIAYgBWsQCxoLIA9BAXRBAXIhCSALIQcMAAsACwALkAkBDn8jAEEgayEEAkACfwJAIAIiBSAAIgcgAWtLBEAgASACaiICIQYgACAFaiIAIAVBEEkNAhogAEF8cSEHIAJBAWshA0EAIABBA3EiCWshCCAAIQIDQCACIAdLBEAgAkEBayICIAMtAAA6AAAgA0EBayEDDAEFCwsgByAFIAlrIg5BfHEiAmshC0EAIQNBACACayEPIAYgCGoiCUEDcSIGRQRAIAEgDmpBBGshASAHIQADQCAAIAtNDQMgAEEEayIAIAEoAgA2AgAgAUEEayEBDAALAAULIARBADoAGCAEQQA6ABYgCSAGayECQQIhDAJ/IAlBAnFFBEBBACEMIARBGGoMAQULIAQgAi0AACIDOgAYIAItAAFBCHQhCiAEQRZqCyEQIAlBAXEEQCAQIAIgDGotAAA6AAAgBC0AFkEQdCENIAQtABghAwULIAtBBGohDCABIAVBBGsiEGohAkEAIAZBA3QiBWtBGHEhCyABIBAgBmtqIQEgCiADQf8BcXIgDXIhAwNAIAwgACAIaiIKTwRAQQAhACAEQQA2AhwgBEEcaiAGciEBQQQgBmsiBkEBcQRAIAEgAiAIai0AADoAAEEBIQAFCyAGQQJxBEAgACABaiACIAAgCGpqLwEAOwEABQsgCkEEayADIAt0IAQoAhwgBXZyNgIADAMFIAggAEEEayIAaiADIAt0IAEgCGooAgAiAyAFdnI2AgAgAUEEayEBIAJBBGshAgwBCwALAAULAkAgBUEQSQRADAEFCyAHQQAgB2tBA3EiBmohAyABIQIDQCAAIANJBEAgACACLQAAOgAAIAJBAWohAiAAQQFqIQAMAQULCyADIAUgBmsiC0F8cSIPaiEAAkAgASAGaiIIQQNxIgJFBEAgCCEBA0AgACADTQ0CIAMgASgCADYCACABQQRqIQEgA0EEaiEDDAALAAULIARBADYCECAEQRBqIAJyIQVBACEDQQQgAmsiCUEBcQRAIAUgCC0AADoAAEEBIQMFCyAJQQJxBEAgAyAFaiADIAhqLwEAOwEABQsgASACayEBQQAgAkEDdCIJa0EYcSENIAQoAhAhAyAHIQIDQCAAIAIgBmoiB0EEak0EQEEAIQIgBEEAOgAMIARBADoACkECIQ4CfyAIQQJxRQRAQQAhBUEAIQ4gBEEMagwBBQsgBCABIAZqIgpBBGotAAAiBToADCAKQQVqLQAAQQh0IQogBEEKagshDCAHIAhBAXEEfyAMIAEgBiAOampBBGotAAA6AAAgBC0ACkEQdCECIAQtAAwFIAULIAIgCnJyIA10IAMgCXZyNgIABSAHIAMgCXYgASAGakEEaigCACIDIA10cjYCACACQQRqIQIgAUEEaiEBDAELCwsgC0EDcSEFIAggD2ohAQsgACAFaiECA0AgACACTw0DIAAgAS0AADoAACABQQFqIQEgAEEBaiEADAALAAsgDkEDcSEFIAkgD2ohBiAHIA9qCyEAIAZBAWshASAAIAVrIQIDQCAAIAJNDQEgAEEBayIAIAEtAAA6AAAgAUEBayEBDAALAAsL8QYBCH8jAEHQAGsiAiQAIAIgAUECQQQQKSACQQA2AhAgAiACKQMANwIIIAJBFGogARBlIAJBADsBRCACIAA2AkggAiAAIAFBAXRqNgJMA0AgAkE4aiIEIAJBxABqEBQgAkEUagJ/AkAgAi8BOCIABEAgAEH//wNHDQEgAkEgaiABEG0gAigCDCEGIAJBLGogAigCECIAEG0gBCAAEG8gAEECdCEDIAYhAUEAIQcDQCABIQQCQAJAIAMEQCABLQACIgBFDQIgB0H/AXEiAUUgACABRnINASACQcQAaiIBIAIoAjwgAigCQBAOIAJBLGogARA8IAJBADYCQAwBBQsgAkHEAGoiASACKAI8IgAgAigCQBAOIAJBLGogARA8IAIoAiwhBSACKAIwIQggAigCNCEHIAIoAjggABBrIAIoAhxBAnQhBEEAIQAgAigCGCEJIAIoAhQhAUEAIQMDQCAAIAdGIAMgBEZyRQRAIAJBIGogAigCKCADIAlqKAIAaiAAIAhqLQAAEDMgAEEBaiEAIANBBGohAwwBBQsLIAUgCBBpIAEgCRBqIAIoAiQhByACKAIgIQUgAigCKCEBIAIoAgggBkECQQQQMiACQcQAaiIAIAEQbyAAIAEQZCACKAJMIQAgAigCSCEGIAEEQCAAIAFqIQQgBiAAQQF0aiEAIAchAwNAIAAgAy0AAEEBakH/AXE7AQAgA0EBaiEDIABBAmohACABQQFrIgENAAsgBCEABQsgAigCRCEBIAUgBxBpIAYgABAAIAEgBhBrIAJB0ABqJAAPCyAAIQcLIARBBGohASACQThqIAQvAQAQTCADQQRrIQMMAAsABQtBhgQhAEEAIQMgAigCPCEFA0AgAEECTwRAIAMgAyAAQQF2IgdqIgYgBkEMbCIEKALshUAgBUsbIAYgBCgC8IVAIAVPGyEDIAAgB2shAAwBBQsLAkAgAkEIagJ/AkAgA0EMbCIEKALwhUAgBUkiAEUEQCAEKALshUAgBU0NAQULQQAhA0EAQYEEIAVBEHQgBUH//wNLGyIAQQFxDQEaQQEhAyAAQRB2ECgMAQsgACADaiIAQYUESw0BIABBDGwiAC0A9oVAIQMgAC8B9IVACyADEERBAUECIAVBgIAESRsMAgsACyACQQhqQQBBABBEQQELEEsMAAsAC6oGAQt/AkACQCABRQ0AQX4hBCAAIAFBAWsiCkEBdGovAQAQKCEHIAohAwNAAkACQCAHQf//A3FB/wFLDQAgASADTQ0EIAAgA0EBdGovAQAQKEEEcQ0AIAchBiADIQUgBCEIDAELIANBAWshBgJ/AkACQANAIAYhBSAEIghBAE4EQCAHQSBxRSAJQRBxRXINAyABIANNDQhBACEEIAAgA0EBdGoiBi8BAEGiDGsiB0H//wNxIglBBUsEQCADIQUMAwULIAMhBUErIAd2QQFxRQ0CIAZB//8DOwEAIAEgCksEQCAAIApBAXRqIAlBAXQvAfiJQSIEOwEAIAohBQwDBQsABQtBfyEGQQAhC0G4FyEEIAVBf0YNACABIAVLBEAgCCAFIAAgBUEBdGovAQAQKCILQQRxQQJ2IggbIQQgBSAIayEGDAEFCwsACyANIQkgBBAoDAELIAMhBSAHCyIGQQNxIgMgC0EDcUEEdCAJQQNxQQJ0cnItAKqFQCEEAn8CQAJAAkACQAJAIANBAUYEQCAEQQFxIQQMAQULIAEgBU0NASAAIAVBAXRqLwEAIgNBywxrQf//A3FBCE8NAAJAIAlBAnFBACALQQFxG0UEQEEAIQQgAkH/AXFBAkcNAgwBBQtBACEEAkAgAkH/AXFBAWsOAgABAgsgA0HeDHFBzAxGDQEgC0EgcUUgCUEQcUVyIQQMAQsgA0HRDEYhBAsgASAFTQ0AIAAgBUEBdGoiDC8BACIDQYAMc0GAAk8NBSADQcsMa0H//wNxIgdBCE8EQCAGQYD+A3FBCHYhAyAGQQhxDQQgA0UgBkEEcXINBiADIARqQZADawwFBQsgAkH/AXFBAkYNAQwCCwALQQYhB0H+/wMgA0HRDEcNAhoLIAQgBy0Ao4NAakGQA2sMAQsgAyAEakGwCWsLIQMgDCADOwEACyAFQQFrIgwgBSAFQQBKGyEDQX4hBCALIQcgCCAMRwRAIAVFDQIgASADTQ0DIAAgA0EBdGovAQAQKCEHIAghBAULIA0gCSAGQQRxIggbIQ0gCSAGIAgbIQkgCiAFIAgbIQogBQ0ACwsPCwALxAUBC38jAEEQayIJJAAgCUKAgICAwAA3AgRBBCEIQXQhDEEBIQUDQCAJIAY2AgwgASALaiEKAkADQAJAAkAgB0EBcSAFIgQgAktyRQRAIAIgBE0hByAEIAIgBEtqIQUgAiAERw0BDAIFCyAJKAIIIQQCQCAGRQRAQQAhCEEBIQIMAQULIAQtAAghCCAGQQFGBEAgCEEBciECDAEFCyAEQRRqIQMgDEEMbiECIAghBQNAIAVB/wFxIgEgAy0AACIFIAEgBUsbIQUgA0EMaiEDIAJBAWsiAg0ACyAEQRRqIQMgDEEMbiEHIAhBAXIhAgNAIAJB/wFxIgEgAy0AAEEBciICIAEgAkkbIQIgA0EMaiEDIAdBAWsiBw0ACyAFIQgLIARBDGshDCACQf8BcSEKAkADQCAKIAhB/wFxIgtLDQFBACEBA0AgASAGTwRAIAhBAWshCAwCBQsCQCALIAQgBiABEFwtAAhNBEAgASAGIAEgBksbIQUgDCABQQxsIg1qIQNBACECIAEhBwNAAkAgBSAHRwRAIAQgBiAHEFwtAAggC08NAQULIAYgB0kgASAHS3INAyACQQF2IQIgBCANaiEFA0AgAgRAIAUgA0EMEBwgAkEBayECIAVBDGohBSADQQxrIQMMAQUgByEBDAYLAAsACyACQQFqIQIgA0EMaiEDIAdBAWohBwwACwAFCyABQQFqIQEMAQsLCwALIAAgCSgCDDYCCCAAIAkpAgQ3AgAgCUEQaiQADwsgAiALTQ0CIAEgBGotAAAgCi0AAEYNAQsLIAIgC00NAEEAQQMgCi0AACINQQFxGyEOIAkoAgQgBkYEQCAJQQRqEHEgCSgCCCEIBQsgCCAGQQxsaiIKIA46AAkgCiANOgAIIAogAyAEajYCBCAKIAMgC2o2AgAgDEEMaiEMIAZBAWohBiAEIQsMAQsLAAurBQIJfwF+IwBB0ABrIgMkAAJ/AkAgAkEFdkEHcSIFQQRLDQAgAkGABnEiCEGAAnJBgAJHDQACQAJ/AkACQAJAAkAgAkGDgARxIgQOBAUAAQIDC0EBIQZBASEEDAQLQQIMAgtBAwwBCyAEQYCABEcNAkEECyEEQQEhBgsgAkERdkEHcSIHQQdGQd0AIAd2QQFxRXINACACQYCAgANxIglBgICAAXJBgICAAUcNACACQYCAgBxxIgpBgICACHJBgICACEcNACACQRx2QQNxIgtBA0YNAEKAgISQsICAAiAHQQN0rYinQQdxQQJ0KAKYikEgBEECdCgChIpBIAJBhIDB4ABxIAVBBXQgAkEDdkEDcSIEQQN0cnIgC0EcdHJyciAIciAJciAKciACRw0AIAJBgIABcSEFAkAgBEECRgRAIAUNAkHcACAHdkEBcUUNAQwCBQsgBkUgBEEDRnENACAFDQELIANBADsBRCADIAA2AkggAyAAIAFBAXRqNgJMA0AgA0E4aiADQcQAaiIFEBQgAy8BOCIERQ0ACyAEQf//A0cNAAJAIAJB+AFxRQRAIAUgACABEDkgA0EQaiAFIAIQAQwBBQsgA0EANgIkIANCgICAgMAANwIcIANBAToANyADQQA2AkwgA0KAgICAwAA3AkQgA0E4aiAAIAEgAiADQRxqIANBxABqIANBN2oQAiADKAJEIAMoAkgQZyADKAI4IQAgAy0AN0UEQCAAIAMoAjwQayADKAIcIAMoAiAQagwCBQsgAyADKQI8NwIsIAMgADYCKCADKAIcIAMoAiAQaiADQRBqIANBKGogAhABCyADKAIQIgBBf0YNACADKAIUIQEgAykCFCIMpyAMQiCIpxAAIAAgARBrQQEMAQtBAAshAiADQdAAaiQAIAILrAQBDn8jAEEQayEFAkAgAiIGQRBJBEAgACECDAEFCyAAQQAgAGtBA3EiB2ohBCABIQMgACECA0AgAiAESQRAIAIgAy0AADoAACADQQFqIQMgAkEBaiECDAEFCwsgBCAGIAdrIg5BfHEiD2ohAgJAIAEgB2oiCEEDcSIDRQRAIAghAQNAIAIgBE0NAiAEIAEoAgA2AgAgAUEEaiEBIARBBGohBAwACwAFCyAFQQA2AgwgBUEMaiADciEGQQAhBEEEIANrIgpBAXEEQCAGIAgtAAA6AABBASEEBQsgCkECcQRAIAQgBmogBCAIai8BADsBAAULIAEgA2shAUEAIANBA3QiCmtBGHEhCyAFKAIMIQQgACEDA0AgAiADIAdqIgxBBGpNBEBBACEDIAVBADoACCAFQQA6AAZBAiENAn8gCEECcUUEQEEAIQZBACENIAVBCGoMAQULIAUgASAHaiIJQQRqLQAAIgY6AAggCUEFai0AAEEIdCEJIAVBBmoLIRAgDCAIQQFxBH8gECABIAcgDWpqQQRqLQAAOgAAIAUtAAZBEHQhAyAFLQAIBSAGCyADIAlyciALdCAEIAp2cjYCAAUgDCAEIAp2IAEgB2pBBGooAgAiBCALdHI2AgAgA0EEaiEDIAFBBGohAQwBCwsLIA5BA3EhBiAIIA9qIQELIAIgBmohAwNAIAIgA0kEQCACIAEtAAA6AAAgAUEBaiEBIAJBAWohAgwBBQsLIAALggQBDX8jAEEgayIGJAAgBkEANgIIIAZCgICAgMAANwIAIAZBDGogARBuIARB//8DcSEHIAMgASABIANLGyINIQEgAiEDIAAhBANAAkACQCABBEAgAygCACEIIAcgBC8BACIJRg0BIAZBDGogCSAIEEYMAgULAn8gBUUEQCAGIQMgBkEMaiEEIAYoAgghBSAGKAIEIQkgBigCFCEKIAYoAhAMAQULIAZBDGohAyAGIQQgBigCFCEFIAYoAhAhCSAGKAIIIQogBigCBAshASAJIAVBA3RqIQ4gASAKQQN0aiEPIAQoAgAhDCADKAIAIRBBACEEIAkhAyABIgchCwNAAn8CfwJAIAsEQCABIA9HDQEgByAMEGZBACEHBQsgAyAORgRAQQAhC0EAIQogBkEcagwDBQtBACELIAEhCCADIgFBCGoMAQsgAUEIaiEIIAMLIQUgBCANTwRAQQAhCiAFIQMgCCEBIAZBHGoMAQULIAYgACAEQQF0ajYCHCACIARBAnRqIQogASgCBCERIAEvAQAhEiAFIQMgCCEBIARBAWohBCAGQRhqCyAKNgIAIAYoAhwiBQRAIAUgEjsBACAGKAIYIBE2AgAMAQULCyAHIAwQZiAQIAkQZyAGQSBqJAAPCyAGQSAgCBBGCyABQQFrIQEgA0EEaiEDIARBAmohBAwACwALugQBAn8CQAJAAkACQAJAIABB/P8DcUGEL0YNAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAEH//wNxIgFBgC9rDl4ODg4CAQEBAQIODg4OAg4ODg4ODgIODg4OAgIODg4BAQ4CDgEBAQEBAQEBAQEBAQEBAQEBAQEMAQEBAQEBARIBAQEBAQEBEQEBAQERBAEBAQEBAwUBAQEBAQEBBhARAAsCQCABQYzAAGsOAgkHAAsgAUHPBkYNBiABQeDAAEYNByABQf//A0YNCQsgAEH+/wNxIgFBtC9GBEBBAg8FCyABQZ4vRw0JC0EBDwtBCA8LQQoPC0ENDwtBDw8LQRIPC0EUDwtBFQ8LQRYPCyAAQaMva0H//wNxQRFJBEBBAw8FCyAAQbcva0H//wNxQQRJDQYgAEG7L2tB//8DcUEDSQRAQQUPBQsgAEHBL2tB//8DcUEDSQRAQQYPBQsgAEG/L2siAUH//wNxQQZLQeMAIAF2QQFxRXINAQtBBw8LIABByS9rQf//A3FBAkkEQEEJDwULIABBzS9rQf//A3FBBUkNAyAAQccva0H//wNxQQJJBEBBDA8FCyAAQeD/A3FB4DNGIABB1C9rQf//A3FBB0lyDQIgAEHu/wNxQeAva0Hu/wNxQQpJBEBBEA8FCyAAQd4vayIBQf//A3FBIk9Cg+CPgD8gAa2Ip0EBcUVyDQFBESECCyACDwtBE0EXIABB8P8DcUGA/ANGGw8LQQ4PC0ELDwtBBAuJAwENfyMAQSBrIgQkACAEQQhqQQAgAhAiIAQoAgwiByAEKAIQQQJ0aiEGIAQoAgghDCAHIgMhBQJAAkACQANAIAMgBkcEQCACIAMoAgAiCE0NAiADQQRqIQMgASAIQQF0ai0AAEEEcQ0BIAUgCDYCACAJQQRqIQkgBUEEaiEFDAEFCwtBAEEEEGpBAEEEEGogBEEUaiIDIAIQbSADIAJB/wEQMyAFIAdrQQJ2IQogBCgCGCENIAQoAhwhDkEBIQUgByEDA0AgCUUNAiABIAIgAxBOIgsEQCABIAIgA0EEa0EAIAVBAmsgCkkbQQAgBUEBRxsQTiEPIAEgAiADQQRqQQAgBSAKSRsQTiEIIA4gAygCACIGTQ0EIAYgDWogCEEEdCAPQQJ0ciALci0AqoVAIgZBAXFBAyAGIAZBA08bIAtBAUYbOgAABQsgA0EEaiEDIAVBAWohBSAJQQRrIQkMAAsACwALIAAgBCgCHDYCCCAAIAQpAhQ3AgAgDCAHEGogBEEgaiQADwsAC6sDAQJ/AkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCAAQf//A3EiAUGz0wJrDi0GCwsICAkJCgoICwUEAgEBAQEBAQEBAQEBAQESBwEBAQEBAQEBAQEBAQEBBwcACwJAIAFBjMAAaw4CDQMACyABQaABRiABQdcBRnINEiABQc8GRg0CIAFBosAARg0SIAFB4MAARg0LIAFBzMsARg0SIAFBhNMCRg0OIAFB//8DRg0NCyAAQfesAWpB//8DcUEDSSAAQfGsAWpB//8DcUEkSXINDSAAQf//A3EiAUGF0wJrIgJBCU0NDgwPC0ECDwtBBA8LQQYPC0EHDwtBCA8LQQwPC0ENDwtBDg8LQQ8PC0EQDwtBEg8LQRMPC0EUIQILIAIPC0EBIAJ0QY8HcUUNAEEDDwsgAUGSwABrQQRJIAFB+8sAa0EESXINASAAQfz/A3FBgNMCRgRAQQUPBQsgAEG/rAFqQf//A3FBDUkEQEEKDwULIABBpqwBakH//wNxQQRJDQBBEUEJIABB8P8DcUGA/ANGGw8LQQsPC0EBC4wCAQt/IAIgAUEDdEEIayIDaiEGIAAgA2ohBSAAIAFBAXYiB0EDdGoiA0EIayEEA0AgBwRAIAIgAyAAIAMoAgAiCCAAKAIAIglJIgobKQIANwIAIAYgBCAFIAUoAgAiCyAEKAIAIgxJIg0bKQIANwIAIAdBAWshByAGQQhrIQYgAkEIaiECIARBeEEAIA0baiEEIAVBeEEAIAsgDE8baiEFIAAgCCAJT0EDdGohACADIApBA3RqIQMMAQULCyAEQQhqIQQgAUEBcQRAIAIgACADIAAgBEkiARspAgA3AgAgAyAAIARPQQN0aiEDIAAgAUEDdGohAAULIAVBCGogA0cgACAER3JFBEAPBQsAC6ICAgR/AX4jAEEgayIGJAACQCADIAIgA2oiAksNACAFrSACIAEoAgAiA0EBdCIIIAIgCEsbIgJBCEEEIAVBAUYbIgggAiAISxsiCK1+IgpCIIhQBEAgCqciAkGAgICAeCAEa0sNAQJ/IANFBEAgBkEcagwBBQsgASgCBCEJIAYgBDYCHCADIAVsIQcgBkEYagsgBzYCAAJAIAYoAhwEQCAGKAIYIgNFBEAgBkEQaiAEIAIQVyAGKAIQIQUMAgULIAQgAhAXIgVFDQEgBSAJIAMQCxogCSADEBgMAQULIAZBCGogBCACEFcgBigCCCEFCyAEIQcgBUUNASABIAg2AgAgASAFNgIEQX8hBwULCyAAIAI2AgQgACAHNgIAIAZBIGokAAuTAgEEfyMAQSBrIgMkACADIAIQbSADQQA7AQwgAyABNgIQIAMgASACQQF0ajYCFANAAkAgA0EYaiADQQxqEBQgAwJ/IAMvARgiAQRAIAFB//8DRg0CIAMvARoQIUH/AXEhAiADKAIIIgEgAygCAEYEQCADQQFBARBABQsgAygCBCABaiACOgAAIAFBAWoMAQULIAMoAhwiARAeIQIgA0EBQQIgAUGAgARJGyIEEGEgAkH/AXEhBSAEQQFrIQIgAygCCCIGIAMoAgRqIQEDQCABIAU6AAAgAgRAIAJBAWshAiABQQFqIQEMAQULCyAEIAZqCzYCCAwBCwsgACADKAIINgIIIAAgAykCADcCACADQSBqJAAL/gEBCX8jAEEQayIEJAAgBEEEaiABIAIQEiABIAJBAXRqIQggBCgCCCIFIAQoAgxqIQkgBCgCBCEKIAUhBgJ/AkADQCABIAhGIAYiByAJRnINASABQQJqIQIgB0EBaiEGAkACQCABLwEAEDUiAUH/AXEiC0H/AUcEQCABQQVrQf8BcUEDSQ0BIAIhASALQQhHDQMgAyADQQBHayEDDAMFCyACIQEgAw0CQQAhAyAHLQAAIgJBA0kNAQwCCyADQQFqIQMgAiEBDAELC0GAggQgAkEDdHYhAUEBDAELQQAhAUEACyECIAogBRBpIAAgAToAASAAIAI6AAAgBEEQaiQAC5oCAQN/IwBBEGsiAyQAIAEvAQAhAiABQQA7AQACQAJAAkACQAJ/IAIEQCABLwECDAEFCyADQQhqIAFBBGoQTSADLwEIQQFxRQ0BIAMvAQoLIgJBgPADcUGAsANHBEAgAEEAOwEAIAAgAjYCBAwEBQsgAkH/twNLDQEgAyABQQRqEE0gAy8BAEEBcUUNAiADLwECIgRBgEBrQf//A3FBgPgDSQRAIAEgBDsBAiABQQE7AQAgACACOwECIABBATsBAAwEBQsgAEEAOwEAIAAgBEH/B3EgAkH/B3FBCnRyQYCABGo2AgQMAwsgAEH//wM7AQAMAgsgACACOwECIABBATsBAAwBCyAAIAI7AQIgAEEBOwEACyADQRBqJAAL8AEBB38gASACTwRAIAAgAkEDdCICaiEDIAAgAUEDdGohCANAAkACQCADIAhHBEACQCADKAIAIgQgA0EIaygCACIBRwRAIAEgBE0NBCADKAIEIQUMAQULIAMoAgQiBSADQQRrKAIATw0DCyACIQEDQCAAIAFqIgYgBkEIayIHKQIANwIAIAFBCEYEQCAAIQcMAwULAkAgBCAGQRBrKAIAIglHBEAgBCAJTw0EDAEFCyAFIAZBDGsoAgBPDQMLIAFBCGshAQwACwAFCw8LIAcgBK0gBa1CIIaENwIACyACQQhqIQIgA0EIaiEDDAALAAULAAvzAQEFfyMAQRBrIgYkACAGQQRqIAAgARASIAFBAWshBSABQQF0IABqQQJrIQEgBigCDCEJIAYoAgghCEEBIQADQAJ/AkACQCAFQX9HBEACQAJAAkACQCAIIAkgBRBfLQAAIgdBFk0EQEEBIAd0IgdBgKT/A3ENASAHQYAYcQ0CBQsgACABLwEAEDVB/wFxQf8BR3ENAgwGCyAARQ0FIAQgBUsNBAwCCyAEIAVNDQEMAwsgBCAFSw0CCwAFCyAGKAIEIAgQaSAGQRBqJAAPCyADIAVqIAI6AABBAQwBC0EACyEAIAFBAmshASAFQQFrIQUMAAsAC/IBAQZ/QQBBCCAAIABBCE0bayEFQQggASABQQhNG0EHakF4cSICQf//A2oiAEGAgHxxIQYgAEEQdiEHQayKwQAhAANAAkACfwJAIAAiASgCACIAQX9HBEAgAiAAKAIEIABqIgNPDQQgACADIAJrIAVxIgRLDQQgAyACIARqIgJLDQEgASECIAAMAgULIAdAACIAQX9HDQJBAA8LIAIgADYCACACIAMgAms2AgQgASACNgIAIAIoAgALIQMgACAERwRAIAMgBCAAazYCBCAEDwULIAIgAygCADYCACAEDwsgAEEQdCAGEBhBrIrBACEADAALAAveAQEDfyAAQQggASABQQhNG0EHakF4cSICaiEDQayKwQAhAQJAAkACQANAIAEiBCgCACIBQX9GDQIgASADRg0BIAAgAU0NAAsgACABIAEoAgQiA2pHBEAgACACNgIEIAAgATYCACAEIAA2AgAPBQsgASACIANqNgIEDwsgASgCBCACaiECIAEoAgAiAUF/Rg0BIAEgASgCBCIDaiAARw0BIAEgAiADajYCBCAEIAE2AgAPCyAAIAI2AgQgAEF/NgIAIAQgADYCAA8LIAQgADYCACAAIAE2AgAgACACNgIEC/EBAQJ/AkACQCAAQYA0a0H//wNxQRdJDQACQAJAAkACQAJAAkACQAJAAkACQCAAQf//A3EiAUGXNGsOCQMEBQYDAQECAgALQQEhAgJAAkAgAUGMwABrDgoJAQwMDAwLCwsLAAsgAUGgAUYgAUH7ywBrQQRJciABQdcBRnINCiABQf//A0YNCSABQaLAAEYNCiABQeDAAEYNByABQczLAEYNCiABQc/TAkYNAiABQc8GRw0LC0ECDwtBBA8LQQUPC0EGDwtBBw8LQQgPC0EJDwtBCw8LQQwPC0ENIQILIAIPC0EKQQMgAEHw/wNxQYD8A0YbC8kBAQN/IANBCE8EQCAAIAAgA0EDdiIDQQV0IgRqIAAgA0E4bCIFaiADEBohACABIAEgBGogASAFaiADEBohASACIAIgBGogAiAFaiADEBohAgULAn8gACgCACIDIAEoAgAiBEcEQCADIARJDAEFCyAAKAIEIAEoAgRJCyEFAn8gAyACKAIAIgZHBEAgAyAGSQwBBQsgACgCBCACKAIESQsgBUYEfyACIAEgBCAGRwR/IAQgBkkFIAEoAgQgAigCBEkLIAVzGwUgAAsLwAEBCX8gACAAKAIIIgIgACgCACIESUEDdGoiBiAAQRhBECAAKAIYIAAoAhBJIgUbaiIDIAAgAiAET0EDdGoiAiAAQRBBGCAFG2oiACgCACACKAIASSIEGyADKAIAIAYoAgBJIgUbIgcoAgAhCSAAIAIgAyAFGyAEGyIIKAIAIQogASADIAYgBRspAgA3AgAgASAIIAcgCSAKSyIDGykCADcCCCABIAcgCCADGykCADcCECABIAIgACAEGykCADcCGAu8AQEEfyACQQJ2IQMgASEEIAAhBQNAIAMEQCAFKAAAIQYgBSAEKAAANgAAIAQgBjYAACADQQFrIQMgBEEEaiEEIAVBBGohBQwBBQsLAkAgAkEDcUUNACABIAJBDHEiBGohASAAIARqIQBBACEDIAJBAnEEQCAALwAAIQQgACABLwAAOwAAIAEgBDsAAEECIQMFCyACQQFxRQ0AIAAgA2oiAC0AACECIAAgASADaiIALQAAOgAAIAAgAjoAAAsLwAEBAn8jAEEwayIDJAACQCACQfgBcUUEQCADQSBqIgQgACABEDkgA0EUaiAEIAIQAQwBBQsgA0EANgIcIANCgICAgMAANwIUIANBADYCKCADQoCAgIDAADcCICADQQE6AC8gA0EIaiIEIAAgASACIANBFGoiACADQSBqIANBL2oQAiADKAIgIAMoAiQQZyADKAIUIAMoAhgQaiAAIAQgAhABCyADKAIYIgAgAygCHBAAIAMoAhQgABBrIANBMGokAAu0AQEEfwJAQYEEIABBEHQgAEH//wNLGyIBQQFxBEBBjgIhAQNAIAFBAk8EQCACIAIgAUEBdiIDaiICIAJBDGwiBCgC0PBAIABLGyACIAQoAtTwQCAATxshAiABIANrIQEMAQULC0EAIQEgAkEMbCIDKALU8EAgAEkiBA0BIAMoAtDwQCAASw0BIAIgBGoiAEGNAk0EQCAAQQxsLQDY8EAPBQsABQsgAUEQdhAhQf8BcSEBCyABC5wBAQN/AkACQAJAIAIgA2ogAU8NACABQQJ0IANBAnRrIABqQQRrIQQgAiABIAEgAkkbIQUgACACQQJ0aiEAIAEgA0F/c2ohAwNAIAIgA08NASACIAVGDQIgASADTQ0DIAAoAgAhBiAAIAQoAgA2AgAgBCAGNgIAIABBBGohACAEQQRrIQQgA0EBayEDIAJBAWohAgwACwALDwsACwALmwEBB38jAEEQayIEJAAgBEEIaiACQQRBDBApIAQoAggiBSACIAIgBUsbQQxsIQkgBCgCDCEGA0AgAyAJRkUEQCADIAZqIgcgASADaiIIKAIANgIAIAdBCGogCEEIai0AADoAACAHQQRqIAhBBGooAgA2AgAgA0EMaiEDDAEFCwsgACACNgIIIAAgBjYCBCAAIAU2AgAgBEEQaiQAC58BAQV/QdsDIQEDQCABQQJPBEAgAiACIAFBAXYiA2oiAiAAQf//A3EiBCACQQZsIgUvAf7GQEkbIAIgBS8BgMdAIARPGyECIAEgA2shAQwBBQsLQQAhAQJAAkAgAEH//wNxIgAgAkEGbCIDLwGAx0BLIgQNACADLwH+xkAgAEsNACACIARqIgBB2gNLDQEgAEEGbC0AgsdAIQELIAEPCwALjwEBBH8jAEEQayIDJAAgA0EEaiIFIAIgAWsiBEEAIAIgBE8bIgQQZSAFIAQQYiADKAIMIgQgAiABIAEgAkkbIgVqIAFrIQYgAygCCCAEQQJ0aiECA0AgASAFRkUEQCACIAE2AgAgAkEEaiECIAFBAWohAQwBBQsLIAAgAykCBDcCACAAIAY2AgggA0EQaiQAC5IBAQN/IAAhBAJAAkADQCABIAVGBEAgASEFDAIFCwJAIAQvAQBBIEcEQCABQQF0IABqQQJrIQBBACEEA0AgASABIARqQQFrTQ0FIAAvAQBBIEcNAiAAQQJrIQAgBEEBayEEDAALAAULIARBAmohBCAFQQFqIQUMAQsLQQAgBGshBgsgAyAGNgIAIAIgBTYCAA8LAAuVAQEHfyABKAIMIQUgASgCECEGIAEoAgghBCABKAIAIQMgASgCBCEHAkAgAAJ/A0BBACADIAdGDQEaIAEgA0EEaiIINgIAIAMoAgAhAiAIIQMgAiAEKAIYTQ0AIAIgBCgCHE8NACACIAZPDQIgBSACQQF0ai8BAEGowABGDQALIAAgAjYCCCAAIAI2AgRBAQs2AgAPCwALlAEBBn8gASgCDCEFIAEoAgghBiABKAIAIQQgASgCBCEHA0ACQCAEIAdGBEBBACECDAEFCyABIARBBGoiAjYCACAEKAIAIQMgAiEEIANBACAGLQAAIgJrRg0BQQFBfyACGyADaiIDIAUoAghPDQFBASECIAUoAgQgA0EBdGovAQBBIEcNAQsLIAAgAzYCBCAAIAI2AgALiwEBB38jAEEQayIEJAAgBEEIaiACQQRBCBApIAQoAggiBSACIAIgBUsbQQN0IQcgBCgCDCEGA0AgAyAHRkUEQCADIAZqIgggASADaiIJKAIANgIAIAhBBGogCUEEai0AADoAACADQQhqIQMMAQULCyAAIAI2AgggACAGNgIEIAAgBTYCACAEQRBqJAALfgECfyADQQhPBEAgACAAIANBA3YiA0EFdCIEaiAAIANBOGwiBWogAxAnIQAgASABIARqIAEgBWogAxAnIQEgAiACIARqIAIgBWogAxAnIQIFCyAAIAIgASAAKAIAIgAgASgCACIBSSIDIAEgAigCACICSXMbIAMgACACSXMbC58BAQN/AkAgAEH//wNxIgNBoQxNDQAgAEGiDGsiAUH//wNxQd4FTwRAIANBjcAARgRAQQMPBQsgAEHtwABrQf//A3FBA0kEQEEEDwULIABBsAlqQf//A3EiAUGTAk8EQCAAQZADaiIAQf//A3FBjQFPDQIgAEH//wNxLQCTgkAPBQsgAS0AgIBADwULIAFB//8DcUEBdC8BwrtAIQILIAILfwICfwF+IwBBEGsiBCQAAkACQCADrSABrX4iBkIgiKcNACAGpyIDQYCAgIB4IAJrSw0AAkAgA0UEQEEAIQEMAQULIARBCGogAiADEFcgBCgCCCIFRQ0CIAUhAgsgACACNgIEIAAgATYCACAEQRBqJAAPC0EAIQILIAIgAxBzAAt1AQN/An8gACgCACIEIAIoAggiAygCACIFRwRAIAQgBUkMAQULIAAoAgQgAygCBEkLIQAgAigCACABIAIoAgwiBEEDdGoiASkCADcCACACIAM2AgAgAiADQQhqNgIIIAIgBCAAQQFzajYCDCABIAMpAgA3AgALaQIEfwF+IwBB0AFrIgEkACABQRBqQciDwABBwAEQCxpBCCECA0AgAkEIaiIDQdABRwRAIAFBCGogAmohBCADIQIgACAEKQIAIgWnSSAAIAVCIIinS3INAQULCyABQdABaiQAIANB0AFHC3IBA38CfyACKAIIIgMoAgAiBCAAKAIAIgVHBEAgBCAFSQwBBQsgAygCBCAAKAIESQshACACKAIAIAEgAigCDCIEQQN0aiIBKQIANwIAIAIgAzYCACACIANBCGo2AgggAiAAIARqNgIMIAEgAykCADcCAAtoAQJ/IAAgASAAKAIIIgJLBH8gACACIAEgAmsiA0EEQQgQVSADQQFrIQEgACgCBCAAKAIIIgBBA3RqIQIDQCACQQA2AgAgAQRAIAFBAWshASACQQhqIQIMAQULCyAAIANqBSABCzYCCAtgAQR/QQAgAUEBdiIDayEEIAFBAXQgAGpBAmshAQJAA0AgAiAERg0BIAJBAWshAiADBEAgAC8BACEFIAAgAS8BADsBACABIAU7AQAgAEECaiEAIAFBAmshAQwBBQsLAAsLZQEDfyAAIAEgACgCCCICSwR/IAAgAiABIAJrIgNBAUEBEFUgA0EBayEBIAAoAggiBCAAKAIEaiECA0AgAkEAOgAAIAEEQCABQQFrIQEgAkEBaiECDAEFCwsgAyAEagUgAQs2AggLYwECfyABIAAoAggiBE0EQCAAKAIAIARGBEAgABBwBQsgACgCBCABQQN0aiEFIAEgBEkEQCAFQQhqIAUgBCABa0EDdBAGBQsgBSADOgAEIAUgAjYCACAAIARBAWo2AggPBQsAC2ICAX8BfgJ/IAAgAkEDdGoiAigCACIDIAAgAUEDdGoiACgCACIBRwRAIAEgA0sMAQULIAIoAgQgACgCBEkLIQEgACACIAEbKQIAIQQgACACIAAgARspAgA3AgAgAiAENwIAC1wBAX8jAEEQayIEJAACfyAARQRAQQAhACAEQQxqDAEFCyAEIAI2AgwgACADbCEAIARBCGoLIAA2AgACQCAEKAIMRQ0AIAQoAggiAEUNACABIAAQGAsgBEEQaiQAC18BA38gACABIAAoAggiA0sEfyAAIAEgA2siBBBhIARBAWshASAAKAIIIgUgACgCBGohAwNAIAMgAjoAACABBEAgAUEBayEBIANBAWohAwwBBQsLIAQgBWoFIAELNgIIC1kBBH8gAiABKAIIIgRJBEAgASgCBCACQQN0aiIDKAIAIQUgAy0ABCEGIAMgA0EIaiAEIAJBf3NqQQN0EAYgASAEQQFrNgIIIAAgBjoABCAAIAU2AgAPBQsAC2wBAX8CQAJAAkACQAJAAkACQAJAAkACQAJAIABB//8DcSIBQarAAGsOBQECAwQFAAtB/wEhACABQebAAGsOBAUGBwgJC0EADwtBAQ8LQQIPC0EDDwtBBA8LQQUPC0EGDwtBBw8LQQghAAsgAAtcAQF/AkACQAJAAkAgAi8BACIDECFB/wFxDgQAAAECAwsgAEEAOgAADwsgAEEBOgAADwsgAC0AAEEBRw0AIANBMGsiAEH//wNxQQlLDQAgAiABLwEAIABqOwEACwtWAgJ/AX4gASgCACABQQhrIgIoAgBJBEAgASkCACIEpyEDA0ACQCACIgFBCGogASkCADcCACAAIAFGDQAgAUEIayICKAIAIANLDQELCyABIAQ3AgAFCwtNAQF/IwBBEGsiAyQAIANBBGogAhBlIAIEQCADKAIIIAEgAkECdBALGiADIAI2AgwFCyAAIAMoAgw2AgggACADKQIENwIAIANBEGokAAtNAQF/IwBBEGsiAyQAIANBBGogAhBvIAIEQCADKAIIIAEgAkEBdBALGiADIAI2AgwFCyAAIAMoAgw2AgggACADKQIENwIAIANBEGokAAtQAQF/IAAoAggiAiAAKAIARgRAIABBBEEUEEAFCyAAIAJBAWo2AgggACgCBCACQRRsaiIAIAEpAgA3AgAgACABKQIINwIIIAAgASgCEDYCEAs+AAJAIAIgA2ogAU8NACABIANBf3NqIQMDQCACIANPDQEgACABIAIgAxA+IANBAWshAyACQQFqIQIMAAsACwtJAQN/IAEoAgQhAiABKAIAIQQgACABKAIIIgEQYSAAKAIIIQMgAQRAIAAoAgQgA2ogAiABEAsaBQsgACABIANqNgIIIAQgAhBpC0YBAX8gACgCCCICIAAoAgBGBEAgAEEBQQMQQAULIAAgAkEBajYCCCAAKAIEIAJBA2xqIgAgATsAACAAQQJqIAFBEHY6AAALPwACQCABIAJLBEAgASADSw0BAAULAAsgACACQQF0aiIBLwEAIQIgASAAIANBAXRqIgAvAQA7AQAgACACOwEAC0EBAX4CQCABIAJLBEAgASADSw0BAAULAAsgACACQQN0aiIBKQIAIQQgASAAIANBA3RqIgApAgA3AgAgACAENwIAC0ABAX8jAEEQayIDJAAgA0EIaiAAIAAoAgBBASABIAIQESADKAIIIgBBf0cEQCAAIAMoAgwQcwAFCyADQRBqJAALPQEBfyMAQRBrIgUkACAFQQhqIAAgASACIAMgBBARIAUoAggiAEF/RwRAIAAgBSgCDBBzAAULIAVBEGokAAtAAQF/IAEoAgAiAiABKAIERgR/QQAFIAEgAkEBajYCACABIAJBAnRqKAIIIQFBAQshAiAAIAE2AgQgACACNgIAC0IBAX8gACgCCCICIAAoAgBGBEAgABByBQsgACACQQFqNgIIIAAoAgQgAkEEdGoiACABKQIANwIAIAAgASkCCDcCCAtAAQF/IAAoAggiAyAAKAIARgRAIABBAkEEEEAFCyAAIANBAWo2AgggACgCBCADQQJ0aiIAIAI6AAIgACABOwEACzwBAX8gACgCCCIDIAAoAgBGBEAgABBwBQsgACADQQFqNgIIIAAoAgQgA0EDdGoiACACNgIEIAAgATYCAAs8AQF/IAAoAggiAyAAKAIARgRAIAAQcAULIAAgA0EBajYCCCAAKAIEIANBA3RqIgAgAjYCBCAAIAE7AQALOgECfyABQf//A3EiAyAALwEATwR/IAAvAQIhAiAALQAERQRAIAIgA08PBQsgAUH//wNxIAJJBUEACws2AQF/IAAgAhBiIAAoAgghAyACBEAgACgCBCADQQJ0aiABIAJBAnQQCxoFCyAAIAIgA2o2AggLNgEBfyAAIAIQZCAAKAIIIQMgAgRAIAAoAgQgA0EBdGogASACQQF0EAsaBQsgACACIANqNgIICzgCAX8BfiMAQRBrIgQkACAEQQhqIAEgAyACECkgBCkDCCEFIABBADYCCCAAIAU3AgAgBEEQaiQACzcBAX8gACgCCCICIAAoAgBGBEAgAEEEQQQQQAULIAAgAkEBajYCCCAAKAIEIAJBAnRqIAE2AgALNwEBfyAAKAIIIgIgACgCAEYEQCAAQQJBAhBABQsgACACQQFqNgIIIAAoAgQgAkEBdGogATsBAAs6AQF/IAEoAgAiAiABKAIERgR/QQAFIAEgAkECajYCACACLwEAIQJBAQshASAAIAI7AQIgACABOwEACyoAIAJFBEBBAA8FCyABIAIoAgAiAksEQCAAIAJBAXRqLwEAQQNxDwULAAsnACABIAJNIAIgBE1xRQRAAAULIAAgAiABazYCBCAAIAEgA2o2AgALKgADQCABBEAgACgCACAAQQRqKAIAEGogAUEBayEBIABBDGohAAwBBQsLCyQAIAEgA00EQCAAIAMgAWs2AgQgACACIAFBA3RqNgIADwULAAsmAQF/IwBBEGsiASQAIAFBBGogABBvIAEoAgghACABQRBqJAAgAAscACAAIAIQGyAAQSBqIAJBIGoQGyACQQggARAQCxoAIAAgASACIAMgAUEBcmdBAXRBPnNBABAECx0AIAAoAgAgAWsgAkkEQCAAIAEgAiADIAQQQQULCxkAIAEgA0YEQCAAIAIgAUEBdBALGg8FCwALHgAgAgRAIAEgAhAXIQEFCyAAIAI2AgQgACABNgIACxoAIAEgA0sEQAAFCyAAIAE2AgQgACACNgIACxoAIAIgA0kEQAAFCyAAIAM2AgQgACABNgIACxQAIAEgAk0EQAAFCyAAIAJBA3RqCxQAIAEgAk0EQAAFCyAAIAJBAnRqCxQAIAEgAk0EQAAFCyAAIAJBDGxqCxkAIAFBgICAgARPBEAABQsgACABQQF0EBgLFwAgACABKAIEIAEoAggQSSABQQA2AggLEQAgASACTQRAAAULIAAgAmoLEAAgABAeQf8BcUEBa0ECSQsRACAAIAAoAgggAUEBQQEQVQsRACAAIAAoAgggAUEEQQQQVQsRACAAIAAoAghBAUEEQQgQVQsRACAAIAAoAgggAUECQQIQVQsMACAAIAFBBEEEEEoLDgAgAARAIAEgABBnBQsLDAAgACABQQRBCBAyCwwAIAAgAUEEQQwQMgsMACAAIAFBAUEBEDILDAAgACABQQRBBBAyCwwAIAAgAUECQQIQMgsMACABIABBBEEIEDILDAAgACABQQFBARBKCwwAIAAgAUEIQQQQSgsMACAAIAFBAkECEEoLCgAgAEEEQQgQQAsKACAAQQRBDBBACwoAIABBBEEQEEALCgAgAARAAAULAAsL1YIBEQBBgYDAAAsJAQAAAAAAAQIDAEGrgMAACwMBAgMAQbuAwAALCwEAAAABAgMAAQIDAEGtgcAACwMBAgMAQY6CwAALmgMEBAQEBAMDAwADAAMDAwMDAwMDAwMAAAEAAQABAAEAAQIDAAEAAQIDAAEAAQIDAAECAwABAgMAAQIDAAECAwABAAEAAQABAAECAwABAgMAAQIDAAECAwABAgMAAQIDAAECAwABAgMAAQIDAAECAwABAgMAAQIDAAECAwABAgMAAQIDAAEAAQABAgMAAQABAAEAAQAAAAACBAYICgwOAAEAAAAAAAEAAQABAAEAAQAA3P/fAAAA2P/bAACALgAA/y4AAAAvAADfLwAA8C8AAP8vAAAAMAAAPzAAAMAxAADvMQAAADIAAP8yAAAAMwAA/zMAAAA0AAC/TQAAAE4AAP+fAAAA+QAA//oAABD+AAAf/gAAMP4AAE/+AABQ/gAAb/4AAAD/AADv/wAA4G8BAP9vAQAAAAIA36YCAACnAgA/twIAQLcCAB+4AgAguAIAr84CALDOAgDv6wIA8OsCAF/uAgAA+AIAH/oCAAAAAwBPEwMAUBMDAK8jAwC+C78LwAvBC8ILwwvEC8ULxgvHC8gLUPv/+wAAcP70/gBBs4XAAAsXAQABAAEAAQAAAgIAAAICAAECAwABAgMAQdOFwAALlTEBAAEAAQABAAACAgAAAgIAAQIDAAECAwAArQAAAK0AAAAEAAAAAAMAAG8DAAAEAAAAgwQAAIkEAAAEAAAAkQUAAL0FAAAEAAAAvwUAAL8FAAAEAAAAwQUAAMIFAAAEAAAAxAUAAMUFAAAEAAAAxwUAAMcFAAAEAAAAAAYAAAUGAAAEAAAAEAYAABoGAAAEAAAAHAYAABwGAAAEAAAAIAYAACAGAAADAAEAIgYAACUGAAABAAEAJgYAACYGAAADAAEAJwYAACcGAAABAAEAKAYAACgGAAADAAEAKQYAACkGAAABAAEAKgYAAC4GAAADAAEALwYAADIGAAABAAEAMwYAAD8GAAADAAEAQAYAAEAGAAADAAAAQQYAAEcGAAADAAEASAYAAEgGAAABAAEASQYAAEoGAAADAAEASwYAAF8GAAAEAAAAbgYAAG8GAAADAAEAcAYAAHAGAAAEAAAAcQYAAHMGAAABAAEAdQYAAHcGAAABAAEAeAYAAIcGAAADAAEAiAYAAJkGAAABAAEAmgYAAL8GAAADAAEAwAYAAMAGAAABAAEAwQYAAMIGAAADAAEAwwYAAMsGAAABAAEAzAYAAMwGAAADAAEAzQYAAM0GAAABAAEAzgYAAM4GAAADAAEAzwYAAM8GAAABAAEA0AYAANEGAAADAAEA0gYAANMGAAABAAEA1QYAANUGAAABAAEA1gYAAN0GAAAEAAAA3wYAAOQGAAAEAAAA5wYAAOgGAAAEAAAA6gYAAO0GAAAEAAAA7gYAAO8GAAABAAEA+gYAAPwGAAADAAEA/wYAAP8GAAADAAEADwcAAA8HAAAEAAAAEAcAABAHAAABAAIAEQcAABEHAAAEAAAAEgcAABQHAAADAAIAFQcAABkHAAABAAIAGgcAAB0HAAADAAIAHgcAAB4HAAABAAIAHwcAACcHAAADAAIAKAcAACgHAAABAAIAKQcAACkHAAADAAIAKgcAACoHAAABAAIAKwcAACsHAAADAAIALAcAACwHAAABAAIALQcAAC4HAAADAAIALwcAAC8HAAABAAIAMAcAAEoHAAAEAAAATQcAAE0HAAABAAIATgcAAE8HAAADAAIAUAcAAFgHAAADAAEAWQcAAFsHAAABAAEAXAcAAGoHAAADAAEAawcAAGwHAAABAAEAbQcAAHAHAAADAAEAcQcAAHEHAAABAAEAcgcAAHIHAAADAAEAcwcAAHQHAAABAAEAdQcAAHcHAAADAAEAeAcAAHkHAAABAAEAegcAAH8HAAADAAEApgcAALAHAAAEAAAAygcAAOoHAAADAAMA6wcAAPMHAAAEAAAA+gcAAPoHAAADAAMA/QcAAP0HAAAEAAAAFggAABkIAAAEAAAAGwgAACMIAAAEAAAAJQgAACcIAAAEAAAAKQgAAC0IAAAEAAAAQAgAAEAIAAABAAQAQQgAAEUIAAADAAQARggAAEcIAAABAAQASAgAAEgIAAADAAQASQgAAEkIAAABAAQASggAAFMIAAADAAQAVAgAAFQIAAABAAQAVQgAAFUIAAADAAQAVggAAFgIAAABAAQAWQgAAFsIAAAEAAAAYAgAAGAIAAADAAIAYggAAGUIAAADAAIAZwgAAGcIAAABAAIAaAgAAGgIAAADAAIAaQgAAGoIAAABAAIAcAgAAIIIAAABAAEAgwgAAIYIAAADAAEAiQgAAI0IAAADAAEAjggAAI4IAAABAAEAkAgAAJEIAAAEAAAAlwgAAJ8IAAAEAAAAoAgAAKkIAAADAAEAqggAAKwIAAABAAEArggAAK4IAAABAAEArwgAALAIAAADAAEAsQgAALIIAAABAAEAswgAALgIAAADAAEAuQgAALkIAAABAAEAuggAAMgIAAADAAEAyggAAAIJAAAEAAAAOgkAADoJAAAEAAAAPAkAADwJAAAEAAAAQQkAAEgJAAAEAAAATQkAAE0JAAAEAAAAUQkAAFcJAAAEAAAAYgkAAGMJAAAEAAAAgQkAAIEJAAAEAAAAvAkAALwJAAAEAAAAwQkAAMQJAAAEAAAAzQkAAM0JAAAEAAAA4gkAAOMJAAAEAAAA/gkAAP4JAAAEAAAAAQoAAAIKAAAEAAAAPAoAADwKAAAEAAAAQQoAAEIKAAAEAAAARwoAAEgKAAAEAAAASwoAAE0KAAAEAAAAUQoAAFEKAAAEAAAAcAoAAHEKAAAEAAAAdQoAAHUKAAAEAAAAgQoAAIIKAAAEAAAAvAoAALwKAAAEAAAAwQoAAMUKAAAEAAAAxwoAAMgKAAAEAAAAzQoAAM0KAAAEAAAA4goAAOMKAAAEAAAA+goAAP8KAAAEAAAAAQsAAAELAAAEAAAAPAsAADwLAAAEAAAAPwsAAD8LAAAEAAAAQQsAAEQLAAAEAAAATQsAAE0LAAAEAAAAVQsAAFYLAAAEAAAAYgsAAGMLAAAEAAAAggsAAIILAAAEAAAAwAsAAMALAAAEAAAAzQsAAM0LAAAEAAAAAAwAAAAMAAAEAAAABAwAAAQMAAAEAAAAPAwAADwMAAAEAAAAPgwAAEAMAAAEAAAARgwAAEgMAAAEAAAASgwAAE0MAAAEAAAAVQwAAFYMAAAEAAAAYgwAAGMMAAAEAAAAgQwAAIEMAAAEAAAAvAwAALwMAAAEAAAAvwwAAL8MAAAEAAAAxgwAAMYMAAAEAAAAzAwAAM0MAAAEAAAA4gwAAOMMAAAEAAAAAA0AAAENAAAEAAAAOw0AADwNAAAEAAAAQQ0AAEQNAAAEAAAATQ0AAE0NAAAEAAAAYg0AAGMNAAAEAAAAgQ0AAIENAAAEAAAAyg0AAMoNAAAEAAAA0g0AANQNAAAEAAAA1g0AANYNAAAEAAAAMQ4AADEOAAAEAAAANA4AADoOAAAEAAAARw4AAE4OAAAEAAAAsQ4AALEOAAAEAAAAtA4AALwOAAAEAAAAyA4AAM4OAAAEAAAAGA8AABkPAAAEAAAANQ8AADUPAAAEAAAANw8AADcPAAAEAAAAOQ8AADkPAAAEAAAAcQ8AAH4PAAAEAAAAgA8AAIQPAAAEAAAAhg8AAIcPAAAEAAAAjQ8AAJcPAAAEAAAAmQ8AALwPAAAEAAAAxg8AAMYPAAAEAAAALRAAADAQAAAEAAAAMhAAADcQAAAEAAAAORAAADoQAAAEAAAAPRAAAD4QAAAEAAAAWBAAAFkQAAAEAAAAXhAAAGAQAAAEAAAAcRAAAHQQAAAEAAAAghAAAIIQAAAEAAAAhRAAAIYQAAAEAAAAjRAAAI0QAAAEAAAAnRAAAJ0QAAAEAAAAXRMAAF8TAAAEAAAAEhcAABQXAAAEAAAAMhcAADMXAAAEAAAAUhcAAFMXAAAEAAAAchcAAHMXAAAEAAAAtBcAALUXAAAEAAAAtxcAAL0XAAAEAAAAxhcAAMYXAAAEAAAAyRcAANMXAAAEAAAA3RcAAN0XAAAEAAAABxgAAAcYAAADAAUAChgAAAoYAAADAAUACxgAAA8YAAAEAAAAIBgAAHgYAAADAAUAhRgAAIYYAAAEAAAAhxgAAKgYAAADAAUAqRgAAKkYAAAEAAAAqhgAAKoYAAADAAUAIBkAACIZAAAEAAAAJxkAACgZAAAEAAAAMhkAADIZAAAEAAAAORkAADsZAAAEAAAAFxoAABgaAAAEAAAAGxoAABsaAAAEAAAAVhoAAFYaAAAEAAAAWBoAAF4aAAAEAAAAYBoAAGAaAAAEAAAAYhoAAGIaAAAEAAAAZRoAAGwaAAAEAAAAcxoAAHwaAAAEAAAAfxoAAH8aAAAEAAAAsBoAAM4aAAAEAAAAABsAAAMbAAAEAAAANBsAADQbAAAEAAAANhsAADobAAAEAAAAPBsAADwbAAAEAAAAQhsAAEIbAAAEAAAAaxsAAHMbAAAEAAAAgBsAAIEbAAAEAAAAohsAAKUbAAAEAAAAqBsAAKkbAAAEAAAAqxsAAK0bAAAEAAAA5hsAAOYbAAAEAAAA6BsAAOkbAAAEAAAA7RsAAO0bAAAEAAAA7xsAAPEbAAAEAAAALBwAADMcAAAEAAAANhwAADccAAAEAAAA0BwAANIcAAAEAAAA1BwAAOAcAAAEAAAA4hwAAOgcAAAEAAAA7RwAAO0cAAAEAAAA9BwAAPQcAAAEAAAA+BwAAPkcAAAEAAAAwB0AAP8dAAAEAAAACyAAAAsgAAAEAAAADSAAAA0gAAADAAAADiAAAA8gAAAEAAAAKiAAAC4gAAAEAAAAYCAAAGQgAAAEAAAAZiAAAG8gAAAEAAAA0CAAAPAgAAAEAAAA7ywAAPEsAAAEAAAAfy0AAH8tAAAEAAAA4C0AAP8tAAAEAAAAKjAAAC0wAAAEAAAAmTAAAJowAAAEAAAAb6YAAHKmAAAEAAAAdKYAAH2mAAAEAAAAnqYAAJ+mAAAEAAAA8KYAAPGmAAAEAAAAAqgAAAKoAAAEAAAABqgAAAaoAAAEAAAAC6gAAAuoAAAEAAAAJagAACaoAAAEAAAALKgAACyoAAAEAAAAQKgAAHGoAAADAAYAcqgAAHKoAAACAAYAxKgAAMWoAAAEAAAA4KgAAPGoAAAEAAAA/6gAAP+oAAAEAAAAJqkAAC2pAAAEAAAAR6kAAFGpAAAEAAAAgKkAAIKpAAAEAAAAs6kAALOpAAAEAAAAtqkAALmpAAAEAAAAvKkAAL2pAAAEAAAA5akAAOWpAAAEAAAAKaoAAC6qAAAEAAAAMaoAADKqAAAEAAAANaoAADaqAAAEAAAAQ6oAAEOqAAAEAAAATKoAAEyqAAAEAAAAfKoAAHyqAAAEAAAAsKoAALCqAAAEAAAAsqoAALSqAAAEAAAAt6oAALiqAAAEAAAAvqoAAL+qAAAEAAAAwaoAAMGqAAAEAAAA7KoAAO2qAAAEAAAA9qoAAPaqAAAEAAAA5asAAOWrAAAEAAAA6KsAAOirAAAEAAAA7asAAO2rAAAEAAAAHvsAAB77AAAEAAAAAP4AAA/+AAAEAAAAIP4AAC/+AAAEAAAA//4AAP/+AAAEAAAA+f8AAPv/AAAEAAAA/QEBAP0BAQAEAAAA4AIBAOACAQAEAAAAdgMBAHoDAQAEAAAAAQoBAAMKAQAEAAAABQoBAAYKAQAEAAAADAoBAA8KAQAEAAAAOAoBADoKAQAEAAAAPwoBAD8KAQAEAAAAwAoBAMQKAQADAAcAxQoBAMUKAQABAAcAxwoBAMcKAQABAAcAyQoBAMoKAQABAAcAzQoBAM0KAQACAAcAzgoBANIKAQABAAcA0woBANYKAQADAAcA1woBANcKAQACAAcA2AoBANwKAQADAAcA3QoBAN0KAQABAAcA3goBAOAKAQADAAcA4QoBAOEKAQABAAcA5AoBAOQKAQABAAcA5QoBAOYKAQAEAAAA6woBAO4KAQADAAcA7woBAO8KAQABAAcAgAsBAIALAQADAAgAgQsBAIELAQABAAgAggsBAIILAQADAAgAgwsBAIULAQABAAgAhgsBAIgLAQADAAgAiQsBAIkLAQABAAgAigsBAIsLAQADAAgAjAsBAIwLAQABAAgAjQsBAI0LAQADAAgAjgsBAI8LAQABAAgAkAsBAJALAQADAAgAkQsBAJELAQABAAgAqQsBAKwLAQABAAgArQsBAK4LAQADAAgAAA0BAAANAQACAAkAAQ0BACENAQADAAkAIg0BACINAQABAAkAIw0BACMNAQADAAkAJA0BACcNAQAEAAAAaQ0BAG0NAQAEAAAAqw4BAKwOAQAEAAAA/A4BAP8OAQAEAAAAMA8BADIPAQADAAoAMw8BADMPAQABAAoANA8BAEQPAQADAAoARg8BAFAPAQAEAAAAUQ8BAFMPAQADAAoAVA8BAFQPAQABAAoAcA8BAHMPAQADAAsAdA8BAHUPAQABAAsAdg8BAIEPAQADAAsAgg8BAIUPAQAEAAAAsA8BALAPAQADAAwAsg8BALMPAQADAAwAtA8BALYPAQABAAwAuA8BALgPAQADAAwAuQ8BALoPAQABAAwAuw8BALwPAQADAAwAvQ8BAL0PAQABAAwAvg8BAL8PAQADAAwAwQ8BAMEPAQADAAwAwg8BAMMPAQABAAwAxA8BAMQPAQADAAwAyQ8BAMkPAQABAAwAyg8BAMoPAQADAAwAyw8BAMsPAQACAAwAARABAAEQAQAEAAAAOBABAEYQAQAEAAAAcBABAHAQAQAEAAAAcxABAHQQAQAEAAAAfxABAIEQAQAEAAAAsxABALYQAQAEAAAAuRABALoQAQAEAAAAvRABAL0QAQAEAAAAwhABAMIQAQAEAAAAzRABAM0QAQAEAAAAABEBAAIRAQAEAAAAJxEBACsRAQAEAAAALREBADQRAQAEAAAAcxEBAHMRAQAEAAAAgBEBAIERAQAEAAAAthEBAL4RAQAEAAAAyREBAMwRAQAEAAAAzxEBAM8RAQAEAAAALxIBADESAQAEAAAANBIBADQSAQAEAAAANhIBADcSAQAEAAAAPhIBAD4SAQAEAAAAQRIBAEESAQAEAAAA3xIBAN8SAQAEAAAA4xIBAOoSAQAEAAAAABMBAAETAQAEAAAAOxMBADwTAQAEAAAAQBMBAEATAQAEAAAAZhMBAGwTAQAEAAAAcBMBAHQTAQAEAAAAuxMBAMATAQAEAAAAzhMBAM4TAQAEAAAA0BMBANATAQAEAAAA0hMBANITAQAEAAAA4RMBAOITAQAEAAAAOBQBAD8UAQAEAAAAQhQBAEQUAQAEAAAARhQBAEYUAQAEAAAAXhQBAF4UAQAEAAAAsxQBALgUAQAEAAAAuhQBALoUAQAEAAAAvxQBAMAUAQAEAAAAwhQBAMMUAQAEAAAAshUBALUVAQAEAAAAvBUBAL0VAQAEAAAAvxUBAMAVAQAEAAAA3BUBAN0VAQAEAAAAMxYBADoWAQAEAAAAPRYBAD0WAQAEAAAAPxYBAEAWAQAEAAAAqxYBAKsWAQAEAAAArRYBAK0WAQAEAAAAsBYBALUWAQAEAAAAtxYBALcWAQAEAAAAHRcBAB8XAQAEAAAAIhcBACUXAQAEAAAAJxcBACsXAQAEAAAALxgBADcYAQAEAAAAORgBADoYAQAEAAAAOxkBADwZAQAEAAAAPhkBAD4ZAQAEAAAAQxkBAEMZAQAEAAAA1BkBANcZAQAEAAAA2hkBANsZAQAEAAAA4BkBAOAZAQAEAAAAARoBAAoaAQAEAAAAMxoBADgaAQAEAAAAOxoBAD4aAQAEAAAARxoBAEcaAQAEAAAAURoBAFYaAQAEAAAAWRoBAFsaAQAEAAAAihoBAJYaAQAEAAAAmBoBAJkaAQAEAAAAMBwBADYcAQAEAAAAOBwBAD0cAQAEAAAAPxwBAD8cAQAEAAAAkhwBAKccAQAEAAAAqhwBALAcAQAEAAAAshwBALMcAQAEAAAAtRwBALYcAQAEAAAAMR0BADYdAQAEAAAAOh0BADodAQAEAAAAPB0BAD0dAQAEAAAAPx0BAEUdAQAEAAAARx0BAEcdAQAEAAAAkB0BAJEdAQAEAAAAlR0BAJUdAQAEAAAAlx0BAJcdAQAEAAAA8x4BAPQeAQAEAAAAAB8BAAEfAQAEAAAANh8BADofAQAEAAAAQB8BAEAfAQAEAAAAQh8BAEIfAQAEAAAAWh8BAFofAQAEAAAAMDQBAEA0AQAEAAAARzQBAFU0AQAEAAAAHmEBAClhAQAEAAAALWEBAC9hAQAEAAAA8GoBAPRqAQAEAAAAMGsBADZrAQAEAAAAT28BAE9vAQAEAAAAj28BAJJvAQAEAAAA5G8BAORvAQAEAAAAnbwBAJ68AQAEAAAAoLwBAKO8AQAEAAAAAM8BAC3PAQAEAAAAMM8BAEbPAQAEAAAAZ9EBAGnRAQAEAAAAc9EBAILRAQAEAAAAhdEBAIvRAQAEAAAAqtEBAK3RAQAEAAAAQtIBAETSAQAEAAAAANoBADbaAQAEAAAAO9oBAGzaAQAEAAAAddoBAHXaAQAEAAAAhNoBAITaAQAEAAAAm9oBAJ/aAQAEAAAAodoBAK/aAQAEAAAAAOABAAbgAQAEAAAACOABABjgAQAEAAAAG+ABACHgAQAEAAAAI+ABACTgAQAEAAAAJuABACrgAQAEAAAAj+ABAI/gAQAEAAAAMOEBADbhAQAEAAAAruIBAK7iAQAEAAAA7OIBAO/iAQAEAAAA7OQBAO/kAQAEAAAA7uUBAO/lAQAEAAAA0OgBANboAQAEAAAAAOkBAEPpAQADAA0AROkBAEvpAQAEAAAAAQAOAAEADgAEAAAAIAAOAH8ADgAEAAAAAAEOAO8BDgAEAAAAcQZxBnsGewZ7BnsGfgZ+Bn4GfgYAAAAAAAAAAHoGegZ6BnoGAAAAAAAAAAB5BnkGeQZ5BgBBiLfAAAs4hgaGBoYGhgYAAAAAAAAAAI0GjQaMBowGjgaOBogGiAaYBpgGkQaRBqkGqQapBqkGrwavBq8GrwYAQdC3wAALKLoGuga7BrsGuwa7BsAGwAbBBsEGwQbBBr4Gvga+Br4G0gbSBtMG0wYAQcK4wAALIscGxwbGBsYGyAbIBgAAywbLBsUGxQbJBskG0AbQBtAG0AYAQYy5wAALsgPMBswGzAbMBksGSwZMBkwGTQZNBk4GTgZPBk8GUAZQBlEGUQZSBlIGIQYiBiIGIwYjBiQGJAYlBiUGJgYmBiYGJgYnBicGKAYoBigGKAYpBikGKgYqBioGKgYrBisGKwYrBiwGLAYsBiwGLQYtBi0GLQYuBi4GLgYuBi8GLwYwBjAGMQYxBjIGMgYzBjMGMwYzBjQGNAY0BjQGNQY1BjUGNQY2BjYGNgY2BjcGNwY3BjcGOAY4BjgGOAY5BjkGOQY5BjoGOgY6BjoGQQZBBkEGQQZCBkIGQgZCBkMGQwZDBkMGRAZEBkQGRAZFBkUGRQZFBkYGRgZGBkYGRwZHBkcGRwZIBkgGSQZJBkoGSgZKBkoGXAZcBl0GXQZeBl4GXwZfBiIGIgYjBiMGJQYlBicGJwbv/vD+IREhEwEVIRcDGSEdAx8BIwMlAykDLQMxAzUBOQE7AT0BPwNBA0UDSQNNA1EDVQNZA10DAAMAAwADAAMAAwADYQNlA2kTbQNxA3UDeQF9AX8DgQQBhAGEAYQBhAGEAUQDBAEEBwQIBAgEAQQABAAEAAQABAABhQGHAYkBiwBB2rzAAAuDAgMAAwAEBgkAIQAhAAAAIQABAAEAAwALFgsOCwIDAAMACwYLEgsKAwADAAsmCyIDAAsqCy4JOAEAAQABAAk0CTIJNgEAAQAJPAEAAQABAAEAAQABAAk6AQADAAMAAwADAAMAAwADAAMAAwADAAsaAwALHgMAAwALPgMAAwADAAuDAwALQgMAC0oDAAtGAwADAAMAAwADAAMACU4LUAMAAwALWgMACVQLVgMAAQABAAmQCYkJhwmLCZIBAAmOC6wBAAMAAQALlAMACV4JYAAAAQAEAAQABAAEAAQABAAEAAQAAAAEAAQABAAEAAQABAAAAAAABAAEAAAABAAEAAQABAABAAEAQfK+wAALCwMAAwADAAAAAAADAEGewMAAC18DAAMAAwADAAMAAwADAAMAAwABAAEAAQADAAMAAwADAAMAAwADAAMAAwADAAMAAwADAAMAAwABAAEAAwADAAMAAwABAAMAAQABAAMAAwADAAEAAQADAAMAAwADAAMAAwBB3sTAAAtDAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQADAAMAAwADAAAAAAADAAMAAwADAAMAAQAAAAQABABBrMXAAAv/RAQABAAEAAQABAAEAAQABAAEAAMAAwADAAMAAwADAAMAAwADAAMAAQABAAEAAAABAAMAAwABAAEAAwADAAMAAwADAAMAAQADAAMAAwADAAMAAwADAAMAAwADAAMAAwADAAMAAwAAAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAQABAAEAAAACAAJAAkACQALAAoACgAKAAsACwALAAwADAAMAA0ADQAKAA4AGwAJABwAHgAKAB8AHwALACAAIAAMACEAIgANACMAJQAFACYAKgANACsAKwAEACwALAAHAC0ALQAEAC4ALwAHADAAOQADADoAOgAHADsAQAANAFsAYAANAHsAfgANAH8AhAAJAIUAhQAKAIYAnwAJAKAAoAAHAKEAoQANAKIApQAFAKYAqQANAKsArAANAK0ArQAJAK4ArwANALAAsQAFALIAswADALQAtAANALYAuAANALkAuQADALsAvwANANcA1wANAPcA9wANALkCugINAMICzwINANIC3wINAOUC7QINAO8C/wINAAADbwMIAHQDdQMNAH4DfgMNAIQDhQMNAIcDhwMNAPYD9gMNAIMEiQQIAIoFigUNAI0FjgUNAI8FjwUFAJAFkAUBAJEFvQUIAL4FvgUBAL8FvwUIAMAFwAUBAMEFwgUIAMMFwwUBAMQFxQUIAMYFxgUBAMcFxwUIAMgF/wUBAAAGBQYGAAYGBwYNAAgGCAYCAAkGCgYFAAsGCwYCAAwGDAYHAA0GDQYCAA4GDwYNABAGGgYIABsGSgYCAEsGXwYIAGAGaQYGAGoGagYFAGsGbAYGAG0GbwYCAHAGcAYIAHEG1QYCANYG3AYIAN0G3QYGAN4G3gYNAN8G5AYIAOUG5gYCAOcG6AYIAOkG6QYNAOoG7QYIAO4G7wYCAPAG+QYDAPoGEAcCABEHEQcIABIHLwcCADAHSgcIAEsHpQcCAKYHsAcIALEHvwcCAMAH6gcBAOsH8wcIAPQH9QcBAPYH+QcNAPoH/AcBAP0H/QcIAP4HFQgBABYIGQgIABoIGggBABsIIwgIACQIJAgBACUIJwgIACgIKAgBACkILQgIAC4IWAgBAFkIWwgIAFwIXwgBAGAIjwgCAJAIkQgGAJIIlggCAJcInwgIAKAIyQgCAMoI4QgIAOII4ggGAOMIAgkIADoJOgkIADwJPAkIAEEJSAkIAE0JTQkIAFEJVwkIAGIJYwkIAIEJgQkIALwJvAkIAMEJxAkIAM0JzQkIAOIJ4wkIAPIJ8wkFAPsJ+wkFAP4J/gkIAAEKAgoIADwKPAoIAEEKQgoIAEcKSAoIAEsKTQoIAFEKUQoIAHAKcQoIAHUKdQoIAIEKggoIALwKvAoIAMEKxQoIAMcKyAoIAM0KzQoIAOIK4woIAPEK8QoFAPoK/woIAAELAQsIADwLPAsIAD8LPwsIAEELRAsIAE0LTQsIAFULVgsIAGILYwsIAIILggsIAMALwAsIAM0LzQsIAPML+AsNAPkL+QsFAPoL+gsNAAAMAAwIAAQMBAwIADwMPAwIAD4MQAwIAEYMSAwIAEoMTQwIAFUMVgwIAGIMYwwIAHgMfgwNAIEMgQwIALwMvAwIAMwMzQwIAOIM4wwIAAANAQ0IADsNPA0IAEENRA0IAE0NTQ0IAGINYw0IAIENgQ0IAMoNyg0IANIN1A0IANYN1g0IADEOMQ4IADQOOg4IAD8OPw4FAEcOTg4IALEOsQ4IALQOvA4IAMgOzg4IABgPGQ8IADUPNQ8IADcPNw8IADkPOQ8IADoPPQ8NAHEPfg8IAIAPhA8IAIYPhw8IAI0Plw8IAJkPvA8IAMYPxg8IAC0QMBAIADIQNxAIADkQOhAIAD0QPhAIAFgQWRAIAF4QYBAIAHEQdBAIAIIQghAIAIUQhhAIAI0QjRAIAJ0QnRAIAF0TXxMIAJATmRMNAAAUABQNAIAWgBYMAJsWnBYNABIXFBcIADIXMxcIAFIXUxcIAHIXcxcIALQXtRcIALcXvRcIAMYXxhcIAMkX0xcIANsX2xcFAN0X3RcIAPAX+RcNAAAYChgNAAsYDRgIAA4YDhgJAA8YDxgIAIUYhhgIAKkYqRgIACAZIhkIACcZKBkIADIZMhkIADkZOxkIAEAZQBkNAEQZRRkNAN4Z/xkNABcaGBoIABsaGxoIAFYaVhoIAFgaXhoIAGAaYBoIAGIaYhoIAGUabBoIAHMafBoIAH8afxoIALAazhoIAAAbAxsIADQbNBsIADYbOhsIADwbPBsIAEIbQhsIAGsbcxsIAIAbgRsIAKIbpRsIAKgbqRsIAKsbrRsIAOYb5hsIAOgb6RsIAO0b7RsIAO8b8RsIACwcMxwIADYcNxwIANAc0hwIANQc4BwIAOIc6BwIAO0c7RwIAPQc9BwIAPgc+RwIAMAd/x0IAL0fvR8NAL8fwR8NAM0fzx8NAN0f3x8NAO0f7x8NAP0f/h8NAAAgCiAMAAsgDSAJAA8gDyABABAgJyANACggKCAMACkgKSAKACogKiAOACsgKyAQACwgLCASAC0gLSAPAC4gLiARAC8gLyAHADAgNCAFADUgQyANAEQgRCAHAEUgXiANAF8gXyAMAGAgZSAJAGYgZiATAGcgZyAUAGggaCAVAGkgaSAWAGogbyAJAHAgcCADAHQgeSADAHogeyAEAHwgfiANAIAgiSADAIogiyAEAIwgjiANAKAgzyAFANAg8CAIAAAhASENAAMhBiENAAghCSENABQhFCENABYhGCENAB4hIyENACUhJSENACchJyENACkhKSENAC4hLiEFADohOyENAEAhRCENAEohTSENAFAhXyENAIkhiyENAJAhESINABIiEiIEABMiEyIFABQiNSMNAHsjlCMNAJYjKSQNAEAkSiQNAGAkhyQNAIgkmyQDAOokqyYNAK0m/ycNAAApcysNAHYrlSsNAJcr/ysNAOUs6iwNAO8s8SwIAPks/ywNAH8tfy0IAOAt/y0IAAAuXS4NAIAumS4NAJsu8y4NAAAv1S8NAPAv/y8NAAAwADAMAAEwBDANAAgwIDANACowLTAIADAwMDANADYwNzANAD0wPzANAJkwmjAIAJswnDANAKAwoDANAPsw+zANAMAx5TENAO8x7zENAB0yHjINAFAyXzINAHwyfjINALEyvzINAMwyzzINAHczejMNAN4z3zMNAP8z/zMNAMBN/00NAJCkxqQNAA2mD6YNAG+mcqYIAHOmc6YNAHSmfaYIAH6mf6YNAJ6mn6YIAPCm8aYIAACnIacNAIiniKcNAAKoAqgIAAaoBqgIAAuoC6gIACWoJqgIACioK6gNACyoLKgIADioOagFAHSod6gNAMSoxagIAOCo8agIAP+o/6gIACapLakIAEepUakIAICpgqkIALOps6kIALapuakIALypvakIAOWp5akIACmqLqoIADGqMqoIADWqNqoIAEOqQ6oIAEyqTKoIAHyqfKoIALCqsKoIALKqtKoIALequKoIAL6qv6oIAMGqwaoIAOyq7aoIAPaq9qoIAGqra6sNAOWr5asIAOir6KsIAO2r7asIAB37HfsBAB77HvsIAB/7KPsBACn7KfsEACr7T/sBAFD7Pf0CAD79T/0NAFD9zv0CAM/9z/0NAND97/0JAPD9/P0CAP39//0NAAD+D/4IABD+Gf4NACD+L/4IADD+T/4NAFD+UP4HAFH+Uf4NAFL+Uv4HAFT+VP4NAFX+Vf4HAFb+Xv4NAF/+X/4FAGD+Yf4NAGL+Y/4EAGT+Zv4NAGj+aP4NAGn+av4FAGv+a/4NAHD+/v4CAP/+//4JAAH/Av8NAAP/Bf8FAAb/Cv8NAAv/C/8EAAz/DP8HAA3/Df8EAA7/D/8HABD/Gf8DABr/Gv8HABv/IP8NADv/QP8NAFv/Zf8NAOD/4f8FAOL/5P8NAOX/5v8FAOj/7v8NAPD/+P8JAPn//f8NAP7///8JACgAKQApACgAPAA+AD4APABbAF0AXQBbAHsAfQB9AHsAqwC7ALsAqwA6DzsPOw86DzwPPQ89DzwPmxacFpwWmxY5IDogOiA5IEUgRiBGIEUgfSB+IH4gfSCNII4gjiCNIAgiCyIJIgwiCiINIgsiCCIMIgkiDSIKIhUi9SkfIv4rICKjKSEimykiIqApJCLuKjwiPSI9IjwiQyLNIkUiTCJMIkUiUiJTIlMiUiJUIlUiVSJUImQiZSJlImQiZiJnImciZiJoImkiaSJoImoiayJrImoibiJvIm8ibiJwInEicSJwInIicyJzInIidCJ1InUidCJ2IncidyJ2IngieSJ5IngieiJ7InsieiJ8In0ifSJ8In4ifyJ/In4igCKBIoEigCKCIoMigyKCIoQihSKFIoQihiKHIocihiKIIokiiSKIIooiiyKLIooijyKQIpAijyKRIpIikiKRIpgiuCmiIqMioyKiIqYi3iqoIuQqqSLjKqsi5SqwIrEisSKwIrIisyKzIrIitCK1IrUitCK2IrcityK2Irgi3CfJIsoiyiLJIssizCLMIssizSJDItAi0SLRItAi1iLXItci1iLYItki2SLYItoi2yLbItoi3CLdIt0i3CLeIt8i3yLeIuAi4SLhIuAi4iLjIuMi4iLkIuUi5SLkIuYi5yLnIuYi6CLpIuki6CLqIusi6yLqIuwi7SLtIuwi8CLxIvEi8CLyIvoi8yL7IvQi/CL2Iv0i9yL+Ivoi8iL7IvMi/CL0Iv0i9iL+IvciCCMJIwkjCCMKIwsjCyMKIykjKiMqIykjaCdpJ2knaCdqJ2snaydqJ2wnbSdtJ2wnbidvJ28nbidwJ3EncSdwJ3IncydzJ3IndCd1J3UndCfDJ8QnxCfDJ8UnxifGJ8UnyCfJJ8knyCfLJ80nzSfLJ9Un1ifWJ9Un3Ce4It0n3ifeJ90n4ifjJ+Mn4ifkJ+Un5SfkJ+Yn5yfnJ+Yn6CfpJ+kn6CfqJ+sn6yfqJ+wn7SftJ+wn7ifvJ+8n7ieDKYQphCmDKYUphimGKYUphymIKYgphymJKYopiimJKYspjCmMKYspjSmQKY4pjymPKY4pkCmNKZEpkimSKZEpkymUKZQpkymVKZYplimVKZcpmCmYKZcpmykhIqApIiKjKSAipCmlKaUppCmoKakpqSmoKaopqymrKaoprCmtKa0prCmuKa8prymuKbgpmCLAKcEpwSnAKcQpxSnFKcQpzynQKdApzynRKdIp0inRKdQp1SnVKdQp2CnZKdkp2CnaKdsp2ynaKegp6SnpKegp9SkVIvgp+Sn5Kfgp/Cn9Kf0p/CkrKiwqLCorKi0qLiouKi0qNCo1KjUqNCo8Kj0qPSo8KmQqZSplKmQqeSp6KnoqeSp7KnwqfCp7Kn0qfip+Kn0qfyqAKoAqfyqBKoIqgiqBKoMqhCqEKoMqhSqGKoYqhSqHKogqiCqHKokqiiqKKokqiyqMKowqiyqNKo4qjiqNKo8qkCqQKo8qkSqSKpIqkSqTKpQqlCqTKpUqliqWKpUqlyqYKpgqlyqZKpoqmiqZKpsqnCqcKpsqnSqeKp4qnSqfKqAqoCqfKqEqoiqiKqEqpiqnKqcqpiqoKqkqqSqoKqoqqyqrKqoqrCqtKq0qrCqvKrAqsCqvKrEqsiqyKrEqsyq0KrQqsyq1KrYqtiq1KrcquCq4KrcquSq6KroquSq7KrwqvCq7Kr0qviq+Kr0qvyrAKsAqvyrBKsIqwirBKsMqxCrEKsMqxSrGKsYqxSrHKsgqyCrHKskqyirKKskqyyrMKswqyyrNKs4qzirNKs8q0CrQKs8q0SrSKtIq0SrTKtQq1CrTKtUq1irWKtUq3iqmIuMqqSLkKqgi5SqrIuwq7SrtKuwq7iokIvcq+Cr4Kvcq+Sr6Kvoq+Sr+Kx8iAi4DLgMuAi4ELgUuBS4ELgkuCi4KLgkuDC4NLg0uDC4cLh0uHS4cLiAuIS4hLiAuIi4jLiMuIi4kLiUuJS4kLiYuJy4nLiYuKC4pLikuKC5VLlYuVi5VLlcuWC5YLlcuWS5aLlouWS5bLlwuXC5bLggwCTAJMAgwCjALMAswCjAMMA0wDTAMMA4wDzAPMA4wEDARMBEwEDAUMBUwFTAUMBYwFzAXMBYwGDAZMBkwGDAaMBswGzAaMFn+Wv5a/ln+W/5c/lz+W/5d/l7+Xv5d/mT+Zf5l/mT+CP8J/wn/CP8c/x7/Hv8c/zv/Pf89/zv/W/9d/13/W/9f/2D/YP9f/2L/Y/9j/2L/KAApAAAAKQAoAAEAWwBdAAAAXQBbAAEAewB9AAAAfQB7AAEAOg87DwAAOw86DwEAPA89DwAAPQ88DwEAmxacFgAAnBabFgEARSBGIAAARiBFIAEAfSB+IAAAfiB9IAEAjSCOIAAAjiCNIAEACCMJIwAACSMIIwEACiMLIwAACyMKIwEAKSMqIwAAKiMpIwEAaCdpJwAAaSdoJwEAaidrJwAAaydqJwEAbCdtJwAAbSdsJwEAbidvJwAAbyduJwEAcCdxJwAAcSdwJwEAcidzJwAAcydyJwEAdCd1JwAAdSd0JwEAxSfGJwAAxifFJwEA5ifnJwAA5yfmJwEA6CfpJwAA6SfoJwEA6ifrJwAA6yfqJwEA7CftJwAA7SfsJwEA7ifvJwAA7yfuJwEAgymEKQAAhCmDKQEAhSmGKQAAhimFKQEAhymIKQAAiCmHKQEAiSmKKQAAiimJKQEAiymMKQAAjCmLKQEAjSmQKQAAjimPKQEAjymOKQAAkCmNKQEAkSmSKQAAkimRKQEAkymUKQAAlCmTKQEAlSmWKQAAlimVKQEAlymYKQAAmCmXKQEA2CnZKQAA2SnYKQEA2inbKQAA2ynaKQEA/Cn9KQAA/Sn8KQEAIi4jLgAAIy4iLgEAJC4lLgAAJS4kLgEAJi4nLgAAJy4mLgEAKC4pLgAAKS4oLgEAVS5WLgAAVi5VLgEAVy5YLgAAWC5XLgEAWS5aLgAAWi5ZLgEAWy5cLgAAXC5bLgEACDAJMAAACTAIMAEACjALMAAACzAKMAEADDANMAAADTAMMAEADjAPMAAADzAOMAEAEDARMAAAETAQMAEAFDAVMAAAFTAUMAEAFjAXMAAAFzAWMAEAGDAZMAAAGTAYMAEAGjAbMAAAGzAaMAEAWf5a/gAAWv5Z/gEAW/5c/gAAXP5b/gEAXf5e/gAAXv5d/gEACP8J/wAACf8I/wEAO/89/wAAPf87/wEAW/9d/wAAXf9b/wEAX/9g/wAAYP9f/wEAYv9j/wAAY/9i/wEAAQEBAAEBAQANAAAAQAEBAIwBAQANAAAAkAEBAJwBAQANAAAAoAEBAKABAQANAAAA/QEBAP0BAQAIAAAA4AIBAOACAQAIAAAA4QIBAPsCAQADAAAAdgMBAHoDAQAIAAAAAAgBAB4JAQABAAAAHwkBAB8JAQANAAAAIAkBAAAKAQABAAAAAQoBAAMKAQAIAAAABAoBAAQKAQABAAAABQoBAAYKAQAIAAAABwoBAAsKAQABAAAADAoBAA8KAQAIAAAAEAoBADcKAQABAAAAOAoBADoKAQAIAAAAOwoBAD4KAQABAAAAPwoBAD8KAQAIAAAAQAoBAOQKAQABAAAA5QoBAOYKAQAIAAAA5woBADgLAQABAAAAOQsBAD8LAQANAAAAQAsBAP8MAQABAAAAAA0BACMNAQACAAAAJA0BACcNAQAIAAAAKA0BAC8NAQACAAAAMA0BADkNAQAGAAAAOg0BAD8NAQACAAAAQA0BAEkNAQAGAAAASg0BAGgNAQABAAAAaQ0BAG0NAQAIAAAAbg0BAG4NAQANAAAAbw0BAF8OAQABAAAAYA4BAH4OAQAGAAAAfw4BAKoOAQABAAAAqw4BAKwOAQAIAAAArQ4BAL8OAQABAAAAwA4BAPsOAQACAAAA/A4BAP8OAQAIAAAAAA8BAC8PAQABAAAAMA8BAEUPAQACAAAARg8BAFAPAQAIAAAAUQ8BAG8PAQACAAAAcA8BAIEPAQABAAAAgg8BAIUPAQAIAAAAhg8BAP8PAQABAAAAARABAAEQAQAIAAAAOBABAEYQAQAIAAAAUhABAGUQAQANAAAAcBABAHAQAQAIAAAAcxABAHQQAQAIAAAAfxABAIEQAQAIAAAAsxABALYQAQAIAAAAuRABALoQAQAIAAAAwhABAMIQAQAIAAAAABEBAAIRAQAIAAAAJxEBACsRAQAIAAAALREBADQRAQAIAAAAcxEBAHMRAQAIAAAAgBEBAIERAQAIAAAAthEBAL4RAQAIAAAAyREBAMwRAQAIAAAAzxEBAM8RAQAIAAAALxIBADESAQAIAAAANBIBADQSAQAIAAAANhIBADcSAQAIAAAAPhIBAD4SAQAIAAAAQRIBAEESAQAIAAAA3xIBAN8SAQAIAAAA4xIBAOoSAQAIAAAAABMBAAETAQAIAAAAOxMBADwTAQAIAAAAQBMBAEATAQAIAAAAZhMBAGwTAQAIAAAAcBMBAHQTAQAIAAAAuxMBAMATAQAIAAAAzhMBAM4TAQAIAAAA0BMBANATAQAIAAAA0hMBANITAQAIAAAA4RMBAOITAQAIAAAAOBQBAD8UAQAIAAAAQhQBAEQUAQAIAAAARhQBAEYUAQAIAAAAXhQBAF4UAQAIAAAAsxQBALgUAQAIAAAAuhQBALoUAQAIAAAAvxQBAMAUAQAIAAAAwhQBAMMUAQAIAAAAshUBALUVAQAIAAAAvBUBAL0VAQAIAAAAvxUBAMAVAQAIAAAA3BUBAN0VAQAIAAAAMxYBADoWAQAIAAAAPRYBAD0WAQAIAAAAPxYBAEAWAQAIAAAAYBYBAGwWAQANAAAAqxYBAKsWAQAIAAAArRYBAK0WAQAIAAAAsBYBALUWAQAIAAAAtxYBALcWAQAIAAAAHRcBAB0XAQAIAAAAHxcBAB8XAQAIAAAAIhcBACUXAQAIAAAAJxcBACsXAQAIAAAALxgBADcYAQAIAAAAORgBADoYAQAIAAAAOxkBADwZAQAIAAAAPhkBAD4ZAQAIAAAAQxkBAEMZAQAIAAAA1BkBANcZAQAIAAAA2hkBANsZAQAIAAAA4BkBAOAZAQAIAAAAARoBAAYaAQAIAAAACRoBAAoaAQAIAAAAMxoBADgaAQAIAAAAOxoBAD4aAQAIAAAARxoBAEcaAQAIAAAAURoBAFYaAQAIAAAAWRoBAFsaAQAIAAAAihoBAJYaAQAIAAAAmBoBAJkaAQAIAAAAMBwBADYcAQAIAAAAOBwBAD0cAQAIAAAAkhwBAKccAQAIAAAAqhwBALAcAQAIAAAAshwBALMcAQAIAAAAtRwBALYcAQAIAAAAMR0BADYdAQAIAAAAOh0BADodAQAIAAAAPB0BAD0dAQAIAAAAPx0BAEUdAQAIAAAARx0BAEcdAQAIAAAAkB0BAJEdAQAIAAAAlR0BAJUdAQAIAAAAlx0BAJcdAQAIAAAA8x4BAPQeAQAIAAAAAB8BAAEfAQAIAAAANh8BADofAQAIAAAAQB8BAEAfAQAIAAAAQh8BAEIfAQAIAAAAWh8BAFofAQAIAAAA1R8BANwfAQANAAAA3R8BAOAfAQAFAAAA4R8BAPEfAQANAAAAQDQBAEA0AQAIAAAARzQBAFU0AQAIAAAAHmEBAClhAQAIAAAALWEBAC9hAQAIAAAA8GoBAPRqAQAIAAAAMGsBADZrAQAIAAAAT28BAE9vAQAIAAAAj28BAJJvAQAIAAAA4m8BAOJvAQANAAAA5G8BAORvAQAIAAAAnbwBAJ68AQAIAAAAoLwBAKO8AQAJAAAAAMwBANXMAQANAAAA8MwBAPnMAQADAAAAAM0BALPOAQANAAAAAM8BAC3PAQAIAAAAMM8BAEbPAQAIAAAAZ9EBAGnRAQAIAAAAc9EBAHrRAQAJAAAAe9EBAILRAQAIAAAAhdEBAIvRAQAIAAAAqtEBAK3RAQAIAAAA6dEBAOrRAQANAAAAANIBAEHSAQANAAAAQtIBAETSAQAIAAAARdIBAEXSAQANAAAAANMBAFbTAQANAAAAwdYBAMHWAQANAAAA29YBANvWAQANAAAA+9YBAPvWAQANAAAAFdcBABXXAQANAAAANdcBADXXAQANAAAAT9cBAE/XAQANAAAAb9cBAG/XAQANAAAAidcBAInXAQANAAAAqdcBAKnXAQANAAAAw9cBAMPXAQANAAAAztcBAP/XAQADAAAAANoBADbaAQAIAAAAO9oBAGzaAQAIAAAAddoBAHXaAQAIAAAAhNoBAITaAQAIAAAAm9oBAJ/aAQAIAAAAodoBAK/aAQAIAAAAAOABAAbgAQAIAAAACOABABjgAQAIAAAAG+ABACHgAQAIAAAAI+ABACTgAQAIAAAAJuABACrgAQAIAAAAj+ABAI/gAQAIAAAAMOEBADbhAQAIAAAAruIBAK7iAQAIAAAA7OIBAO/iAQAIAAAA/+IBAP/iAQAFAAAA7OQBAO/kAQAIAAAA7uUBAO/lAQAIAAAAAOgBAM/oAQABAAAA0OgBANboAQAIAAAA1+gBAEPpAQABAAAAROkBAErpAQAIAAAAS+kBAG/sAQABAAAAcOwBAL/sAQACAAAAwOwBAP/sAQABAAAAAO0BAE/tAQACAAAAUO0BAP/tAQABAAAAAO4BAO/uAQACAAAA8O4BAPHuAQANAAAA8u4BAP/uAQACAAAAAO8BAP/vAQABAAAAAPABACvwAQANAAAAMPABAJPwAQANAAAAoPABAK7wAQANAAAAsfABAL/wAQANAAAAwfABAM/wAQANAAAA0fABAPXwAQANAAAAAPEBAArxAQADAAAAC/EBAA/xAQANAAAAL/EBAC/xAQANAAAAavEBAG/xAQANAAAArfEBAK3xAQANAAAAYPIBAGXyAQANAAAAAPMBANf2AQANAAAA3PYBAOz2AQANAAAA8PYBAPz2AQANAAAAAPcBAHb3AQANAAAAe/cBANn3AQANAAAA4PcBAOv3AQANAAAA8PcBAPD3AQANAAAAAPgBAAv4AQANAAAAEPgBAEf4AQANAAAAUPgBAFn4AQANAAAAYPgBAIf4AQANAAAAkPgBAK34AQANAAAAsPgBALv4AQANAAAAwPgBAMH4AQANAAAAAPkBAFP6AQANAAAAYPoBAG36AQANAAAAcPoBAHz6AQANAAAAgPoBAIn6AQANAAAAj/oBAMb6AQANAAAAzvoBANz6AQANAAAA3/oBAOn6AQANAAAA8PoBAPj6AQANAAAAAPsBAJL7AQANAAAAlPsBAO/7AQANAAAA8PsBAPn7AQADAAAA/v8BAP//AQAJAAAA/v8CAP//AgAJAAAA/v8DAP//AwAJAAAA/v8EAP//BAAJAAAA/v8FAP//BQAJAAAA/v8GAP//BgAJAAAA/v8HAP//BwAJAAAA/v8IAP//CAAJAAAA/v8JAP//CQAJAAAA/v8KAP//CgAJAAAA/v8LAP//CwAJAAAA/v8MAP//DAAJAAAA/v8NAP8ADgAJAAAAAAEOAO8BDgAIAAAA8AEOAP8PDgAJAAAA/v8OAP//DgAJAAAA/v8PAP//DwAJAAAA/v8QAP//EAAJAAAAXAZdBgAAXgYAAF8GAAAAAAEAAAACAAAAAwAAAAAAAQAAAAAAAAAEAAAABgAAAAgAAAAMAEGsisEACwT/////';
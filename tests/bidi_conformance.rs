@@ -0,0 +1,314 @@
+//! Conformance tests for the bidi algorithm against the Unicode test data.
+//! https://www.unicode.org/Public/8.0.0/ucd/BidiTest.txt
+//! https://www.unicode.org/Public/8.0.0/ucd/BidiCharacterTest.txt
+//!
+//! Every case is checked for its resolved levels and visual order. The pass rate is reported
+//! per rule (a case counts towards every rule its character classes exercise), run with
+//! `cargo test --test bidi_conformance -- --nocapture` to see it. The minimum pass rates below
+//! guard against regressions and should be raised as the implementation improves. Scripts
+//! added since 8.0.0 (e.g. Kawi and Nag Mundari) are covered by `NEW_SCRIPT_CASES`.
+
+use std::{fs, path::Path};
+use unicode_shaper::{
+    get_bidi_class_code_point, get_bracket, process_bidi_text_with_map, resolve_bidi_levels,
+    BaseDirection, BidiClass,
+};
+
+/// Minimum share of BidiTest.txt cases that must pass
+const MIN_BIDI_TEST_PASS_RATE: f64 = 1.0;
+/// Minimum share of BidiCharacterTest.txt cases that must pass
+const MIN_BIDI_CHARACTER_TEST_PASS_RATE: f64 = 1.0;
+
+/// Cases in the format of BidiCharacterTest.txt for scripts the 8.0.0 test data predates, e.g.
+/// Kawi and Nag Mundari with their letters, marks and digits (all L or NSM)
+const NEW_SCRIPT_CASES: &str = "\
+# Kawi letter a after alef, in a right-to-left paragraph
+05D0 0020 11F04;2;1;1 1 2;2 1 0
+# Kawi letter a with a vowel sign, before alef
+11F04 11F00 0020 05D0;2;0;0 0 0 1;0 1 2 3
+11F04 11F00;1;1;2 2;0 1
+# Kawi digit one between Arabic alef and Arabic-Indic digit one
+0627 0020 11F51 0661;2;1;1 1 2 2;2 3 1 0
+# Nag Mundari letter ok with a mark and digit one after alef
+05D0 0020 1E4D0 1E4EC 1E4F1;2;1;1 1 2 2 2;2 3 4 1 0
+# a Nag Mundari mark at the start of a paragraph
+1E4EC 05D0;0;0;0 1;0 1
+";
+
+/// The rules reported on, with the classes that exercise them
+const RULES: [(&str, &[BidiClass]); 10] = [
+    (
+        "X1-X8 embeddings/overrides",
+        &[BidiClass::LRE, BidiClass::RLE, BidiClass::LRO, BidiClass::RLO, BidiClass::PDF],
+    ),
+    ("X5a-X6a isolates", &[BidiClass::LRI, BidiClass::RLI, BidiClass::FSI, BidiClass::PDI]),
+    ("X9 boundary neutrals", &[BidiClass::BN]),
+    ("W1 nonspacing marks", &[BidiClass::NSM]),
+    ("W2-W3 arabic letters", &[BidiClass::AL]),
+    ("W4 separators", &[BidiClass::ES, BidiClass::CS]),
+    ("W5-W6 terminators", &[BidiClass::ET]),
+    ("W7 european numbers", &[BidiClass::EN]),
+    ("N1-N2 neutrals", &[BidiClass::ON, BidiClass::WS, BidiClass::S, BidiClass::B]),
+    ("L1 separators/whitespace", &[BidiClass::S, BidiClass::B, BidiClass::WS]),
+];
+
+/// Pass counts of every rule
+struct Report {
+    name: &'static str,
+    rules: Vec<(&'static str, usize, usize)>,
+    brackets: (usize, usize),
+    all: (usize, usize),
+}
+impl Report {
+    fn new(name: &'static str) -> Report {
+        let rules = RULES.iter().map(|(rule, _)| (*rule, 0, 0)).collect();
+        Report { name, rules, brackets: (0, 0), all: (0, 0) }
+    }
+
+    fn add(&mut self, classes: &[BidiClass], has_brackets: bool, passed: bool) {
+        for ((_, rule_classes), stats) in RULES.iter().zip(self.rules.iter_mut()) {
+            if classes.iter().any(|c| rule_classes.contains(c)) {
+                stats.1 += passed as usize;
+                stats.2 += 1;
+            }
+        }
+        if has_brackets {
+            self.brackets.0 += passed as usize;
+            self.brackets.1 += 1;
+        }
+        self.all.0 += passed as usize;
+        self.all.1 += 1;
+    }
+
+    fn print(&self) {
+        let line = |rule: &str, (passed, total): (usize, usize)| {
+            let rate = if total == 0 { 100.0 } else { passed as f64 * 100.0 / total as f64 };
+            println!("  {rule:<28} {passed:>7} / {total:<7} {rate:>6.2}%");
+        };
+        println!("{}", self.name);
+        for (rule, passed, total) in self.rules.iter() {
+            line(rule, (*passed, *total));
+        }
+        line("N0 paired brackets", self.brackets);
+        line("all", self.all);
+    }
+
+    fn pass_rate(&self) -> f64 {
+        self.all.0 as f64 / self.all.1 as f64
+    }
+}
+
+/// A representative character of each Bidi_Class used by BidiTest.txt
+fn class_char(class: &str) -> (u16, BidiClass) {
+    match class {
+        "L" => (0x0061, BidiClass::L),
+        "R" => (0x05D0, BidiClass::R),
+        "AL" => (0x0627, BidiClass::AL),
+        "EN" => (0x0031, BidiClass::EN),
+        "ES" => (0x002B, BidiClass::ES),
+        "ET" => (0x0024, BidiClass::ET),
+        "AN" => (0x0661, BidiClass::AN),
+        "CS" => (0x002C, BidiClass::CS),
+        "NSM" => (0x0300, BidiClass::NSM),
+        "BN" => (0x00AD, BidiClass::BN),
+        // information separator, a paragraph separator that is not a line terminator
+        "B" => (0x001C, BidiClass::B),
+        "S" => (0x0009, BidiClass::S),
+        "WS" => (0x0020, BidiClass::WS),
+        "ON" => (0x0021, BidiClass::ON),
+        "LRE" => (0x202A, BidiClass::LRE),
+        "RLE" => (0x202B, BidiClass::RLE),
+        "PDF" => (0x202C, BidiClass::PDF),
+        "LRO" => (0x202D, BidiClass::LRO),
+        "RLO" => (0x202E, BidiClass::RLO),
+        "LRI" => (0x2066, BidiClass::LRI),
+        "RLI" => (0x2067, BidiClass::RLI),
+        "FSI" => (0x2068, BidiClass::FSI),
+        "PDI" => (0x2069, BidiClass::PDI),
+        _ => panic!("unknown bidi class {class}"),
+    }
+}
+
+/// Resolve the paragraph level and the level of every code unit with `resolve_bidi_levels`,
+/// and the visual order with `process_bidi_text_with_map`. Line terminators take the level
+/// of their paragraph.
+fn resolve(input: &[u16], direction: BaseDirection) -> (u8, Vec<u8>, Vec<usize>) {
+    let paragraphs = resolve_bidi_levels(input, direction);
+    let mut levels: Vec<u8> = vec![0; input.len()];
+    for (idx, paragraph) in paragraphs.iter().enumerate() {
+        let next_start = paragraphs.get(idx + 1).map_or(input.len(), |p| p.start);
+        levels[paragraph.start..paragraph.end].copy_from_slice(&paragraph.levels);
+        for level in levels[paragraph.end..next_start].iter_mut() {
+            *level = paragraph.level;
+        }
+    }
+    let para_level = paragraphs.first().map_or(0, |p| p.level);
+    let mut order = Vec::<usize>::new();
+    process_bidi_text_with_map(input, direction, &mut order);
+
+    (para_level, levels, order)
+}
+
+/// Compare the resolved paragraph level, levels and order to the expected ones, `None` levels
+/// are not checked and their characters are skipped in the visual order.
+fn matches(
+    input: &[u16],
+    direction: BaseDirection,
+    para_level: u8,
+    levels: &[Option<u8>],
+    order: &[usize],
+) -> bool {
+    let (resolved_para_level, resolved_levels, resolved_order) = resolve(input, direction);
+    let levels_match = resolved_para_level == para_level
+        && levels.iter().zip(resolved_levels.iter()).all(|(e, r)| e.is_none_or(|e| e == *r));
+    let visible_order: Vec<usize> =
+        resolved_order.into_iter().filter(|idx| levels[*idx].is_some()).collect();
+
+    levels_match && visible_order == order
+}
+
+/// The paragraph level BidiTest.txt expects for a direction: the level of the first strong class
+/// of the first paragraph outside of isolates for `Auto` (rules P2 and P3)
+fn expected_para_level(classes: &[BidiClass], direction: BaseDirection) -> u8 {
+    match direction {
+        BaseDirection::Ltr => return 0,
+        BaseDirection::Rtl => return 1,
+        BaseDirection::Auto => {}
+    }
+    let mut isolates: usize = 0;
+    for class in classes {
+        match class {
+            BidiClass::B => break,
+            BidiClass::LRI | BidiClass::RLI | BidiClass::FSI => isolates += 1,
+            BidiClass::PDI => isolates = isolates.saturating_sub(1),
+            BidiClass::L if isolates == 0 => return 0,
+            BidiClass::R | BidiClass::AL if isolates == 0 => return 1,
+            _ => {}
+        }
+    }
+    0
+}
+
+/// Read a file of tests/data, which is left out of the published crate, or `None` to skip its
+/// test when it is missing
+fn read_test_data(name: &str) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data").join(name);
+    match fs::read_to_string(&path) {
+        Ok(data) => Some(data),
+        Err(err) => {
+            println!("skipping {name}, {} can't be read: {err}", path.display());
+            None
+        }
+    }
+}
+
+/// Parse a list of levels where `x` is a removed character
+fn parse_levels(levels: &str) -> Vec<Option<u8>> {
+    levels.split_whitespace().map(|l| l.parse::<u8>().ok()).collect()
+}
+
+/// Parse a list of indices
+fn parse_order(order: &str) -> Vec<usize> {
+    order.split_whitespace().map(|i| i.parse::<usize>().unwrap()).collect()
+}
+
+#[test]
+fn bidi_test() {
+    let Some(data) = read_test_data("BidiTest.txt") else {
+        return;
+    };
+    let mut report = Report::new("BidiTest.txt");
+    let mut levels = Vec::<Option<u8>>::new();
+    let mut order = Vec::<usize>::new();
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(l) = line.strip_prefix("@Levels:") {
+            levels = parse_levels(l);
+            continue;
+        }
+        if let Some(o) = line.strip_prefix("@Reorder:") {
+            order = parse_order(o);
+            continue;
+        }
+        if line.starts_with('@') {
+            continue;
+        }
+        let (classes, bitset) = line.split_once(';').unwrap();
+        let (input, classes): (Vec<u16>, Vec<BidiClass>) =
+            classes.split_whitespace().map(class_char).unzip();
+        let bitset: u8 = bitset.trim().parse().unwrap();
+        let directions =
+            [(1, BaseDirection::Auto), (2, BaseDirection::Ltr), (4, BaseDirection::Rtl)];
+        for (bit, direction) in directions {
+            if bitset & bit != 0 {
+                let para_level = expected_para_level(&classes, direction);
+                let passed = matches(&input, direction, para_level, &levels, &order);
+                report.add(&classes, false, passed);
+            }
+        }
+    }
+    report.print();
+    assert!(report.pass_rate() >= MIN_BIDI_TEST_PASS_RATE);
+}
+
+/// Check every case of a file in the format of BidiCharacterTest.txt
+fn run_bidi_character_test(name: &'static str, data: &str) -> Report {
+    let mut report = Report::new(name);
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        let chars: Vec<char> = fields[0]
+            .split_whitespace()
+            .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+            .collect();
+        // the indices of the test data are code points, map them to the code units of the input
+        let mut input = Vec::<u16>::new();
+        let mut units = Vec::<core::ops::Range<usize>>::new();
+        for c in chars.iter() {
+            let start = input.len();
+            input.extend(c.encode_utf16(&mut [0; 2]).iter());
+            units.push(start..input.len());
+        }
+        let direction = match fields[1] {
+            "0" => BaseDirection::Ltr,
+            "1" => BaseDirection::Rtl,
+            _ => BaseDirection::Auto,
+        };
+        let para_level: u8 = fields[2].parse().unwrap();
+        let levels: Vec<Option<u8>> = parse_levels(fields[3])
+            .into_iter()
+            .zip(units.iter())
+            .flat_map(|(level, range)| core::iter::repeat_n(level, range.len()))
+            .collect();
+        let order: Vec<usize> =
+            parse_order(fields[4]).into_iter().flat_map(|idx| units[idx].clone()).collect();
+        let classes: Vec<BidiClass> =
+            chars.iter().map(|c| get_bidi_class_code_point(&(*c).into())).collect();
+        let has_brackets = input.iter().any(|c| get_bracket(c).is_some());
+        let passed = matches(&input, direction, para_level, &levels, &order);
+        report.add(&classes, has_brackets, passed);
+    }
+    report.print();
+    report
+}
+
+#[test]
+fn bidi_character_test() {
+    let Some(data) = read_test_data("BidiCharacterTest.txt") else {
+        return;
+    };
+    let report = run_bidi_character_test("BidiCharacterTest.txt", &data);
+    assert!(report.pass_rate() >= MIN_BIDI_CHARACTER_TEST_PASS_RATE);
+}
+
+#[test]
+fn bidi_character_test_new_scripts() {
+    let report = run_bidi_character_test("scripts after 8.0.0", NEW_SCRIPT_CASES);
+    assert_eq!(report.pass_rate(), 1.0);
+}
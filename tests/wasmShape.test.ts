@@ -1,4 +1,9 @@
-import { DEFAULT_OPTIONS_WITHOUT_BIDI_SHAPING, WasmTextShaper } from '../src';
+import {
+  DEFAULT_OPTIONS,
+  DEFAULT_OPTIONS_WITHOUT_BIDI_SHAPING,
+  U_SHAPE_DIGITS_RESERVED,
+  WasmTextShaper,
+} from '../src';
 import { describe, expect, test } from 'bun:test';
 
 describe('many different string cases', () => {
@@ -53,6 +58,46 @@ describe('many different string cases', () => {
   });
 });
 
+describe('try shape string', () => {
+  const wasm = new WasmTextShaper();
+  test('valid input', () => {
+    expect(wasm.tryShapeString('')).toEqual('');
+    expect(wasm.tryShapeString('سلام۳۹')).toEqual('۳۹ﻡﻼﺳ');
+  });
+  test('invalid options', () => {
+    const options = DEFAULT_OPTIONS | U_SHAPE_DIGITS_RESERVED;
+    expect(wasm.tryShapeString('سلام۳۹', options)).toBeUndefined();
+  });
+  test('unpaired surrogate', () => {
+    expect(wasm.tryShapeString('ب\udd00')).toBeUndefined();
+  });
+  test('non-joiner runs', () => {
+    expect(wasm.tryShapeString('\u200c\u200c')).toEqual('\u200c\u200c');
+    expect(wasm.tryShapeString(' \u200c\u200c')).toEqual(' \u200c\u200c');
+  });
+  test('myanmar mark at the start of a cluster', () => {
+    const input = String.fromCharCode(0x1036, 0x1037, 0x1094);
+    expect(wasm.tryShapeString(input, 0x6000b)).toEqual(input);
+  });
+});
+
+describe('joining forms', () => {
+  const wasm = new WasmTextShaper();
+  test('empty string', () => {
+    expect(wasm.joiningForms('')).toEqual([]);
+  });
+  test('arabic string', () => {
+    expect(wasm.joiningForms('سلام')).toEqual(['init', 'medi', 'fina', 'isol']);
+  });
+  test('non joining characters', () => {
+    expect(wasm.joiningForms('a ب')).toEqual([undefined, undefined, 'isol']);
+  });
+  test('scripts do not join each other', () => {
+    expect(wasm.joiningForms('بܒ')).toEqual(['isol', 'isol']);
+    expect(wasm.joiningForms('بـܒ')).toEqual(['init', 'fina', 'isol']);
+  });
+});
+
 describe('boolean RTL & CJK', () => {
   const wasm = new WasmTextShaper();
   test('RTL', () => {
@@ -0,0 +1,150 @@
+//! Development tasks of unicode-shaper, run with `cargo xtask <task>`.
+//!
+//! `gen-tables <UCD_DIR>` regenerates the Unicode property tables of the crate from the
+//! Unicode Character Database files in `UCD_DIR`: UnicodeData.txt, ArabicShaping.txt,
+//! BidiMirroring.txt, BidiBrackets.txt, IndicSyllabicCategory.txt, Blocks.txt and Scripts.txt of
+//! the same Unicode version, found at https://www.unicode.org/Public/<version>/ucd/.
+//! Every table lives between a `// BEGIN GENERATED <NAME>` and a `// END GENERATED <NAME>`
+//! line in the source and is replaced in place.
+
+mod tables;
+mod ucd;
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+use ucd::UnicodeData;
+
+const USAGE: &str = "usage: cargo xtask gen-tables <UCD_DIR>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("gen-tables") if args.len() == 2 => gen_tables(&workspace_root(), Path::new(&args[1])),
+        _ => Err(USAGE.into()),
+    };
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+/// Generate every table and write it into its source file
+fn gen_tables(root: &Path, ucd_dir: &Path) -> Result<(), String> {
+    let unicode_data = UnicodeData::parse(&ucd::read(ucd_dir, "UnicodeData.txt")?)?;
+    let arabic_shaping = ucd::read(ucd_dir, "ArabicShaping.txt")?;
+    let bidi_mirroring = ucd::read(ucd_dir, "BidiMirroring.txt")?;
+    let bidi_brackets = ucd::read(ucd_dir, "BidiBrackets.txt")?;
+    let indic_syllabic_category = ucd::read(ucd_dir, "IndicSyllabicCategory.txt")?;
+    let blocks = ucd::read(ucd_dir, "Blocks.txt")?;
+    let scripts = ucd::read(ucd_dir, "Scripts.txt")?;
+    let (bidi_class, bidi_class_supplementary) = tables::bidi_class(&unicode_data);
+    let version = ucd_version(&[
+        ("ArabicShaping.txt", &arabic_shaping),
+        ("BidiMirroring.txt", &bidi_mirroring),
+        ("BidiBrackets.txt", &bidi_brackets),
+        ("IndicSyllabicCategory.txt", &indic_syllabic_category),
+        ("Blocks.txt", &blocks),
+        ("Scripts.txt", &scripts),
+    ])?;
+
+    let files = [
+        (
+            "rust/ubidi/internal.rs",
+            vec![
+                ("MIRROR_CHAR", tables::mirror_char(&bidi_mirroring)?),
+                ("BIDI_BRACKETS", tables::bidi_brackets(&bidi_brackets)?),
+                ("BIDI_CLASS", bidi_class),
+                ("BIDI_CLASS_SUPPLEMENTARY", bidi_class_supplementary),
+            ],
+        ),
+        (
+            "rust/shape/internal.rs",
+            vec![
+                ("ARA_LINK", tables::ara_link(&unicode_data, &arabic_shaping)?),
+                ("JOINING_LINK", tables::joining_link(&unicode_data, &arabic_shaping, &scripts)?),
+                (
+                    "KHMER_DEPENDENT_VOWELS",
+                    tables::khmer_dependent_vowels(&indic_syllabic_category)?,
+                ),
+            ],
+        ),
+        ("rust/shape/cjk.rs", vec![("CJK", tables::cjk(&blocks)?)]),
+        ("rust/lib.rs", vec![("UNICODE_VERSION", tables::unicode_version(version))]),
+    ];
+    for (file, generated) in files {
+        let path = root.join(file);
+        let mut source = fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        for (name, code) in generated {
+            source = replace_region(&source, name, &code)
+                .ok_or(format!("{file} has no generated region {name}"))?;
+        }
+        fs::write(&path, source)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+        // keep the generated code stable under `cargo fmt`
+        let status = Command::new("rustfmt").args(["--edition", "2021"]).arg(&path).status();
+        if !status.is_ok_and(|s| s.success()) {
+            return Err(format!("failed to format {}", path.display()));
+        }
+        println!("generated {file}");
+    }
+
+    Ok(())
+}
+
+/// The Unicode version of the [name, content] of UCD files, which must all be the same
+fn ucd_version(files: &[(&str, &str)]) -> Result<(u8, u8, u8), String> {
+    let mut version: Option<(u8, u8, u8)> = None;
+    for (name, text) in files {
+        let file_version =
+            ucd::version(text, name).ok_or(format!("{name} has no version header"))?;
+        if version.is_some_and(|v| v != file_version) {
+            return Err(format!("{name} is not from the same Unicode version as {}", files[0].0));
+        }
+        version = Some(file_version);
+    }
+
+    version.ok_or("no UCD files".into())
+}
+
+/// Replace the code between the `BEGIN GENERATED` and `END GENERATED` lines of a table
+fn replace_region(source: &str, name: &str, code: &str) -> Option<String> {
+    let begin = source.find(&format!("// BEGIN GENERATED {name}"))?;
+    let start = begin + source[begin..].find('\n')? + 1;
+    let stop = start + source[start..].find(&format!("// END GENERATED {name}"))?;
+
+    Some([&source[..start], code, &source[stop..]].concat())
+}
+
+/// The directory of the workspace Cargo.toml
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_generated_region() {
+        let source = "/// Docs\n// BEGIN GENERATED T (cargo xtask gen-tables)\npub static T: \
+                      [u8; 1] = [1];\n// END GENERATED T\nfn f() {}\n";
+        assert_eq!(
+            replace_region(source, "T", "pub static T: [u8; 2] = [1, 2];\n").unwrap(),
+            "/// Docs\n// BEGIN GENERATED T (cargo xtask gen-tables)\npub static T: [u8; 2] = \
+             [1, 2];\n// END GENERATED T\nfn f() {}\n"
+        );
+        assert_eq!(replace_region(source, "U", ""), None);
+    }
+
+    #[test]
+    fn mixed_versions() {
+        let arabic_shaping = ("ArabicShaping.txt", "# ArabicShaping-16.0.0.txt\n");
+        let blocks = ("Blocks.txt", "# Blocks-15.1.0.txt\n");
+        assert_eq!(ucd_version(&[arabic_shaping]), Ok((16, 0, 0)));
+        assert!(ucd_version(&[arabic_shaping, blocks]).is_err());
+    }
+}
@@ -0,0 +1,569 @@
+//! Derive the property tables of the crate from the UCD and render them as Rust source.
+
+use crate::ucd::{parse_code_point, parse_ranges, UnicodeData};
+use std::{collections::BTreeMap, fmt::Write};
+
+/// The Bidi_Class of unassigned code points that are not L, from the `@missing` lines of
+/// DerivedBidiClass.txt
+const BIDI_CLASS_DEFAULTS: [(u32, u32, &str); 28] = [
+    (0x0590, 0x05FF, "R"),
+    (0x0600, 0x07BF, "AL"),
+    (0x07C0, 0x085F, "R"),
+    (0x0860, 0x08FF, "AL"),
+    (0x2060, 0x206F, "BN"),
+    (0x20A0, 0x20CF, "ET"),
+    (0xFB1D, 0xFB4F, "R"),
+    (0xFB50, 0xFDCF, "AL"),
+    (0xFDD0, 0xFDEF, "BN"),
+    (0xFDF0, 0xFDFF, "AL"),
+    (0xFE70, 0xFEFF, "AL"),
+    (0xFFF0, 0xFFF8, "BN"),
+    (0xFFFE, 0xFFFF, "BN"),
+    (0x10800, 0x10CFF, "R"),
+    (0x10D00, 0x10D3F, "AL"),
+    (0x10D40, 0x10EBF, "R"),
+    (0x10EC0, 0x10EFF, "AL"),
+    (0x10F00, 0x10F2F, "R"),
+    (0x10F30, 0x10F6F, "AL"),
+    (0x10F70, 0x10FFF, "R"),
+    (0x1E800, 0x1EC6F, "R"),
+    (0x1EC70, 0x1ECBF, "AL"),
+    (0x1ECC0, 0x1ECFF, "R"),
+    (0x1ED00, 0x1ED4F, "AL"),
+    (0x1ED50, 0x1EDFF, "R"),
+    (0x1EE00, 0x1EEFF, "AL"),
+    (0x1EF00, 0x1EFFF, "R"),
+    (0xE0000, 0xE0FFF, "BN"),
+];
+
+/// The first and last character of `ARA_LINK`, through the end of Arabic Extended-A
+const ARA_LINK_RANGE: (u32, u32) = (0x0622, 0x08FF);
+
+/// The Arabic blocks of `ARA_LINK_RANGE`. Syriac, Thaana, N'Ko, Samaritan and Mandaic between
+/// them are left non-joining, so Arabic letters don't join with them.
+const ARABIC_BLOCKS: [(u32, u32); 3] = [(0x0600, 0x06FF), (0x0750, 0x077F), (0x0870, 0x08FF)];
+
+// link flags of ARA_LINK, see rust/shape/internal.rs
+const LINKR: u32 = 1;
+const LINKL: u32 = 2;
+const IRRELEVANT: u32 = 4;
+const APRESENT: u32 = 8;
+const LAMTYPE: u32 = 16;
+const ALEFTYPE: u32 = 32;
+const SHADDA: u32 = 64;
+const CSHADDA: u32 = 128;
+
+/// The alef characters that form a lam-alef ligature
+const ALEF_TYPE: [u32; 7] = [0x0622, 0x0623, 0x0625, 0x0627, 0x0672, 0x0673, 0x0675];
+
+/// [mark, flags, position] of the tashkeel marks and the superscript alef, which are linked
+/// like them. The position is the index of the isolated form in `IRRELEVANT_POS`.
+const TASHKEEL: [(u32, u32, u32); 13] = [
+    (0x064B, 0, 1),
+    (0x064C, CSHADDA, 1),
+    (0x064D, CSHADDA, 1),
+    (0x064E, CSHADDA, 1),
+    (0x064F, CSHADDA, 1),
+    (0x0650, CSHADDA, 1),
+    (0x0651, SHADDA, 3),
+    (0x0652, 0, 1),
+    (0x0653, 0, 7),
+    (0x0654, 0, 8),
+    (0x0655, 0, 8),
+    (0x0656, 0, 1),
+    (0x0670, 0, 6),
+];
+
+/// [placeholder, alef] pairs. The shaper temporarily replaces the alef of a lam-alef with a
+/// placeholder in U+065C–U+065F, which links like the isolated lam-alef ligature.
+const LAM_ALEF_PLACEHOLDERS: [(u32, u32); 4] =
+    [(0x065C, 0x0622), (0x065D, 0x0623), (0x065E, 0x0625), (0x065F, 0x0627)];
+
+/// The blocks of CJK characters whose names do not contain "CJK"
+const CJK_BLOCKS: [&str; 7] = [
+    "Kangxi Radicals",
+    "Ideographic Description Characters",
+    "Ideographic Symbols and Punctuation",
+    "Halfwidth and Fullwidth Forms",
+    "Small Form Variants",
+    "Vertical Forms",
+    "Enclosed CJK Letters and Months",
+];
+
+/// `BIDI_CLASS` and `BIDI_CLASS_SUPPLEMENTARY` from UnicodeData.txt
+pub fn bidi_class(data: &UnicodeData) -> (String, String) {
+    let mut classes = vec!["L"; 0x110000];
+    for (first, last, class) in BIDI_CLASS_DEFAULTS {
+        classes[first as usize..=last as usize].fill(class);
+    }
+    // the last two code points of every plane are noncharacters
+    for plane in 1..=0x10 {
+        classes[plane << 16 | 0xFFFE..=plane << 16 | 0xFFFF].fill("BN");
+    }
+    for (first, last, char_data) in data.entries.iter() {
+        classes[*first as usize..=*last as usize].fill(char_data.bidi_class.as_str());
+    }
+    let mut rows = Vec::<String>::new();
+    let mut supplementary_rows = Vec::<String>::new();
+    let mut start: usize = 0;
+    for cp in 1..=classes.len() {
+        // ranges never cross from the Basic Multilingual Plane into the supplementary planes
+        if cp < classes.len() && cp != 0x10000 && classes[cp] == classes[start] {
+            continue;
+        }
+        if classes[start] != "L" {
+            let row = format!("(0x{start:04X}, 0x{:04X}, BidiClass::{}),", cp - 1, classes[start]);
+            if start < 0x10000 {
+                rows.push(row)
+            } else {
+                supplementary_rows.push(row)
+            }
+        }
+        start = cp;
+    }
+
+    (
+        render("pub static BIDI_CLASS: [(u16, u16, BidiClass); {len}]", &rows, 6),
+        render(
+            "pub static BIDI_CLASS_SUPPLEMENTARY: [(u32, u32, BidiClass); {len}]",
+            &supplementary_rows,
+            12,
+        ),
+    )
+}
+
+/// `MIRROR_CHAR` from BidiMirroring.txt
+pub fn mirror_char(bidi_mirroring: &str) -> Result<String, String> {
+    let mut pairs = Vec::<(u32, u32)>::new();
+    for (cp, _, fields) in parse_ranges(bidi_mirroring)? {
+        let mirror = parse_code_point(fields.first().copied().unwrap_or_default())?;
+        if cp <= 0xFFFF && mirror <= 0xFFFF {
+            pairs.push((cp, mirror));
+        }
+    }
+    pairs.sort();
+    let rows: Vec<String> =
+        pairs.iter().map(|(cp, mirror)| format!("[0x{cp:04X}, 0x{mirror:04X}],")).collect();
+
+    Ok(render("pub static MIRROR_CHAR: [[u16; 2]; {len}]", &rows, 4))
+}
+
+/// `BIDI_BRACKETS` from BidiBrackets.txt, a [bracket, paired bracket, type] row (type 0 opens
+/// and 1 closes) for every opening and closing bracket sorted by bracket
+pub fn bidi_brackets(bidi_brackets: &str) -> Result<String, String> {
+    let mut brackets = Vec::<(u32, u32, u8)>::new();
+    for (cp, _, fields) in parse_ranges(bidi_brackets)? {
+        let [pair, bracket_type] = fields[..] else {
+            return Err(format!("invalid bracket line for {cp:04X}"));
+        };
+        let bracket_type = match bracket_type {
+            "o" => 0,
+            "c" => 1,
+            _ => return Err(format!("invalid bracket type {bracket_type:?} of {cp:04X}")),
+        };
+        let pair = parse_code_point(pair)?;
+        if cp <= 0xFFFF && pair <= 0xFFFF {
+            brackets.push((cp, pair, bracket_type));
+        }
+    }
+    brackets.sort();
+    let rows: Vec<String> = brackets
+        .iter()
+        .map(|(cp, pair, bracket_type)| format!("[0x{cp:04X}, 0x{pair:04X}, {bracket_type}],"))
+        .collect();
+
+    Ok(render("pub static BIDI_BRACKETS: [[u16; 3]; {len}]", &rows, 6))
+}
+
+/// `ARA_LINK` from ArabicShaping.txt and the presentation forms of UnicodeData.txt. Every
+/// character gets its link flags and the offset of its isolated form from U+FE70 (or from
+/// U+FB50 with `APRESENT`) if its final form follows it. Dual joining characters without initial
+/// and medial forms after those can only be shaped as right joining. Characters outside the
+/// Arabic blocks don't join.
+pub fn ara_link(data: &UnicodeData, arabic_shaping: &str) -> Result<String, String> {
+    let mut joining_types = BTreeMap::<u32, &str>::new();
+    for (cp, _, fields) in parse_ranges(arabic_shaping)? {
+        let joining_type = fields.get(1).ok_or(format!("missing joining type of {cp:04X}"))?;
+        joining_types.insert(cp, joining_type);
+    }
+    let forms = data.presentation_forms()?;
+    let isolated_form = |chars: Vec<u32>| -> Option<u32> {
+        let forms = forms.get(&("isolated".into(), chars))?;
+        // prefer Arabic Presentation Forms-B
+        forms.iter().find(|f| **f >= 0xFE70).or(forms.first()).copied()
+    };
+    let has_form = |tag: &str, chars: u32, form: u32| {
+        forms.get(&(tag.into(), vec![chars])).is_some_and(|f| f.contains(&form))
+    };
+
+    let mut rows = Vec::<String>::new();
+    for cp in ARA_LINK_RANGE.0..=ARA_LINK_RANGE.1 {
+        if !ARABIC_BLOCKS.iter().any(|(first, last)| (*first..=*last).contains(&cp)) {
+            rows.push(format!("0, // 0x{cp:04X}"));
+            continue;
+        }
+        let mut flags = Vec::<u32>::new();
+        let mut offset: Option<u32> = None;
+        if let Some((_, flag, pos)) = TASHKEEL.iter().find(|(c, _, _)| *c == cp) {
+            flags.extend([IRRELEVANT, *flag]);
+            offset = Some(*pos);
+        } else if let Some((_, alef)) = LAM_ALEF_PLACEHOLDERS.iter().find(|(c, _)| *c == cp) {
+            let form = isolated_form(vec![0x0644, *alef])
+                .ok_or(format!("missing lam-alef ligature of {alef:04X}"))?;
+            flags.push(LINKR);
+            offset = Some(form - 0xFE70);
+        } else {
+            let category = data.get(cp).map(|c| c.category.as_str()).unwrap_or_default();
+            let joining_type = match joining_types.get(&cp) {
+                Some(joining_type) => *joining_type,
+                None if matches!(category, "Mn" | "Me" | "Cf") => "T",
+                None => "U",
+            };
+            let mut link = joining_type_link(joining_type);
+            let form = isolated_form(vec![cp]).filter(|f| has_form("final", cp, f + 1));
+            if let Some(form) = form.filter(|_| link & IRRELEVANT == 0) {
+                if !(has_form("initial", cp, form + 2) && has_form("medial", cp, form + 3)) {
+                    link &= !LINKL;
+                }
+                if form >= 0xFE70 {
+                    offset = Some(form - 0xFE70);
+                } else {
+                    link += APRESENT;
+                    offset = Some(form - 0xFB50).filter(|o| *o > 0);
+                }
+            }
+            flags.extend([link & LINKR, link & LINKL]);
+            if link & (LINKR + LINKL) != 0 {
+                flags.push(link & APRESENT);
+                if cp == 0x0644 {
+                    flags.push(LAMTYPE);
+                }
+                if ALEF_TYPE.contains(&cp) {
+                    flags.push(ALEFTYPE);
+                }
+            }
+            flags.push(link & IRRELEVANT);
+        }
+        let mut terms: Vec<String> =
+            flags.iter().filter(|f| **f != 0).map(|f| f.to_string()).collect();
+        match offset {
+            Some(1) => terms.push("256".into()),
+            Some(pos) if pos < 0x10 && flags.contains(&IRRELEVANT) => {
+                terms.push(format!("256 * {pos}"))
+            }
+            Some(offset) => terms.push(format!("256 * 0x{offset:02X}")),
+            None => {}
+        }
+        let value = if terms.is_empty() { "0".into() } else { terms.join(" + ") };
+        rows.push(format!("{value}, // 0x{cp:04X}"));
+    }
+
+    Ok(render("pub static ARA_LINK: [u16; {len}]", &rows, 2))
+}
+
+/// `JOINING_LINK` from ArabicShaping.txt and Scripts.txt: the plain Joining_Type of every script
+/// as link flags, without the adjustments `ARA_LINK` makes for the presentation forms, and the
+/// script of the joining characters so that joins break between scripts. Scripts are numbered
+/// from 1 in the order of their first joining character, i.e. Arabic is 1. Common and Inherited
+/// characters (e.g. tatweel and ZWJ) and transparent ones join any script and get 0. Unlisted Mn,
+/// Me and Cf characters are transparent.
+pub fn joining_link(
+    data: &UnicodeData,
+    arabic_shaping: &str,
+    scripts: &str,
+) -> Result<String, String> {
+    let mut links = vec![0; 0x110000];
+    for (first, last, char_data) in data.entries.iter() {
+        if matches!(char_data.category.as_str(), "Mn" | "Me" | "Cf") {
+            links[*first as usize..=*last as usize].fill(IRRELEVANT);
+        }
+    }
+    // ZWNJ is a Cf character that breaks the join instead of being transparent to it
+    links[0x200C] = 0;
+    for (first, last, fields) in parse_ranges(arabic_shaping)? {
+        let joining_type = fields.get(1).ok_or(format!("missing joining type of {first:04X}"))?;
+        links[first as usize..=last as usize].fill(joining_type_link(joining_type));
+    }
+    let mut script_names = vec![""; 0x110000];
+    for (first, last, fields) in parse_ranges(scripts)? {
+        let script = fields.first().ok_or(format!("missing script of {first:04X}"))?;
+        script_names[first as usize..=last as usize].fill(script);
+    }
+    let mut joining_scripts = Vec::<&str>::new();
+    let mut script_ids = vec![0; 0x110000];
+    for (cp, link) in links.iter().enumerate() {
+        let script = script_names[cp];
+        if link & (LINKR + LINKL) == 0 || matches!(script, "" | "Common" | "Inherited") {
+            continue;
+        }
+        script_ids[cp] = match joining_scripts.iter().position(|s| *s == script) {
+            Some(idx) => idx + 1,
+            None => {
+                joining_scripts.push(script);
+                joining_scripts.len()
+            }
+        };
+    }
+
+    let mut rows = Vec::<String>::new();
+    let mut start: usize = 0;
+    for cp in 1..=links.len() {
+        if cp < links.len() && links[cp] == links[start] && script_ids[cp] == script_ids[start] {
+            continue;
+        }
+        if links[start] != 0 {
+            let terms: Vec<String> = [LINKR, LINKL, IRRELEVANT]
+                .iter()
+                .filter(|flag| links[start] & **flag != 0)
+                .map(|flag| flag.to_string())
+                .collect();
+            rows.push(format!(
+                "(0x{start:04X}, 0x{:04X}, {}, {}),",
+                cp - 1,
+                terms.join(" + "),
+                script_ids[start]
+            ));
+        }
+        start = cp;
+    }
+
+    Ok(render("pub static JOINING_LINK: [(u32, u32, u16, u8); {len}]", &rows, 12))
+}
+
+/// The link flags of a Joining_Type of ArabicShaping.txt
+fn joining_type_link(joining_type: &str) -> u32 {
+    match joining_type {
+        "R" => LINKR,
+        "L" => LINKL,
+        "D" | "C" => LINKR + LINKL,
+        "T" => IRRELEVANT,
+        _ => 0,
+    }
+}
+
+/// `CJK` from Blocks.txt
+pub fn cjk(blocks: &str) -> Result<String, String> {
+    let mut rows = Vec::<String>::new();
+    let mut len: usize = 0;
+    for (first, last, fields) in parse_ranges(blocks)? {
+        let name = fields.first().copied().unwrap_or_default();
+        if !name.contains("CJK") && !CJK_BLOCKS.contains(&name) {
+            continue;
+        }
+        rows.push(format!("// {name} (Range: {first:04X}–{last:04X})"));
+        rows.push(format!("[0x{first:04X}, 0x{last:04X}],"));
+        len += 1;
+    }
+    let declaration = format!("pub static CJK: [[u32; 2]; {len}]");
+
+    Ok(render_sized(&declaration, &rows, len * 8))
+}
+
+/// `KHMER_DEPENDENT_VOWELS` from IndicSyllabicCategory.txt
+pub fn khmer_dependent_vowels(indic_syllabic_category: &str) -> Result<String, String> {
+    let mut vowels = Vec::<String>::new();
+    for (first, last, fields) in parse_ranges(indic_syllabic_category)? {
+        if fields.first() == Some(&"Vowel_Dependent") && (0x1780..=0x17FF).contains(&first) {
+            vowels.extend((first..=last).map(|cp| format!("0x{cp:04X},")));
+        }
+    }
+    let declaration = format!("pub const KHMER_DEPENDENT_VOWELS: [u16; {}]", vowels.len());
+
+    Ok(format!("{declaration} = [\n    {}\n];\n", vowels.join(" ")))
+}
+
+/// `UNICODE_VERSION` of the generated tables
+pub fn unicode_version((major, minor, update): (u8, u8, u8)) -> String {
+    format!("pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});\n")
+}
+
+/// Render a static table of `rows` with its size in bytes
+fn render(declaration: &str, rows: &[String], row_size: usize) -> String {
+    let declaration = declaration.replace("{len}", &rows.len().to_string());
+    render_sized(&declaration, rows, rows.len() * row_size)
+}
+
+/// Render a static table of `rows` with a given size in bytes
+fn render_sized(declaration: &str, rows: &[String], size: usize) -> String {
+    let mut code = format!("{declaration} = [\n");
+    for row in rows {
+        let _ = writeln!(code, "    {row}");
+    }
+    let _ = writeln!(code, "];\n// {size} bytes");
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNICODE_DATA: &str = "0028;LEFT PARENTHESIS;Ps;0;ON;;;;;Y;OPENING PARENTHESIS;;;;\n\
+        0622;ARABIC LETTER ALEF WITH MADDA ABOVE;Lo;0;AL;0627 0653;;;;N;;;;;\n\
+        0628;ARABIC LETTER BEH;Lo;0;AL;;;;;N;;;;;\n\
+        0624;ARABIC LETTER WAW WITH HAMZA ABOVE;Lo;0;AL;0648 0654;;;;N;;;;;\n\
+        0644;ARABIC LETTER LAM;Lo;0;AL;;;;;N;;;;;\n\
+        064B;ARABIC FATHATAN;Mn;27;NSM;;;;;N;;;;;\n\
+        0657;ARABIC INVERTED DAMMA;Mn;230;NSM;;;;;N;;;;;\n\
+        FE81;ARABIC LETTER ALEF WITH MADDA ABOVE ISOLATED FORM;Lo;0;AL;<isolated> 0622;;;;N;;;;;\n\
+        FE82;ARABIC LETTER ALEF WITH MADDA ABOVE FINAL FORM;Lo;0;AL;<final> 0622;;;;N;;;;;\n\
+        FE85;ARABIC LETTER WAW WITH HAMZA ABOVE ISOLATED FORM;Lo;0;AL;<isolated> 0624;;;;N;;;;;\n\
+        FE8F;ARABIC LETTER BEH ISOLATED FORM;Lo;0;AL;<isolated> 0628;;;;N;;;;;\n\
+        FE90;ARABIC LETTER BEH FINAL FORM;Lo;0;AL;<final> 0628;;;;N;;;;;\n\
+        FE91;ARABIC LETTER BEH INITIAL FORM;Lo;0;AL;<initial> 0628;;;;N;;;;;\n\
+        FE92;ARABIC LETTER BEH MEDIAL FORM;Lo;0;AL;<medial> 0628;;;;N;;;;;\n\
+        FEDD;ARABIC LETTER LAM ISOLATED FORM;Lo;0;AL;<isolated> 0644;;;;N;;;;;\n\
+        FEDE;ARABIC LETTER LAM FINAL FORM;Lo;0;AL;<final> 0644;;;;N;;;;;\n\
+        FEF5;ARABIC LIGATURE LAM WITH ALEF WITH MADDA ABOVE ISOLATED FORM;Lo;0;AL;<isolated> 0644 0622;;;;N;;;;;\n\
+        FEF7;ARABIC LIGATURE LAM WITH ALEF WITH HAMZA ABOVE ISOLATED FORM;Lo;0;AL;<isolated> 0644 0623;;;;N;;;;;\n\
+        FEF9;ARABIC LIGATURE LAM WITH ALEF WITH HAMZA BELOW ISOLATED FORM;Lo;0;AL;<isolated> 0644 0625;;;;N;;;;;\n\
+        FEFB;ARABIC LIGATURE LAM WITH ALEF ISOLATED FORM;Lo;0;AL;<isolated> 0644 0627;;;;N;;;;;\n";
+
+    #[test]
+    fn bidi_class_ranges() {
+        let data = UnicodeData::parse(UNICODE_DATA).unwrap();
+        let (table, supplementary) = bidi_class(&data);
+        assert!(table.starts_with("pub static BIDI_CLASS: [(u16, u16, BidiClass); 18] = [\n"));
+        assert!(table.contains("    (0x0028, 0x0028, BidiClass::ON),\n"));
+        // unassigned code points take the default of their block
+        assert!(table.contains("    (0x0600, 0x064A, BidiClass::AL),\n"));
+        assert!(table.contains("    (0x064B, 0x064B, BidiClass::NSM),\n"));
+        assert!(table.ends_with("];\n// 108 bytes\n"));
+        // the supplementary planes only have their defaults
+        assert!(supplementary.contains("    (0x10800, 0x10CFF, BidiClass::R),\n"));
+        assert!(supplementary.contains("    (0x1EE00, 0x1EEFF, BidiClass::AL),\n"));
+        assert!(supplementary.contains("    (0x10FFFE, 0x10FFFF, BidiClass::BN),\n"));
+    }
+
+    #[test]
+    fn mirror_pairs() {
+        let text = "# BidiMirroring-16.0.0.txt\n0029; 0028 # RIGHT PARENTHESIS\n\
+            0028; 0029 # LEFT PARENTHESIS\n1D6DB; 2202 # [BEST FIT] MATHEMATICAL BOLD\n";
+        assert_eq!(
+            mirror_char(text).unwrap(),
+            "pub static MIRROR_CHAR: [[u16; 2]; 2] = [\n    [0x0028, 0x0029],\n    \
+             [0x0029, 0x0028],\n];\n// 8 bytes\n"
+        );
+    }
+
+    #[test]
+    fn bracket_pairs() {
+        let text = "# BidiBrackets-16.0.0.txt\n0029; 0028; c # RIGHT PARENTHESIS\n\
+            0028; 0029; o # LEFT PARENTHESIS\n";
+        assert_eq!(
+            bidi_brackets(text).unwrap(),
+            "pub static BIDI_BRACKETS: [[u16; 3]; 2] = [\n    [0x0028, 0x0029, 0],\n    \
+             [0x0029, 0x0028, 1],\n];\n// 12 bytes\n"
+        );
+        assert!(bidi_brackets("0028; 0029; n # LEFT PARENTHESIS\n").is_err());
+    }
+
+    #[test]
+    fn arabic_links() {
+        let data = UnicodeData::parse(UNICODE_DATA).unwrap();
+        let shaping = "0622; ALEF WITH MADDA ABOVE; R; ALEF\n0624; WAW WITH HAMZA ABOVE; R; WAW\n\
+            0628; BEH; D; BEH\n0644; LAM; D; LAM\n";
+        let table = ara_link(&data, shaping).unwrap();
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows[0], "pub static ARA_LINK: [u16; 734] = [");
+        assert_eq!(rows[1], "    1 + 32 + 256 * 0x11, // 0x0622");
+        // unassigned
+        assert_eq!(rows[2], "    0, // 0x0623");
+        // no final form to shape to
+        assert_eq!(rows[3], "    1, // 0x0624");
+        assert_eq!(rows[7], "    1 + 2 + 256 * 0x1F, // 0x0628");
+        // dual joining without initial and medial forms
+        assert_eq!(rows[35], "    1 + 16 + 256 * 0x6D, // 0x0644");
+        assert_eq!(rows[42], "    4 + 256, // 0x064B");
+        assert_eq!(rows[54], "    4, // 0x0657");
+        assert_eq!(rows[59], "    1 + 256 * 0x85, // 0x065C");
+        assert_eq!(rows[79], "    4 + 256 * 6, // 0x0670");
+        // Syriac doesn't join, but Arabic Supplement does
+        let shaping = format!("{shaping}0712; BETH; D; BETH\n0750; BEH WITH THREE DOTS; D; BEH\n");
+        let table = ara_link(&data, &shaping).unwrap();
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows[0x0712 - 0x0622 + 1], "    0, // 0x0712");
+        assert_eq!(rows[0x0750 - 0x0622 + 1], "    1 + 2, // 0x0750");
+    }
+
+    #[test]
+    fn joining_links() {
+        let data = UnicodeData::parse(
+            "0300;COMBINING GRAVE ACCENT;Mn;230;NSM;;;;;N;NON-SPACING GRAVE;;;;\n\
+             0649;ARABIC LETTER ALEF MAKSURA;Lo;0;AL;;;;;N;;;;;\n\
+             065C;ARABIC VOWEL SIGN DOT BELOW;Mn;220;NSM;;;;;N;;;;;\n\
+             0710;SYRIAC LETTER ALAPH;Lo;0;AL;;;;;N;;;;;\n\
+             180B;MONGOLIAN FREE VARIATION SELECTOR ONE;Mn;0;NSM;;;;;N;;;;;\n\
+             1820;MONGOLIAN LETTER A;Lo;0;L;;;;;N;;;;;\n\
+             1821;MONGOLIAN LETTER E;Lo;0;L;;;;;N;;;;;\n\
+             200C;ZERO WIDTH NON-JOINER;Cf;0;BN;;;;;N;;;;;\n\
+             200D;ZERO WIDTH JOINER;Cf;0;BN;;;;;N;;;;;\n\
+             FEEF;ARABIC LETTER ALEF MAKSURA ISOLATED FORM;Lo;0;AL;<isolated> 0649;;;;N;;;;;\n\
+             10D00;HANIFI ROHINGYA LETTER A;Lo;0;AL;;;;;N;;;;;\n",
+        )
+        .unwrap();
+        let shaping = "0649; ALEF MAKSURA; D; YEH\n0710; ALAPH; R; ALAPH\n\
+            180B; FVS1; T; No_Joining_Group\n1820; MONGOLIAN A; D; No_Joining_Group\n\
+            1821; MONGOLIAN E; D; No_Joining_Group\n200D; ZERO WIDTH JOINER; C; No_Joining_Group\n\
+            10D00; HANIFI ROHINGYA A; L; No_Joining_Group\n";
+        let scripts = "0300..036F    ; Inherited\n0640          ; Common\n0649          ; Arabic\n\
+            065C          ; Inherited\n0710          ; Syriac\n1820..1821    ; Mongolian\n\
+            180B          ; Mongolian\n200C..200D    ; Inherited\n10D00         ; Hanifi_Rohingya\n";
+        let table = joining_link(&data, shaping, scripts).unwrap();
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            rows[..10],
+            [
+                "pub static JOINING_LINK: [(u32, u32, u16, u8); 8] = [",
+                // unlisted Mn
+                "    (0x0300, 0x0300, 4, 0),",
+                // dual joining, even without initial and medial presentation forms
+                "    (0x0649, 0x0649, 1 + 2, 1),",
+                // transparent, not a lam-alef placeholder
+                "    (0x065C, 0x065C, 4, 0),",
+                "    (0x0710, 0x0710, 1, 2),",
+                // transparent characters join any script
+                "    (0x180B, 0x180B, 4, 0),",
+                // equal links are merged
+                "    (0x1820, 0x1821, 1 + 2, 3),",
+                // ZWNJ is not transparent, ZWJ joins any script
+                "    (0x200D, 0x200D, 1 + 2, 0),",
+                "    (0x10D00, 0x10D00, 2, 4),",
+                "];",
+            ]
+        );
+        // the same links of different scripts are not merged
+        let scripts = "0649          ; Arabic\n1820..1821    ; Mongolian\n";
+        let shaping = "0649; ALEF MAKSURA; D; YEH\n064A; YEH; D; YEH\n1820; MONGOLIAN A; D; X\n";
+        let table = joining_link(&data, shaping, scripts).unwrap();
+        assert!(
+            table.contains("    (0x0649, 0x0649, 1 + 2, 1),\n    (0x064A, 0x064A, 1 + 2, 0),\n")
+        );
+    }
+
+    #[test]
+    fn cjk_blocks() {
+        let blocks = "2E80..2EFF; CJK Radicals Supplement\n2F00..2FDF; Kangxi Radicals\n\
+            4E00..9FFF; CJK Unified Ideographs\nA000..A48F; Yi Syllables\n\
+            20000..2A6DF; CJK Unified Ideographs Extension B\n";
+        assert_eq!(
+            cjk(blocks).unwrap(),
+            "pub static CJK: [[u32; 2]; 4] = [\n    \
+             // CJK Radicals Supplement (Range: 2E80–2EFF)\n    [0x2E80, 0x2EFF],\n    \
+             // Kangxi Radicals (Range: 2F00–2FDF)\n    [0x2F00, 0x2FDF],\n    \
+             // CJK Unified Ideographs (Range: 4E00–9FFF)\n    [0x4E00, 0x9FFF],\n    \
+             // CJK Unified Ideographs Extension B (Range: 20000–2A6DF)\n    \
+             [0x20000, 0x2A6DF],\n];\n// 32 bytes\n"
+        );
+    }
+
+    #[test]
+    fn khmer_vowels() {
+        let text = "17B6          ; Vowel_Dependent # Mc\n17B7..17B8    ; Vowel_Dependent # Mn\n\
+            17C6          ; Bindu # Mn\n0BBE..0BBF    ; Vowel_Dependent # Mc\n";
+        assert_eq!(
+            khmer_dependent_vowels(text).unwrap(),
+            "pub const KHMER_DEPENDENT_VOWELS: [u16; 3] = [\n    0x17B6, 0x17B7, 0x17B8,\n];\n"
+        );
+    }
+}
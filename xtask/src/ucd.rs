@@ -0,0 +1,168 @@
+//! Parsers for the Unicode Character Database files the tables are generated from.
+//! https://www.unicode.org/reports/tr44/
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// [first, last, fields] of the entries of a property file
+pub type Ranges<'a> = Vec<(u32, u32, Vec<&'a str>)>;
+
+/// The presentation forms of characters keyed by decomposition tag and characters
+pub type PresentationForms = BTreeMap<(String, Vec<u32>), Vec<u32>>;
+
+/// A code point of UnicodeData.txt
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharData {
+    /// General_Category, e.g. `Lo` or `Mn`
+    pub category: String,
+    /// Bidi_Class, e.g. `AL` or `NSM`
+    pub bidi_class: String,
+    /// Decomposition type and mapping, e.g. `<isolated> 0627`
+    pub decomposition: String,
+}
+
+/// The content of UnicodeData.txt. Ranges given as `<..., First>` and `<..., Last>` pairs
+/// are stored with their first code point.
+#[derive(Debug, Default)]
+pub struct UnicodeData {
+    /// [first, last, data] of every entry, sorted by code point
+    pub entries: Vec<(u32, u32, CharData)>,
+}
+impl UnicodeData {
+    /// Parse UnicodeData.txt
+    pub fn parse(text: &str) -> Result<UnicodeData, String> {
+        let mut entries = Vec::<(u32, u32, CharData)>::new();
+        let mut range_start: Option<u32> = None;
+        for fields in data_lines(text) {
+            if fields.len() < 6 {
+                return Err(format!("malformed UnicodeData.txt line: {}", fields.join(";")));
+            }
+            let cp = parse_code_point(fields[0])?;
+            let data = CharData {
+                category: fields[2].into(),
+                bidi_class: fields[4].into(),
+                decomposition: fields[5].into(),
+            };
+            if fields[1].ends_with(", First>") {
+                range_start = Some(cp);
+            } else if fields[1].ends_with(", Last>") {
+                let first =
+                    range_start.take().ok_or(format!("range end without start: {cp:04X}"))?;
+                entries.push((first, cp, data));
+            } else {
+                entries.push((cp, cp, data));
+            }
+        }
+
+        Ok(UnicodeData { entries })
+    }
+
+    /// Get the data of a code point
+    pub fn get(&self, cp: u32) -> Option<&CharData> {
+        let idx = self.entries.partition_point(|(_, last, _)| *last < cp);
+        self.entries.get(idx).filter(|(first, _, _)| *first <= cp).map(|(_, _, data)| data)
+    }
+
+    /// Map the characters (or ligated character sequences) to their presentation forms, keyed
+    /// by the decomposition tag (`isolated`, `final`, `initial` or `medial`).
+    pub fn presentation_forms(&self) -> Result<PresentationForms, String> {
+        let mut forms = PresentationForms::new();
+        for (cp, _, data) in self.entries.iter() {
+            let Some(mapping) = data.decomposition.strip_prefix('<') else { continue };
+            let Some((tag, chars)) = mapping.split_once("> ") else { continue };
+            let chars = chars.split_whitespace().map(parse_code_point).collect::<Result<_, _>>()?;
+            forms.entry((tag.into(), chars)).or_default().push(*cp);
+        }
+
+        Ok(forms)
+    }
+}
+
+/// Parse a property file of `code point or range; value` lines (e.g. BidiMirroring.txt,
+/// IndicSyllabicCategory.txt or Blocks.txt) into [first, last, fields] entries.
+pub fn parse_ranges(text: &str) -> Result<Ranges<'_>, String> {
+    data_lines(text)
+        .map(|fields| {
+            let (first, last) = parse_range(fields[0])?;
+            Ok((first, last, fields[1..].to_vec()))
+        })
+        .collect()
+}
+
+/// The Unicode version in the header of a UCD file, e.g. `# ArabicShaping-16.0.0.txt`
+pub fn version(text: &str, name: &str) -> Option<(u8, u8, u8)> {
+    let stem = name.strip_suffix(".txt")?;
+    let header = text.lines().next()?.strip_prefix("# ")?.strip_prefix(stem)?;
+    let mut parts = header.strip_prefix('-')?.strip_suffix(".txt")?.split('.');
+    let mut part = || parts.next()?.parse::<u8>().ok();
+
+    Some((part()?, part()?, part()?))
+}
+
+/// Read a file of the UCD directory
+pub fn read(dir: &Path, name: &str) -> Result<String, String> {
+    let path = dir.join(name);
+    fs::read_to_string(&path).map_err(|err| format!("failed to read {}: {err}", path.display()))
+}
+
+/// The `;` separated and trimmed fields of every line that is not empty or a comment
+fn data_lines(text: &str) -> impl Iterator<Item = Vec<&str>> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split(';').map(str::trim).collect())
+}
+
+/// Parse a hexadecimal code point, e.g. `0627`
+pub fn parse_code_point(cp: &str) -> Result<u32, String> {
+    u32::from_str_radix(cp.trim(), 16).map_err(|_| format!("invalid code point: {cp:?}"))
+}
+
+/// Parse a code point or a code point range, e.g. `0627` or `0600..06FF`
+fn parse_range(range: &str) -> Result<(u32, u32), String> {
+    match range.split_once("..") {
+        Some((first, last)) => Ok((parse_code_point(first)?, parse_code_point(last)?)),
+        None => parse_code_point(range).map(|cp| (cp, cp)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_data() {
+        let text = "0627;ARABIC LETTER ALEF;Lo;0;AL;;;;;N;;;;;\n\
+            3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;\n\
+            4DB5;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;\n\
+            FE8D;ARABIC LETTER ALEF ISOLATED FORM;Lo;0;AL;<isolated> 0627;;;;N;;;;;\n\
+            FEFB;ARABIC LIGATURE LAM WITH ALEF ISOLATED FORM;Lo;0;AL;<isolated> 0644 0627;;;;N;;;;;\n";
+        let data = UnicodeData::parse(text).unwrap();
+        assert_eq!(data.entries.len(), 4);
+        assert_eq!(data.get(0x0627).unwrap().bidi_class, "AL");
+        assert_eq!(data.get(0x4000).unwrap().category, "Lo");
+        assert_eq!(data.get(0x4DB6), None);
+        let forms = data.presentation_forms().unwrap();
+        assert_eq!(forms.len(), 2);
+        assert_eq!(forms[&("isolated".into(), vec![0x0627])], vec![0xFE8D]);
+        assert_eq!(forms[&("isolated".into(), vec![0x0644, 0x0627])], vec![0xFEFB]);
+    }
+
+    #[test]
+    fn ranges() {
+        let text = "# Blocks-16.0.0.txt\n\n0000..007F; Basic Latin\n17B6          ; Vowel_Dependent # Mc\n";
+        let ranges = parse_ranges(text).unwrap();
+        assert_eq!(
+            ranges,
+            vec![(0, 0x7F, vec!["Basic Latin"]), (0x17B6, 0x17B6, vec!["Vowel_Dependent"])]
+        );
+        assert!(parse_ranges("XYZ; L").is_err());
+    }
+
+    #[test]
+    fn file_version() {
+        let text = "# ArabicShaping-16.0.0.txt\n# Date: 2024-02-02\n";
+        assert_eq!(version(text, "ArabicShaping.txt"), Some((16, 0, 0)));
+        assert_eq!(version(text, "Blocks.txt"), None);
+        assert_eq!(version("0627;ARABIC LETTER ALEF;Lo;0;AL;;;;;N;;;;;", "UnicodeData.txt"), None);
+    }
+}